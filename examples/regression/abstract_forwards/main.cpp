@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+int Counter::get() {
+	return count;
+}
+
+void Counter::add(int amount) {
+	count += amount;
+}
+
+void Tally_add_twice(Counter& self, int amount) {
+	self.add(amount);
+	self.add(amount);
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+void Tally_add_twice(Counter& self, int amount);
+
+class Counter {
+public:
+	int get();
+	void add(int amount);
+
+	int count = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int setup() {
+	int count          = 1;
+	double total_value = 2.5;
+	bool flag          = true;
+	count += 2;
+
+	int width            = 4;
+	int height_in_pixels = count + width;
+
+	return width;
+}
\ No newline at end of file
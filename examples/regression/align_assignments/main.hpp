@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int setup();
+
+#endif
\ No newline at end of file
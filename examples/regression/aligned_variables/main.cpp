@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+alignas(64) int cache_line = 0;
+
+int fill() {
+
+	alignas(32) int buffer = 5;
+	return buffer;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+alignas(64) extern int cache_line;
+
+int fill();
+
+class Packet {
+public:
+	alignas(16) int header = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+unsigned char to_byte(int value) {
+	return static_cast<unsigned char>(value);
+}
+
+int widen(unsigned char value) {
+	return static_cast<int>(value);
+}
+
+int to_int(size_t value) {
+	return static_cast<int>(value);
+}
\ No newline at end of file
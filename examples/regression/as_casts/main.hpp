@@ -0,0 +1,10 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <stddef.h>
+
+unsigned char to_byte(int value);
+int widen(unsigned char value);
+int to_int(size_t value);
+
+#endif
\ No newline at end of file
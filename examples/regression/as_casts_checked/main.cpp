@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+unsigned char to_byte(int value) {
+	return [](auto value) { if(!std::in_range<unsigned char>(value)) throw std::out_of_range("checked cast out of range"); return static_cast<unsigned char>(value); }(value);
+}
+
+int widen(unsigned char value) {
+	return static_cast<int>(value);
+}
+
+int to_int(size_t value) {
+	return [](auto value) { if(!std::in_range<int>(value)) throw std::out_of_range("checked cast out of range"); return static_cast<int>(value); }(value);
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+int report(int value) {
+	printf("%d\n", value);
+	return value;
+}
+
+int main() {
+	return report(sum_squares(4));
+}
\ No newline at end of file
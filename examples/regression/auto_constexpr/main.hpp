@@ -0,0 +1,21 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <cstdio>
+
+constexpr int square(int value) {
+	return value * value;
+}
+
+constexpr int sum_squares(int count) {
+	int total = 0;
+	for(int i = 0; i < count; i++) {
+		total += square(i);
+	}
+	return total;
+}
+
+int report(int value);
+int main();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,24 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Shape {
+public:
+	int id = 0;
+};
+
+class Named {
+public:
+	int name = 0;
+};
+
+class Tracked {
+public:
+	int count = 0;
+};
+
+class Circle: private Shape, public Named, protected Tracked {
+public:
+	int radius = 0;
+};
+
+#endif
\ No newline at end of file
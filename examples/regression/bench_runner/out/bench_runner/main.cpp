@@ -0,0 +1,14 @@
+#include "out/bench_runner/main.hpp"
+
+void sum_numbers() {
+	int total = 0;
+	for(int i = 0; i < 1000; i++) {
+		total += i;
+	}
+}
+
+void empty_loop() {
+	for(int i = 0; i < 100; i++) {
+		
+	}
+}
\ No newline at end of file
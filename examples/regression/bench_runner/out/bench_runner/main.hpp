@@ -0,0 +1,7 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+void sum_numbers();
+void empty_loop();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,20 @@
+#include <chrono>
+#include <cstdio>
+
+#include "bench_runner/main.hpp"
+
+int main() {
+	{
+		auto start = std::chrono::steady_clock::now();
+		sum_numbers();
+		auto elapsed = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() - start);
+		std::printf("[BENCH] %s: %lld us\n", "sum to one thousand", static_cast<long long>(elapsed.count()));
+	}
+	{
+		auto start = std::chrono::steady_clock::now();
+		empty_loop();
+		auto elapsed = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() - start);
+		std::printf("[BENCH] %s: %lld us\n", "empty_loop", static_cast<long long>(elapsed.count()));
+	}
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Flags {
+public:
+	unsigned int mode : 3;
+	unsigned int enabled : 1;
+	int count = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,23 @@
+#include "main.hpp"
+
+void Counter::add(int amount) {
+	if(amount > 0) {
+		count += amount;
+	} else {
+		count -= 1;
+	}
+}
+
+int total(int limit) {
+	int sum = 0;
+	for(int i = 0; i < limit; i++) {
+		sum += i;
+	}
+	while(sum > 100) {
+		sum -= 100;
+	}
+	std::function<int(int)> doubled = [](int x) -> int {
+		return x * 2;
+	};
+	return doubled(sum);
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <functional>
+
+int total(int limit);
+
+class Counter {
+public:
+	void add(int amount);
+
+	int count = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,29 @@
+#include "main.hpp"
+
+int count(int limit) {
+	int total = 0;
+	if(limit <= 0) {
+		return 0;
+	}
+	while(total <= limit) {
+		if(total == 10) {
+			break;
+		}
+	}
+	for(int i = 0; i < limit; i++) {
+		if(i == 2) {
+			continue;
+		}
+	}
+	while(true) {
+		if(total >= 0) {
+			break;
+		}
+	}
+	do {
+		if(total <= 0) {
+			return total;
+		}
+	} while(total != limit);
+	return total;
+}
\ No newline at end of file
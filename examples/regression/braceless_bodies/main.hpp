@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int count(int limit);
+
+#endif
\ No newline at end of file
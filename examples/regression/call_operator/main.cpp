@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+Scale::Scale(int value) {
+	factor = value;
+}
+
+int Scale::operator()(int x) {
+	return x * factor;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Scale {
+public:
+	Scale(int value);
+
+	int operator()(int x);
+
+	int factor = 0;
+};
+
+#endif
\ No newline at end of file
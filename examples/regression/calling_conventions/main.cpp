@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int __attribute__((stdcall)) on_message(int code) {
+	return code;
+}
+
+int __attribute__((fastcall)) add(int a, int b) {
+	return a + b;
+}
\ No newline at end of file
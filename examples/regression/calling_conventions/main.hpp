@@ -0,0 +1,7 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int __attribute__((stdcall)) on_message(int code);
+int __attribute__((fastcall)) add(int a, int b);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int __stdcall on_message(int code) {
+	return code;
+}
+
+int __fastcall add(int a, int b) {
+	return a + b;
+}
\ No newline at end of file
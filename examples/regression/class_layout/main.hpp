@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class [[gnu::packed]] Header {
+public:
+	unsigned char tag = 0;
+	int size = 0;
+};
+
+class alignas(16) Block {
+public:
+	int data = 0;
+};
+
+#endif
\ No newline at end of file
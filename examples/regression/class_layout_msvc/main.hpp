@@ -0,0 +1,17 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#pragma pack(push, 1)
+class Header {
+public:
+	unsigned char tag = 0;
+	int size = 0;
+};
+#pragma pack(pop)
+
+class alignas(16) Block {
+public:
+	int data = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int read(boost::intrusive_ptr<Node> node) {
+	Node copied = *node;
+	Node* raw = node.get();
+	boost::intrusive_ptr<Node> shared = boost::intrusive_ptr<Node>(raw);
+	return copied.value + raw->value + shared->value;
+}
+
+int main() {
+	boost::intrusive_ptr<Node> node = boost::intrusive_ptr<Node>(new Node(3));
+	return read(node);
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <boost/intrusive_ptr.hpp>
+
+#include "node.hpp"
+
+int read(boost::intrusive_ptr<Node> node);
+int main();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,5 @@
+#include "node.hpp"
+
+Node::Node(int start) {
+	value = start;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#ifndef NODE_TASTYFILE
+#define NODE_TASTYFILE
+
+class Node {
+public:
+	Node(int start);
+
+	int value = 0;
+};
+
+#endif
\ No newline at end of file
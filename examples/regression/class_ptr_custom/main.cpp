@@ -0,0 +1,6 @@
+#include "main.hpp"
+
+int read(ref::ptr<Node> node) {
+	Node copied = *node;
+	return copied.value;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <ref_ptr.hpp>
+
+int read(ref::ptr<Node> node);
+
+class Node {
+public:
+	int value = 0;
+};
+
+#endif
\ No newline at end of file
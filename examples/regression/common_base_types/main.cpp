@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+int pick(bool flag, Shape* shape, Square* square) {
+	Shape* chosen = flag ? shape : square;
+	return chosen->area();
+}
+
+int pick_reversed(bool flag, Shape* shape, Square* square) {
+	Shape* chosen = flag ? square : shape;
+	return chosen->area();
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include "shapes.hpp"
+
+int pick(bool flag, Shape* shape, Square* square);
+int pick_reversed(bool flag, Shape* shape, Square* square);
+
+#endif
\ No newline at end of file
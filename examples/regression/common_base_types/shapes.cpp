@@ -0,0 +1,5 @@
+#include "shapes.hpp"
+
+int Shape::area() {
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+#ifndef SHAPES_TASTYFILE
+#define SHAPES_TASTYFILE
+
+class Shape {
+public:
+	int area();
+};
+
+class Square: public Shape {
+};
+
+#endif
\ No newline at end of file
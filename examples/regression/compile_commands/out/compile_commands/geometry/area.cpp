@@ -0,0 +1,5 @@
+#include "out/compile_commands/geometry/area.hpp"
+
+int square(int side) {
+	return side * side;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef GEOMETRY_AREA_TASTYFILE
+#define GEOMETRY_AREA_TASTYFILE
+
+int square(int side);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,7 @@
+#include "out/compile_commands/main.hpp"
+
+#include "geometry/area.hpp"
+
+int main() {
+	return square(4);
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#include "main.hpp"
+
+#ifdef DEBUG
+#include <cassert>
+#endif
+
+void start() { }
\ No newline at end of file
@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#ifdef _WIN32
+#include <windows.h>
+#endif
+#if defined(__linux__) && !defined(__ANDROID__)
+#include <unistd.h>
+#endif
+
+#include "config.hpp"
+
+void start();
+
+#endif
\ No newline at end of file
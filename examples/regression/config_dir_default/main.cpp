@@ -0,0 +1,6 @@
+#include "main.hpp"
+
+size_t sizes() {
+	int value = 1;
+	return alignof(decltype(value));
+}
\ No newline at end of file
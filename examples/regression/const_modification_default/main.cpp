@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+int count(int limit) {
+	int total = limit * 2;
+	total = 3;
+	total++;
+	int value = 1;
+	const int* cursor = &value;
+	return total;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int describe() {
+	constexpr bool DEBUG = false;
+	constexpr int LEVEL = 2;
+	if constexpr(DEBUG) {
+		return 1;
+	} else if constexpr(LEVEL > 1) {
+		return 2;
+	} else {
+		return 3;
+	}
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int check(int count) {
+	constexpr int LEVEL = 2;
+	if constexpr(count > LEVEL) {
+		return count;
+	}
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int check(int count);
+
+#endif
\ No newline at end of file
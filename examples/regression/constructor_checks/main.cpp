@@ -0,0 +1,23 @@
+#include "main.hpp"
+
+Shape::Shape() { }
+
+Shape::Shape(int value) {
+	id = value;
+}
+
+Point::Point(int value) {
+	id = value;
+}
+
+Point::Point(std::string text, bool flag) {
+	name = text;
+}
+
+void construct() {
+	Point sum(3 + 1);
+	Point flag(true);
+	Point named("origin", false);
+	Point copied(sum);
+	Shape base(sum);
+}
\ No newline at end of file
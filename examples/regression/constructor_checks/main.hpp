@@ -0,0 +1,24 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <string>
+
+void construct();
+
+class Shape {
+public:
+	Shape();
+	Shape(int value);
+
+	int id = 0;
+};
+
+class Point: public Shape {
+public:
+	Point(int value);
+	Point(std::string text, bool flag);
+
+	std::string name;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+Shape* Shape::duplicate() {
+	return this;
+}
+
+Shape& Shape::self_ref() {
+	return *this;
+}
+
+Square* Square::duplicate() {
+	return this;
+}
+
+Square& Square::self_ref() {
+	return *this;
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Shape {
+public:
+	virtual Shape* duplicate();
+	virtual Shape& self_ref();
+};
+
+class Square: public Shape {
+public:
+	Square* duplicate() override;
+	Square& self_ref() override;
+};
+
+#endif
\ No newline at end of file
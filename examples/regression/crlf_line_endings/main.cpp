@@ -0,0 +1,5 @@
+#include "main.hpp"
+
+int add(int a, int b) {
+	return a + b;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int add(int a, int b);
+
+#endif
\ No newline at end of file
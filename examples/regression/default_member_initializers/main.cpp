@@ -0,0 +1,15 @@
+#include "main.hpp"
+
+Counter::Counter(int start) {
+	count = start;
+}
+
+int Counter::advance() {
+	count += step;
+	return count;
+}
+
+int main() {
+	auto counter = Counter(3);
+	return counter.advance();
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int main();
+
+class Counter {
+public:
+	Counter(int start);
+
+	int advance();
+
+	int count = 0;
+	int step = 5;
+};
+
+#endif
\ No newline at end of file
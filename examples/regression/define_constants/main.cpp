@@ -0,0 +1,3 @@
+#include "main.hpp"
+
+int limit = MAX_USERS * 2;
\ No newline at end of file
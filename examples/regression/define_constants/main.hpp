@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#define MAX_USERS 64
+#define APP_NAME "tasty"
+extern int limit;
+
+#endif
\ No newline at end of file
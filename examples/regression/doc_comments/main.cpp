@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int square(int x) {
+	return x * x;
+}
+
+int clamp(int value, int low, int high) {
+	return value < low ? low : (value > high ? high : value);
+}
\ No newline at end of file
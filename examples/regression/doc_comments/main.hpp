@@ -0,0 +1,20 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+/** Squares a number. */
+int square(int x);
+/**
+ * Clamps a value.
+ * Values outside the range are moved to the nearest bound.
+ * The bounds are inclusive.
+ */
+int clamp(int value, int low, int high);
+
+/** A point in 2D space. * / not closed early */
+class Point {
+public:
+	int x = 0;
+	int y = 0;
+};
+
+#endif
\ No newline at end of file
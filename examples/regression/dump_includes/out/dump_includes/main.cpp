@@ -0,0 +1,7 @@
+#include "out/dump_includes/main.hpp"
+
+#include "math.hpp"
+
+int main() {
+	return area(2) + square(3);
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+#include "out/dump_includes/math.hpp"
+
+int square(int x) {
+	return x * x;
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+#include "out/dump_includes/shapes/circle.hpp"
+
+int area(int radius) {
+	return square(radius) * 3;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef SHAPES_CIRCLE_TASTYFILE
+#define SHAPES_CIRCLE_TASTYFILE
+
+#include "math.hpp"
+
+int area(int radius);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+double first_weight(const std::vector<Item>& items) {
+	double weight = items[0].weight;
+	return weight;
+}
+
+double named_weight(std::map<int, Item>& items) {
+	double weight = items[3].weight;
+	return weight;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+#include <map>
+
+double first_weight(const std::vector<Item>& items);
+double named_weight(std::map<int, Item>& items);
+
+class Item {
+public:
+	double weight = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+std::optional<int> find(const std::string& name) {
+	return std::nullopt;
+}
+
+int defaults(Node* node, Node* fallback, std::optional<int> count, int* limit) {
+	Node* chosen = (node ? node : fallback);
+	int amount = count.value_or(0);
+	int looked_up = find("key").value_or(-1);
+	Node* next = [&](auto elvis_value) { return elvis_value ? elvis_value : fallback; }((node->next));
+	int bound = (limit ? *limit : 10);
+	return amount;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+std::optional<int> find(const std::string& name);
+int defaults(Node* node, Node* fallback, std::optional<int> count, int* limit);
+
+class Node {
+public:
+	int value = 0;
+	Node* next = nullptr;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+void start() {
+	bool ready = true;
+}
+
+int main(int argc, char** argv) {
+	start();
+	return 0;
+}
\ No newline at end of file
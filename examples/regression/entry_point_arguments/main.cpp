@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int run(int count, char** args) {
+	return count;
+}
+
+int main(int argc, char** argv) {
+	return run(argc, argv);
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int run(int count, char** args);
+
+#endif
\ No newline at end of file
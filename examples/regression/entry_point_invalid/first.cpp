@@ -0,0 +1,8 @@
+#include "first.hpp"
+
+void start() { }
+
+int main(int argc, char** argv) {
+	start();
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef FIRST_TASTYFILE
+#define FIRST_TASTYFILE
+
+void start();
+
+#endif
\ No newline at end of file
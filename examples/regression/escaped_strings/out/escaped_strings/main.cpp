@@ -0,0 +1,5 @@
+#include "out/escaped_strings/main.hpp"
+
+void test_quotes() { }
+
+void bench_tabs() { }
\ No newline at end of file
@@ -0,0 +1,11 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+/**
+ * Returns "zero" from C:\temp.
+ * Never fails.
+ */
+void test_quotes();
+void bench_tabs();
+
+#endif
\ No newline at end of file
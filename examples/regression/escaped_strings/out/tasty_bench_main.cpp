@@ -0,0 +1,14 @@
+#include <chrono>
+#include <cstdio>
+
+#include "escaped_strings/main.hpp"
+
+int main() {
+	{
+		auto start = std::chrono::steady_clock::now();
+		bench_tabs();
+		auto elapsed = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() - start);
+		std::printf("[BENCH] %s: %lld us\n", "tab\tseparated", static_cast<long long>(elapsed.count()));
+	}
+	return 0;
+}
\ No newline at end of file
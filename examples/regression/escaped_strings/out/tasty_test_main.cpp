@@ -0,0 +1,17 @@
+#include <cstdio>
+#include <exception>
+
+#include "escaped_strings/main.hpp"
+
+int main() {
+	int failed = 0;
+	try {
+		test_quotes();
+		std::printf("[PASS] %s\n", "handles \"quotes\" and C:\\paths");
+	} catch(...) {
+		failed++;
+		std::printf("[FAIL] %s\n", "handles \"quotes\" and C:\\paths");
+	}
+	std::printf("%d of 1 tests passed\n", 1 - failed);
+	return failed;
+}
\ No newline at end of file
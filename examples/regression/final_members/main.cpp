@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int Shape::area() {
+	return 0;
+}
+
+int Square::area() {
+	return 4;
+}
\ No newline at end of file
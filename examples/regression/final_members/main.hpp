@@ -0,0 +1,19 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Shape {
+public:
+	virtual int area();
+};
+
+class Square: public Shape {
+public:
+	int area() override final;
+};
+
+class Unit final: public Square {
+public:
+	int scale = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int sum() {
+	std::array<int, 4> values = {};
+	values[0] = 3;
+	int first = values[0];
+	std::array<bool, 2> grid = {};
+	return first + values[1];
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <array>
+
+int sum();
+
+#endif
\ No newline at end of file
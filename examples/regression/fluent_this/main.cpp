@@ -0,0 +1,22 @@
+#include "main.hpp"
+
+Builder& Builder::set_width(int value) {
+	width = value;
+	return *this;
+}
+
+Builder& Builder::merge(Builder& other) {
+	other.set_width(width);
+	return *this;
+}
+
+Builder Builder::copy_with(int value) {
+	width = value;
+	return *this;
+}
+
+int build() {
+	Builder builder;
+	builder.set_width(3).set_width(4);
+	return builder.width;
+}
\ No newline at end of file
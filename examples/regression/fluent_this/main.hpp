@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int build();
+
+class Builder {
+public:
+	Builder& set_width(int value);
+	Builder& merge(Builder& other);
+	Builder copy_with(int value);
+
+	int width = 0;
+	int height = 0;
+};
+
+#endif
\ No newline at end of file
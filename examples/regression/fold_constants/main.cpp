@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+double compute(int x) {
+	int total = 14;
+	double ratio = 6.0;
+	int mixed = 3 + 0.5;
+	int negative = -5;
+	int partial = x + 10;
+	int invalid = 10 / 0;
+	return ratio;
+}
\ No newline at end of file
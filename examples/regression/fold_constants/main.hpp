@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+double compute(int x);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+int add(int a, int b) {
+	return a + b;
+}
+
+int apply(std::function<int(int, int)> callback, int value) {
+	return callback(value, value);
+}
+
+int apply_raw(std::add_pointer_t<int(int, int)> callback, int value) {
+	return callback(value, 1);
+}
+
+int run() {
+	return apply(add, 2) + apply_raw(add, 3);
+}
\ No newline at end of file
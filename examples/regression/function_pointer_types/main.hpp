@@ -0,0 +1,12 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <functional>
+#include <type_traits>
+
+int add(int a, int b);
+int apply(std::function<int(int, int)> callback, int value);
+int apply_raw(std::add_pointer_t<int(int, int)> callback, int value);
+int run();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+int first_positive(int* values, int count) {
+	if(count <= 0) {
+		return -1;
+	}
+	for(int i = 0; i < count; i++) {
+		if(values[i] < 0) {
+			continue;
+		}
+		if(values[i] == 0 || i >= 100) {
+			break;
+		}
+		return values[i];
+	}
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int first_positive(int* values, int count);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,3 @@
+#include "helper.hpp"
+
+static void log() { std::puts("log"); }
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef HELPER_TASTYFILE
+#define HELPER_TASTYFILE
+
+#include <cstdio>
+
+#endif
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+static int counter = 0;
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+__attribute__((hot)) int process(int value);
+__attribute__((cold)) int report_failure(int code);
+__attribute__((cold)) inline int rare(int value);
+
+#endif
\ No newline at end of file
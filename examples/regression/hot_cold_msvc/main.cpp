@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int process(int value) {
+	return value * 2;
+}
+
+int report_failure(int code) {
+	return code;
+}
+
+int rare(int value) {
+	return value + 1;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int process(int value);
+int report_failure(int code);
+inline int rare(int value);
+
+#endif
\ No newline at end of file
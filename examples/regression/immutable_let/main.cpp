@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+int count(int limit) {
+	const int total = limit * 2;
+	int index = 0;
+	while(index < total) {
+		index++;
+	}
+	const int first = 1;
+	const int second = 2;
+	const int* cursor = &first;
+	cursor = &second;
+	return index + *cursor;
+}
\ No newline at end of file
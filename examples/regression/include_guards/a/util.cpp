@@ -0,0 +1,3 @@
+#include "a/util.hpp"
+
+int first = 1;
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef A_UTIL_TASTYFILE
+#define A_UTIL_TASTYFILE
+
+extern int first;
+
+#endif
\ No newline at end of file
@@ -0,0 +1,3 @@
+#include "b/util.hpp"
+
+int second = 2;
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef B_UTIL_TASTYFILE
+#define B_UTIL_TASTYFILE
+
+extern int second;
+
+#endif
\ No newline at end of file
@@ -0,0 +1,3 @@
+#include "main.hpp"
+
+std::vector<int> values;
\ No newline at end of file
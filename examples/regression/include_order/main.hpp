@@ -0,0 +1,12 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include "util.hpp"
+#include "base.hpp"
+
+#include <vector>
+#include <map>
+
+extern std::vector<int> values;
+
+#endif
\ No newline at end of file
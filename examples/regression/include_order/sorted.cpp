@@ -0,0 +1,3 @@
+#include "sorted.hpp"
+
+std::map<int, int> keys;
\ No newline at end of file
@@ -0,0 +1,12 @@
+#ifndef SORTED_TASTYFILE
+#define SORTED_TASTYFILE
+
+#include <map>
+#include <vector>
+
+#include "base.hpp"
+#include "util.hpp"
+
+extern std::map<int, int> keys;
+
+#endif
\ No newline at end of file
@@ -0,0 +1,7 @@
+#include "myproj/core/main.hpp"
+
+#include "myproj/core/math.hpp"
+
+int main() {
+	return square(3);
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+
+int main();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,7 @@
+#include "main.hpp"
+
+#include "math.hpp"
+
+int main() {
+	return square(3);
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+#include "math.hpp"
+
+int square(int x) {
+	return x * x * 1;
+}
\ No newline at end of file
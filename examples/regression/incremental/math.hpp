@@ -0,0 +1,6 @@
+#ifndef MATH_TASTYFILE
+#define MATH_TASTYFILE
+
+int square(int x);
+
+#endif
\ No newline at end of file
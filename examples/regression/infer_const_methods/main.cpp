@@ -0,0 +1,22 @@
+#include "main.hpp"
+
+int Counter::get() const {
+	return count;
+}
+
+int Counter::doubled() const {
+	return get() * 2;
+}
+
+void Counter::increment() {
+	count += step;
+}
+
+void Counter::reset() {
+	increment();
+	count = 0;
+}
+
+int Counter::next() const {
+	return count + step;
+}
\ No newline at end of file
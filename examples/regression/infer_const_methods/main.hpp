@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Counter {
+public:
+	int get() const;
+	int doubled() const;
+	void increment();
+	void reset();
+	int next() const;
+
+	int count = 0;
+	int step = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,22 @@
+#include "main.hpp"
+
+int Counter::get() {
+	return count;
+}
+
+int Counter::doubled() {
+	return get() * 2;
+}
+
+void Counter::increment() {
+	count += step;
+}
+
+void Counter::reset() {
+	increment();
+	count = 0;
+}
+
+int Counter::next() {
+	return count + step;
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+#include "main.hpp"
+
+#include "shapes.hpp"
+
+namespace app {
+inline namespace v2 {
+
+int runs = 0;
+
+int run() {
+	int scaled = scale(4);
+	int versioned = v1::scale(1);
+	Circle circle;
+	double area = circle.area();
+	v1::Circle unit;
+	double unit_area = unit.area();
+	return scaled + versioned;
+}
+
+int twice() {
+	return run() + app::run();
+}
+
+}
+}
\ No newline at end of file
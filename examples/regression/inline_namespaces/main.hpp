@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+namespace app {
+inline namespace v2 {
+
+extern int runs;
+
+int run();
+int twice();
+
+}
+}
+
+#endif
\ No newline at end of file
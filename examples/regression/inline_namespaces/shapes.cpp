@@ -0,0 +1,13 @@
+#include "shapes.hpp"
+
+inline namespace v1 {
+
+double Circle::area() {
+	return radius * radius * 3.14;
+}
+
+int scale(int value) {
+	return value * 2;
+}
+
+}
\ No newline at end of file
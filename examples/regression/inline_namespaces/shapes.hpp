@@ -0,0 +1,19 @@
+#ifndef SHAPES_TASTYFILE
+#define SHAPES_TASTYFILE
+
+#include <cmath>
+
+inline namespace v1 {
+
+int scale(int value);
+
+class Circle {
+public:
+	double area();
+
+	double radius = 0;
+};
+
+}
+
+#endif
\ No newline at end of file
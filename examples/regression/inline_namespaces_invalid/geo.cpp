@@ -0,0 +1,9 @@
+#include "geo.hpp"
+
+namespace geo {
+
+double area(int size) {
+	return size * 3.5;
+}
+
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+#ifndef GEO_TASTYFILE
+#define GEO_TASTYFILE
+
+namespace geo {
+
+double area(int size);
+
+}
+
+#endif
\ No newline at end of file
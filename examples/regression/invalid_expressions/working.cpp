@@ -0,0 +1,6 @@
+#include "working.hpp"
+
+int working() {
+	int d = 1 + 2;
+	return d;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef WORKING_TASTYFILE
+#define WORKING_TASTYFILE
+
+int working();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+/* The number of items
+   kept at a time. */
+int capacity = 16;
+
+// Doubles the value.
+int twice(int value) {
+
+	return value * 2;
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+
+extern int capacity;
+
+int twice(int value);
+
+// Holds a list of values.
+class Buffer {
+public:
+	int size = 0;
+};
+
+#endif
\ No newline at end of file
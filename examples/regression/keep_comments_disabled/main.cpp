@@ -0,0 +1,8 @@
+#include "main.hpp"
+
+int capacity = 16;
+
+int twice(int value) {
+
+	return value * 2;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+
+extern int capacity;
+
+int twice(int value);
+
+class Buffer {
+public:
+	int size = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,38 @@
+#include "main.hpp"
+
+bool find_pair(std::vector<int> values, int target) {
+	bool found = false;
+	for(auto& a : values) {
+		{
+			for(auto& b : values) {
+				if(a + b == target) {
+					found = true;
+					goto outer_break;
+				}
+				if(b > target) {
+					goto outer_continue;
+				}
+				auto sum = a + b;
+				if(sum == 0) {
+					continue;
+				}
+			}
+			auto checked = a;
+		}
+		outer_continue:;
+	}
+	outer_break:;
+	while(!found) {
+		while(true) {
+			{
+				if(found) {
+					goto outer_2_break;
+				}
+				goto inner_continue;
+			}
+			inner_continue:;
+		}
+	}
+	outer_2_break:;
+	return found;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+
+bool find_pair(std::vector<int> values, int target);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+int compute(int total, int scale) {
+	std::function<int(int)> by_reference = [&](int x) -> int {
+		return x + total;
+	};
+	std::function<int(int)> by_copy = [=](int x) -> int {
+		return x * scale;
+	};
+	std::function<int(int)> mixed = [&, scale](int x) -> int {
+		return x + total * scale;
+	};
+	std::function<void()> copied = [=, &total]() {
+		total += 1;
+	};
+	return by_reference(1) + by_copy(2) + mixed(3);
+}
\ No newline at end of file
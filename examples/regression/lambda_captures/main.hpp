@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <functional>
+
+int compute(int total, int scale);
+
+#endif
\ No newline at end of file
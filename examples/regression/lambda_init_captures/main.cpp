@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+int make_counter(int start, std::vector<int> values) {
+	std::function<int(int)> scaled = [offset = start * 2](int x) -> int {
+		return x + offset;
+	};
+	std::function<int()> owner = [=, items = std::move(values)]() -> int {
+		return items.size();
+	};
+	std::function<void()> reference = [&total = start]() {
+		total += 1;
+	};
+	return scaled(1) + owner();
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <functional>
+#include <vector>
+
+int make_counter(int start, std::vector<int> values);
+
+#endif
\ No newline at end of file
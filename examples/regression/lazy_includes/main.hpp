@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <vector>
+
+double lengths(std::vector<double> values);
+
+#endif
\ No newline at end of file
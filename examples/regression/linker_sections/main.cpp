@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+__attribute__((section(".bootloader"))) int boot_flag = 1;
+
+int boot() {
+	return boot_flag;
+}
+
+__attribute__((section(".config"))) int Config::version = 2;
\ No newline at end of file
@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+#pragma section(".bootloader", read, write)
+__declspec(allocate(".bootloader")) int boot_flag = 1;
+
+int boot() {
+	return boot_flag;
+}
+
+#pragma section(".config", read, write)
+__declspec(allocate(".config")) int Config::version = 2;
\ No newline at end of file
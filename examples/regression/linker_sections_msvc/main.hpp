@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+extern int boot_flag;
+
+__declspec(code_seg(".fastcode")) int boot();
+
+class Config {
+public:
+	static int version;
+};
+
+#endif
\ No newline at end of file
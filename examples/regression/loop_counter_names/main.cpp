@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+int sum(int limit) {
+	int total = 0;
+	for(int row = 0; row < limit; row++) {
+		for(int col = limit; col > 0; col--) {
+			total += row * col;
+		}
+	}
+	for(int step = 0; step < limit; step += 3) {
+		total -= step;
+	}
+	return total;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+void walk() {
+	for(double i = 0; i < 10; i += 0.5) {
+		auto value = i;
+	}
+	for(int j = 10; j > 0; j -= 2) {
+		auto value = j;
+	}
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Buffer {
+public:
+	int size = 0;
+	int get_size();
+	int capacity = 0;
+	int get_capacity();
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int Buffer::get_size() {
+	return size;
+}
+
+int Buffer::get_capacity() {
+	return capacity;
+}
\ No newline at end of file
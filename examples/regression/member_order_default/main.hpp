@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Buffer {
+public:
+	int get_size();
+	int get_capacity();
+
+	int size = 0;
+	int capacity = 0;
+};
+
+#endif
\ No newline at end of file
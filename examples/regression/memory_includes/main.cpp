@@ -0,0 +1,7 @@
+#include "main.hpp"
+
+int make_values() {
+	std::unique_ptr<int> counter = nullptr;
+	std::tuple<int, bool> pair = std::make_tuple(1, true);
+	return 0;
+}
\ No newline at end of file
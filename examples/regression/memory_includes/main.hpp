@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <memory>
+#include <tuple>
+
+int make_values();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,8 @@
+#include "main.hpp"
+
+int use_meta() {
+	int area = 144;
+	double middle = 2.5;
+	int sign = -1;
+	return area + sign;
+}
\ No newline at end of file
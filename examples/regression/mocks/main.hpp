@@ -0,0 +1,18 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <string>
+#include <vector>
+
+int Storage_load(int& self, std::string key);
+void Storage_save(int& self, std::string key, int value);
+
+class StorageMock {
+public:
+	int load(std::string key) { calls.push_back("load"); return {}; }
+	void save(std::string key, int value) { calls.push_back("save"); }
+
+	std::vector<std::string> calls;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,7 @@
+#include "main.hpp"
+
+int Storage_load(int& self, std::string key) {
+	return 0;
+}
+
+void Storage_save(int& self, std::string key, int value) { }
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <string>
+
+int Storage_load(int& self, std::string key);
+void Storage_save(int& self, std::string key, int value);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,12 @@
+#include "out/module_init/config.hpp"
+
+int limit = 0;
+int count = 3;
+
+int load_limit() {
+	return 10;
+}
+
+void __init_module_config() {
+	limit = load_limit();
+}
\ No newline at end of file
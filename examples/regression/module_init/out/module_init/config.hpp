@@ -0,0 +1,10 @@
+#ifndef CONFIG_TASTYFILE
+#define CONFIG_TASTYFILE
+
+extern int limit;
+extern int count;
+
+int load_limit();
+void __init_module_config();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,14 @@
+#include "out/module_init/main.hpp"
+
+#include "config.hpp"
+
+int doubled = 0;
+
+int main() {
+	tasty_init_modules();
+	return doubled;
+}
+
+void __init_module_main() {
+	doubled = limit * 2;
+}
\ No newline at end of file
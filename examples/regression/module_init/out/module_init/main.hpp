@@ -0,0 +1,10 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+extern int doubled;
+
+extern void tasty_init_modules();
+int main();
+void __init_module_main();
+
+#endif
\ No newline at end of file
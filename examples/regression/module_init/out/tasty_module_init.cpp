@@ -0,0 +1,7 @@
+#include "module_init/config.hpp"
+#include "module_init/main.hpp"
+
+void tasty_init_modules() {
+	__init_module_config();
+	__init_module_main();
+}
\ No newline at end of file
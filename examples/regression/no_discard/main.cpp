@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+int compute(int value) {
+	return value * 2;
+}
+
+int caller() {
+	compute(1);
+	int kept = compute(2);
+	return kept;
+}
\ No newline at end of file
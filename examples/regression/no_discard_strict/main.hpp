@@ -0,0 +1,7 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+[[nodiscard]] int compute(int value);
+int caller();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,31 @@
+#include "main.hpp"
+
+void fail() {
+	throw std::runtime_error("failed");
+}
+
+void stop(int code) {
+	if(code > 0) {
+		std::exit(code);
+	} else {
+		abort();
+	}
+}
+
+void serve() {
+	while(true) {
+		int request = 1;
+	}
+}
+
+void maybe(int code) {
+	if(code > 0) {
+		throw std::runtime_error("bad code");
+	}
+}
+
+void escape() {
+	while(true) {
+		break;
+	}
+}
\ No newline at end of file
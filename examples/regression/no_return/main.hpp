@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <stdexcept>
+#include <cstdlib>
+
+[[noreturn]] void fail();
+[[noreturn]] void stop(int code);
+[[noreturn]] void serve();
+[[noreturn]] void maybe(int code);
+[[noreturn]] void escape();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+void walk() {
+	Node* node = new Node();
+	std::optional<int> second = (node ? (node->next ? std::make_optional(node->next->value) : std::nullopt) : std::nullopt);
+	std::optional<int> label = (node ? node->label : std::nullopt);
+	std::optional<int> first = (node ? std::make_optional(node->value) : std::nullopt);
+}
+
+void follow() {
+	std::optional<Link> link;
+	std::optional<int> value = (link.has_value() ? ((*link).node.has_value() ? std::make_optional((*(*link).node).value) : std::nullopt) : std::nullopt);
+	std::optional<int> label = (link.has_value() ? ((*link).node.has_value() ? (*(*link).node).label : std::nullopt) : std::nullopt);
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <optional>
+
+void walk();
+void follow();
+
+class Node {
+public:
+	int value = 0;
+	Node* next = nullptr;
+	std::optional<int> label;
+};
+
+class Link {
+public:
+	std::optional<Node> node;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+int compute(int a, int b, int c) {
+	int grouped = (a + b) * c;
+	int ungrouped = a + b * c;
+	int left = a - b - c;
+	int right = a - (b - c);
+	int nested = (a - b) * (c + 1) / (a % b);
+	bool chained = b > a && b < c;
+	auto compared = (b > a && b < c) == false;
+	auto negated = !(a < b && b < c);
+	int picked = (a > b ? a : b) + c;
+	return grouped + ungrouped + left + right + nested;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int compute(int a, int b, int c);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,22 @@
+#include "main.hpp"
+
+Vector Vector::operator+(Vector& other) {
+	return *this;
+}
+
+double Vector::operator*(int scale) {
+	return x * scale;
+}
+
+std::strong_ordering Vector::operator<=>(Vector& other) {
+	return x <=> other.x;
+}
+
+double combine() {
+	Vector first;
+	Vector second;
+	Vector sum = first + second;
+	double scaled = first * 2;
+	bool smaller = first < second;
+	return scaled;
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <compare>
+
+double combine();
+
+class Vector {
+public:
+	Vector operator+(Vector& other);
+	double operator*(int scale);
+	std::strong_ordering operator<=>(Vector& other);
+
+	int x = 0;
+	int y = 0;
+};
+
+#endif
\ No newline at end of file
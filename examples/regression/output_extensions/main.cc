@@ -0,0 +1,5 @@
+#include "main.hh"
+
+void Counter::increment() {
+	count++;
+}
\ No newline at end of file
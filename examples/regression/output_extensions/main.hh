@@ -0,0 +1,11 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Counter {
+public:
+	void increment();
+
+	int count = 0;
+};
+
+#endif
\ No newline at end of file
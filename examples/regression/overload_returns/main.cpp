@@ -0,0 +1,14 @@
+#include "main.hpp"
+
+double convert(int value) {
+	return value * 0.5;
+}
+
+int convert(bool flag) {
+	return 1;
+}
+
+void use_overloads() {
+	double half = convert(3);
+	int count = convert(true);
+}
\ No newline at end of file
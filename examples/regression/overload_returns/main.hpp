@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+double convert(int value);
+int convert(bool flag);
+void use_overloads();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,16 @@
+#include "main.hpp"
+
+double read(int* value) {
+	return 0.5;
+}
+
+int read(const int& value) {
+	return value;
+}
+
+void use_overloads() {
+	int number = 3;
+	int* address = nullptr;
+	int from_value = read(number);
+	double from_pointer = read(address);
+}
\ No newline at end of file
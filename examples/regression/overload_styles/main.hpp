@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+double read(int* value);
+int read(const int& value);
+void use_overloads();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+int Shape::area() {
+	return 0;
+}
+
+int Shape::scale(int factor) {
+	return factor;
+}
+
+int Square::area() {
+	return side * side;
+}
+
+int BigSquare::scale(int factor) {
+	return factor * 2;
+}
\ No newline at end of file
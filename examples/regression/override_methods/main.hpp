@@ -0,0 +1,22 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+class Shape {
+public:
+	virtual int area();
+	virtual int scale(int factor);
+};
+
+class Square: public Shape {
+public:
+	int area() override;
+
+	int side = 0;
+};
+
+class BigSquare: public Square {
+public:
+	int scale(int factor) override;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,14 @@
+#include "app.hpp"
+
+#include "middle.hpp"
+
+double app_value() {
+	double value = base_value();
+	int count = middle_value();
+	return value * count;
+}
+
+int app_sides() {
+	Shape shape;
+	return shape.sides;
+}
\ No newline at end of file
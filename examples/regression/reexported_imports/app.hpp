@@ -0,0 +1,7 @@
+#ifndef APP_TASTYFILE
+#define APP_TASTYFILE
+
+double app_value();
+int app_sides();
+
+#endif
\ No newline at end of file
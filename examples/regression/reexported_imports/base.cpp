@@ -0,0 +1,5 @@
+#include "base.hpp"
+
+double base_value() {
+	return 1.5;
+}
\ No newline at end of file
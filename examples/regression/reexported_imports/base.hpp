@@ -0,0 +1,11 @@
+#ifndef BASE_TASTYFILE
+#define BASE_TASTYFILE
+
+double base_value();
+
+class Shape {
+public:
+	int sides = 0;
+};
+
+#endif
\ No newline at end of file
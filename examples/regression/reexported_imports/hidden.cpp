@@ -0,0 +1,7 @@
+#include "hidden.hpp"
+
+#include "base.hpp"
+
+int hidden_value() {
+	return 3;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef HIDDEN_TASTYFILE
+#define HIDDEN_TASTYFILE
+
+int hidden_value();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,5 @@
+#include "middle.hpp"
+
+int middle_value() {
+	return 2;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MIDDLE_TASTYFILE
+#define MIDDLE_TASTYFILE
+
+#include "base.hpp"
+
+int middle_value();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,6 @@
+#include "main.hpp"
+
+double to_bytes(int* value, int count) {
+	char* bytes = reinterpret_cast<char*>(value);
+	return static_cast<double>(count);
+}
\ No newline at end of file
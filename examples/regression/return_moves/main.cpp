@@ -0,0 +1,15 @@
+#include "main.hpp"
+
+std::string make_name() {
+	std::string result = "local";
+	return result;
+}
+
+std::string forward_name(std::string&& value) {
+	return std::move(value);
+}
+
+std::string take_name() {
+	Holder holder;
+	return std::move(holder.name);
+}
\ No newline at end of file
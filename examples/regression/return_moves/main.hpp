@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <utility>
+#include <string>
+
+std::string make_name();
+std::string forward_name(std::string&& value);
+std::string take_name();
+
+class Holder {
+public:
+	std::string name;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,17 @@
+#include "main.hpp"
+
+int check(bool a, bool b, bool c) {
+	if(!a || !b) {
+		return 1;
+	}
+	if(!a && (!b || !c)) {
+		return 2;
+	}
+	if(!a) {
+		return 3;
+	}
+	while(a && b) {
+		a = !a;
+	}
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int check(bool a, bool b, bool c);
+
+#endif
\ No newline at end of file
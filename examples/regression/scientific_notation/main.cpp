@@ -0,0 +1,12 @@
+#include "main.hpp"
+
+int main() {
+	double large = 1.5e10;
+	double small = 3E-4;
+	double positive = 2e+3;
+	float single = 2.5e3f;
+	long double extended = 1e5l;
+	int hex = 0x1e2;
+	int hex_upper = 0x1E;
+	return hex + hex_upper;
+}
\ No newline at end of file
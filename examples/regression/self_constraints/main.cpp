@@ -0,0 +1,19 @@
+#include "main.hpp"
+
+void Buffer::resize(int amount) & {
+	size = amount;
+}
+
+int Buffer::length() const& {
+	return size;
+}
+
+int Buffer::take() && {
+	return size;
+}
+
+int use_buffer() {
+	Buffer buffer;
+	buffer.resize(10);
+	return buffer.length() + (Buffer()).take();
+}
\ No newline at end of file
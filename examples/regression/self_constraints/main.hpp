@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int use_buffer();
+
+class Buffer {
+public:
+	void resize(int amount) &;
+	int length() const&;
+	int take() &&;
+
+	int size = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,12 @@
+#include "main.hpp"
+
+int Point::count = 0;
+
+void to_json(nlohmann::json& j, const Point& value) {
+	j = nlohmann::json{ { "x", value.x }, { "y", value.y } };
+}
+
+void from_json(const nlohmann::json& j, Point& value) {
+	j.at("x").get_to(value.x);
+	j.at("y").get_to(value.y);
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <nlohmann/json.hpp>
+
+class Point {
+public:
+	int x = 0;
+	double y = 0;
+	static int count;
+};
+
+void to_json(nlohmann::json& j, const Point& value);
+void from_json(const nlohmann::json& j, Point& value);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+void to_json(nlohmann::ordered_json& j, const Settings& value) {
+	j = nlohmann::ordered_json{ { "volume", value.volume }, { "muted", value.muted } };
+}
+
+void from_json(const nlohmann::ordered_json& j, Settings& value) {
+	j.at("volume").get_to(value.volume);
+	j.at("muted").get_to(value.muted);
+}
\ No newline at end of file
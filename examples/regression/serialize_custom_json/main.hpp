@@ -0,0 +1,15 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <json.hpp>
+
+class Settings {
+public:
+	int volume = 0;
+	bool muted = false;
+};
+
+void to_json(nlohmann::ordered_json& j, const Settings& value);
+void from_json(const nlohmann::ordered_json& j, Settings& value);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,6 @@
+#include "main.hpp"
+
+double outer_weight(const T& item) {
+	double weight = item.weight;
+	return weight;
+}
\ No newline at end of file
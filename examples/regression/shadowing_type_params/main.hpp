@@ -0,0 +1,17 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+template<typename T>
+T heaviest(const T& a, const T& b) {
+	auto first = a.weight;
+	return first > b.weight ? a : b;
+}
+
+double outer_weight(const T& item);
+
+class T {
+public:
+	double weight = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+int run(int x) {
+	int grouped = (x);
+	std::tuple<int> single = std::make_tuple(x);
+	std::tuple<int> spaced = std::make_tuple(x + 1);
+	std::tuple<int, double> pair = std::make_tuple(x, 2.5);
+	return grouped * (x + 1);
+}
\ No newline at end of file
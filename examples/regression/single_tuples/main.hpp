@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <tuple>
+
+int run(int x);
+
+#endif
\ No newline at end of file
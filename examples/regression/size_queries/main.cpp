@@ -0,0 +1,10 @@
+#include "main.hpp"
+
+size_t sizes() {
+	double value = 1.0;
+	size_t int_size = sizeof(int);
+	size_t value_size = sizeof(value);
+	size_t value_align = alignof(decltype(value));
+	size_t total = int_size + value_size * 2;
+	return total + value_align;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <stddef.h>
+
+size_t sizes();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+std::strong_ordering Version::operator<=>(Version& other) {
+	return major <=> other.major;
+}
+
+bool Version::operator==(Version& other) {
+	return major == other.major && minor == other.minor;
+}
+
+std::partial_ordering Score::operator<=>(Score& other) {
+	return value <=> other.value;
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <compare>
+
+class Version {
+public:
+	std::strong_ordering operator<=>(Version& other);
+	bool operator==(Version& other);
+
+	int major = 0;
+	int minor = 0;
+};
+
+class Score {
+public:
+	std::partial_ordering operator<=>(Score& other);
+
+	double value = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+eastl::tuple<int, int> describe(eastl::string_view name) {
+	return eastl::make_tuple(1, 2);
+}
+
+int first_of(const eastl::tuple<int, int>& pair) {
+	return eastl::get<0>(pair);
+}
+
+std::string forward_name(std::string&& value) {
+	return eastl::move(value);
+}
\ No newline at end of file
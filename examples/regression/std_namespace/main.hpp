@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <string_view>
+#include <tuple>
+#include <utility>
+#include <string>
+
+eastl::tuple<int, int> describe(eastl::string_view name);
+int first_of(const eastl::tuple<int, int>& pair);
+std::string forward_name(std::string&& value);
+
+#endif
\ No newline at end of file
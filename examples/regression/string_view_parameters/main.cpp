@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+void Greeter::greet(std::string_view name) {
+	std::cout << greeting << name;
+}
+
+size_t length_of(std::string_view value) {
+	return value.size();
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <string_view>
+#include <stddef.h>
+#include <iostream>
+
+size_t length_of(std::string_view value);
+
+class Greeter {
+public:
+	void greet(std::string_view name);
+
+	const char* greeting = "";
+};
+
+#endif
\ No newline at end of file
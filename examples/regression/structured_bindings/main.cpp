@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int unpack() {
+	std::tuple<int, bool> pair = std::make_tuple(1, true);
+	auto [count, flag] = pair;
+	std::tuple<int, double, bool> triple = std::make_tuple(2, 3.5, false);
+	auto [index, scale, enabled] = triple;
+	double total = scale;
+	if(flag && enabled) {
+		return count;
+	}
+	return index;
+}
\ No newline at end of file
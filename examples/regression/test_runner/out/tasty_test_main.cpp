@@ -0,0 +1,24 @@
+#include <cstdio>
+#include <exception>
+
+#include "test_runner/main.hpp"
+
+int main() {
+	int failed = 0;
+	try {
+		test_addition();
+		std::printf("[PASS] %s\n", "addition works");
+	} catch(...) {
+		failed++;
+		std::printf("[FAIL] %s\n", "addition works");
+	}
+	try {
+		test_nothing();
+		std::printf("[PASS] %s\n", "nothing throws");
+	} catch(...) {
+		failed++;
+		std::printf("[FAIL] %s\n", "nothing throws");
+	}
+	std::printf("%d of 2 tests passed\n", 2 - failed);
+	return failed;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#include "out/test_runner/main.hpp"
+
+void test_addition() {
+	int sum = 1 + 2;
+}
+
+void test_nothing() { }
+
+int helper() {
+	return 3;
+}
\ No newline at end of file
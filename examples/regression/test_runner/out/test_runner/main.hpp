@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+void test_addition();
+void test_nothing();
+int helper();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,19 @@
+#include "main.hpp"
+
+void Mutex::lock() {
+	handle.lock();
+}
+
+bool Mutex::try_lock() {
+	return handle.try_lock();
+}
+
+void Mutex::unlock() {
+	handle.unlock();
+}
+
+void Account::deposit(int amount) {
+	mutex.lock();
+	balance += amount;
+	mutex.unlock();
+}
\ No newline at end of file
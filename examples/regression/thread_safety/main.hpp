@@ -0,0 +1,23 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <mutex>
+
+class __attribute__((capability("mutex"))) Mutex {
+public:
+	void lock() __attribute__((acquire_capability()));
+	bool try_lock() __attribute__((try_acquire_capability(true)));
+	void unlock() __attribute__((release_capability()));
+
+	std::mutex handle;
+};
+
+class Account {
+public:
+	void deposit(int amount);
+
+	Mutex mutex;
+	__attribute__((guarded_by(mutex))) int balance = 0;
+};
+
+#endif
\ No newline at end of file
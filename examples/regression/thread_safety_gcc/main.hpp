@@ -0,0 +1,23 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <mutex>
+
+class Mutex {
+public:
+	void lock();
+	bool try_lock();
+	void unlock();
+
+	std::mutex handle;
+};
+
+class Account {
+public:
+	void deposit(int amount);
+
+	Mutex mutex;
+	int balance = 0;
+};
+
+#endif
\ No newline at end of file
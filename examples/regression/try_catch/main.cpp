@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int parse_or_default(const char* input) {
+	try {
+		throw std::runtime_error(input);
+	} catch(const std::runtime_error& e) {
+		std::cout << e.what();
+		return 1;
+	} catch(...) {
+		return 0;
+	}
+	return 2;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <exception>
+#include <stdexcept>
+#include <iostream>
+
+int parse_or_default(const char* input);
+
+#endif
\ No newline at end of file
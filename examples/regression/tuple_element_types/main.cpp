@@ -0,0 +1,8 @@
+#include "main.hpp"
+
+int main() {
+	std::tuple<int, double> pair = std::make_tuple(3, 2.5);
+	int count = std::get<0>(pair);
+	double ratio = std::get<1>(pair);
+	return count;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#include "main.hpp"
+
+std::tuple<int, int> divmod(int value, int divisor) {
+	return std::make_tuple(value / divisor, value % divisor);
+}
+
+std::tuple<int, int, bool> bounds(int a, int b) {
+	return std::make_tuple(a, b, a < b);
+}
+
+int run() {
+	auto [q, r] = divmod(17, 5);
+	auto [low, high, ordered] = bounds(q, r);
+	return low + high;
+}
\ No newline at end of file
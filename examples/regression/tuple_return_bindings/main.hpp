@@ -0,0 +1,10 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <tuple>
+
+std::tuple<int, int> divmod(int value, int divisor);
+std::tuple<int, int, bool> bounds(int a, int b);
+int run();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#include "main.hpp"
+
+std::tuple<int, int> divide(int a, int b) {
+	return std::make_tuple(a / b, a % b);
+}
+
+std::tuple<int, double> measure(int width) {
+	return std::make_tuple(width, 2.5);
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <tuple>
+
+std::tuple<int, int> divide(int a, int b);
+std::tuple<int, double> measure(int width);
+
+#endif
\ No newline at end of file
@@ -0,0 +1,11 @@
+#include "main.hpp"
+
+int buffer_size;
+int default_size = 0;
+
+int fill() {
+	int scratch;
+	int counter = 0;
+	scratch = 4;
+	return scratch + counter;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+extern int buffer_size;
+extern int default_size;
+
+int fill();
+
+#endif
\ No newline at end of file
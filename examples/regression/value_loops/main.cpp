@@ -0,0 +1,24 @@
+#include "main.hpp"
+
+int first_square_above(int limit) {
+	int current = 0;
+	int found = [&]() {
+		while(true) {
+			current += 1;
+			if(current * current > limit) {
+				return current * current;
+			}
+		}
+	}();
+	double label = [&]() {
+		while(true) {
+			for(int i = 0; i < 10; i++) {
+				if(i == 5) {
+					break;
+				}
+			}
+			return 2;
+		}
+	}();
+	return found;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#include "main.hpp"
+
+int limit = 10;
+
+void Counter::increment() {
+	count++;
+}
+
+void Counter::reset() {
+	count = 0;
+}
+
+int main() {
+	return limit;
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+extern int limit;
+
+int main();
+
+class Counter {
+public:
+	void increment();
+	void reset();
+
+	int count = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+__attribute__((visibility("default"))) int create_widget();
+__attribute__((visibility("hidden"))) int internal_helper();
+__attribute__((visibility("hidden"))) int plain();
+
+class __attribute__((visibility("default"))) Widget {
+public:
+	int size = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,13 @@
+#include "main.hpp"
+
+int create_widget() {
+	return 1;
+}
+
+int internal_helper() {
+	return 2;
+}
+
+int plain() {
+	return 3;
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+__declspec(dllexport) int create_widget();
+int internal_helper();
+int plain();
+
+class __declspec(dllexport) Widget {
+public:
+	int size = 0;
+};
+
+#endif
\ No newline at end of file
@@ -0,0 +1,16 @@
+#include "main.hpp"
+
+int compute() {
+	return 3;
+}
+
+int run() {
+	int unused = 5;
+	int written = 1;
+	written = 2;
+	int effect = compute();
+	int total = 0;
+	total = 4;
+	int result = total + 1;
+	return result;
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+int compute();
+int run();
+
+#endif
\ No newline at end of file
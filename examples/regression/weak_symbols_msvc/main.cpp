@@ -0,0 +1,7 @@
+#include "main.hpp"
+
+int log_level = 1;
+
+int on_startup() {
+	return 0;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+extern int log_level;
+
+int on_startup();
+
+#endif
\ No newline at end of file
@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <concepts>
+
+template<typename T> requires std::integral<T>
+T add(T a, T b) {
+	return a + b;
+}
+
+template<typename T, typename U> requires std::signed_integral<T> && std::copyable<T> && std::floating_point<U>
+T scale(T value, U factor) {
+	return value * factor;
+}
+
+#endif
\ No newline at end of file
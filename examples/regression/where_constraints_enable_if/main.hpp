@@ -0,0 +1,16 @@
+#ifndef MAIN_TASTYFILE
+#define MAIN_TASTYFILE
+
+#include <type_traits>
+
+template<typename T, typename = std::enable_if_t<std::is_integral<T>::value>>
+T add(T a, T b) {
+	return a + b;
+}
+
+template<typename T, typename U, typename = std::enable_if_t<std::is_integral<T>::value && std::is_signed<T>::value && std::is_floating_point<U>::value>>
+T scale(T value, U factor) {
+	return value * factor;
+}
+
+#endif
\ No newline at end of file
@@ -18,7 +18,236 @@ use std::io::prelude::*;
 pub struct ConfigData {
 	pub operators: OperatorDataStructure,
 	pub pragma_guard: bool,
-	pub hpp_headers: bool
+	pub hpp_headers: bool,
+	pub source_ext: String,
+	pub header_ext: String,
+	pub library: LibraryNames,
+	pub include_prefix: String,
+	pub checked_casts: bool,
+	pub allow_reinterpret: bool,
+	pub immutable_let: bool,
+	pub align_assignments: bool,
+	pub strict: bool,
+	pub preserve_member_order: bool,
+	pub fold_constants: bool,
+	pub module_init: bool,
+	pub emit_mocks: bool,
+	pub warn_unused: bool,
+	pub keep_comments: bool,
+	pub infer_const_methods: bool,
+	pub auto_constexpr: bool,
+	pub json_type: String,
+	pub json_include: String,
+	pub constraint_style: ConstraintStyle,
+	pub include_order: IncludeOrder,
+	pub target: CompilerTarget,
+	pub line_ending: LineEnding,
+	pub default_visibility: Visibility,
+	pub brace_style: BraceStyle,
+	pub max_errors: usize
+}
+
+/// The names used for the library types and functions in generated code, such as the
+/// standard library namespace and the smart pointer used for the `classptr` style.
+/// These are copied into each `Context` so type output can use them.
+#[derive(Clone)]
+pub struct LibraryNames {
+	pub std_namespace: String,
+	pub class_ptr_type: String,
+	pub class_ptr_include: String
+}
+
+impl LibraryNames {
+	pub fn new() -> LibraryNames {
+		LibraryNames {
+			std_namespace: "std".to_string(),
+			class_ptr_type: "boost::intrusive_ptr".to_string(),
+			class_ptr_include: "boost/intrusive_ptr.hpp".to_string()
+		}
+	}
+
+	/// Qualifies a standard library name with the configured namespace.
+	///
+	/// # Arguments
+	///
+	/// * `name` - The unqualified name, such as `make_shared`.
+	///
+	/// # Return
+	///
+	/// The qualified name, such as `std::make_shared`.
+	pub fn std_name(&self, name: &str) -> String {
+		format!("{}::{}", self.std_namespace, name)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum IncludeOrder {
+	SystemFirst,
+	LocalFirst,
+	Alphabetical
+}
+
+impl IncludeOrder {
+	pub fn new(name: &str) -> Option<IncludeOrder> {
+		match name {
+			"system-first" => Some(IncludeOrder::SystemFirst),
+			"local-first" => Some(IncludeOrder::LocalFirst),
+			"alphabetical" => Some(IncludeOrder::Alphabetical),
+			_ => None
+		}
+	}
+
+	pub fn from_module_attribute(attribute: &str) -> Option<IncludeOrder> {
+		match attribute {
+			"IncludeSystemFirst" => Some(IncludeOrder::SystemFirst),
+			"IncludeLocalFirst" => Some(IncludeOrder::LocalFirst),
+			"IncludeAlphabetical" => Some(IncludeOrder::Alphabetical),
+			_ => None
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CompilerTarget {
+	Gnu,
+	Clang,
+	Msvc
+}
+
+impl CompilerTarget {
+	pub fn new(name: &str) -> Option<CompilerTarget> {
+		match name {
+			"gcc" => Some(CompilerTarget::Gnu),
+			"clang" => Some(CompilerTarget::Clang),
+			"msvc" => Some(CompilerTarget::Msvc),
+			_ => None
+		}
+	}
+
+	pub fn is_msvc(&self) -> bool {
+		*self == CompilerTarget::Msvc
+	}
+
+	/// Only Clang supports the thread-safety analysis used by `@ThreadSafe` and `@GuardedBy`.
+	pub fn is_clang(&self) -> bool {
+		*self == CompilerTarget::Clang
+	}
+
+	/// Generates the default command used to compile a source file for `--emit-compile-commands`.
+	pub fn compile_command(&self, source_path: &str, include_dirs: &Vec<String>) -> String {
+		let (compiler, standard, include_flag, compile_flag) = match self {
+			CompilerTarget::Gnu => ("g++", "-std=c++20", "-I", "-c"),
+			CompilerTarget::Clang => ("clang++", "-std=c++20", "-I", "-c"),
+			CompilerTarget::Msvc => ("cl", "/std:c++20", "/I", "/c")
+		};
+		let mut command = format!("{} {}", compiler, standard);
+		for dir in include_dirs {
+			command += &format!(" {}\"{}\"", include_flag, dir);
+		}
+		format!("{} {} \"{}\"", command, compile_flag, source_path)
+	}
+
+	/// Generates the specifier for a calling convention used by `@CallConv`.
+	/// Returns `None` if the convention is not known.
+	pub fn calling_convention(&self, name: &str) -> Option<String> {
+		match name {
+			"cdecl" | "stdcall" | "fastcall" | "thiscall" | "vectorcall" => Some(if self.is_msvc() {
+				format!("__{}", name)
+			} else {
+				format!("__attribute__(({}))", name)
+			}),
+			_ => None
+		}
+	}
+
+	/// Generates the specifier for a symbol's visibility used by `@Export` and `@Hidden`.
+	/// MSVC hides symbols unless they are exported, so hidden symbols do not need a specifier.
+	pub fn visibility(&self, visibility: Visibility) -> Option<&'static str> {
+		match (self, visibility) {
+			(CompilerTarget::Gnu, Visibility::Default) | (CompilerTarget::Clang, Visibility::Default) => Some("__attribute__((visibility(\"default\")))"),
+			(CompilerTarget::Gnu, Visibility::Hidden) | (CompilerTarget::Clang, Visibility::Hidden) => Some("__attribute__((visibility(\"hidden\")))"),
+			(CompilerTarget::Msvc, Visibility::Default) => Some("__declspec(dllexport)"),
+			(CompilerTarget::Msvc, Visibility::Hidden) => None
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Visibility {
+	Default,
+	Hidden
+}
+
+impl Visibility {
+	pub fn new(name: &str) -> Option<Visibility> {
+		match name {
+			"default" => Some(Visibility::Default),
+			"hidden" => Some(Visibility::Hidden),
+			_ => None
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BraceStyle {
+	KAndR,
+	Allman
+}
+
+impl BraceStyle {
+	pub fn new(name: &str) -> Option<BraceStyle> {
+		match name {
+			"k&r" | "kr" => Some(BraceStyle::KAndR),
+			"allman" => Some(BraceStyle::Allman),
+			_ => None
+		}
+	}
+
+	pub fn is_allman(&self) -> bool {
+		*self == BraceStyle::Allman
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LineEnding {
+	Lf,
+	Crlf
+}
+
+impl LineEnding {
+	pub fn new(name: &str) -> Option<LineEnding> {
+		match name {
+			"lf" => Some(LineEnding::Lf),
+			"crlf" => Some(LineEnding::Crlf),
+			_ => None
+		}
+	}
+
+	/// Joins output lines using this line ending. Lines that contain
+	/// line breaks of their own are converted as well.
+	pub fn join(&self, lines: &[String]) -> String {
+		let content = lines.join("\n");
+		match self {
+			LineEnding::Lf => content,
+			LineEnding::Crlf => content.replace("\r\n", "\n").replace('\n', "\r\n")
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ConstraintStyle {
+	Requires,
+	EnableIf
+}
+
+impl ConstraintStyle {
+	pub fn new(name: &str) -> Option<ConstraintStyle> {
+		match name {
+			"requires" => Some(ConstraintStyle::Requires),
+			"enable-if" => Some(ConstraintStyle::EnableIf),
+			_ => None
+		}
+	}
 }
 
 impl ConfigData {
@@ -26,7 +255,33 @@ impl ConfigData {
 		return ConfigData {
 			operators: BTreeMap::new(),
 			pragma_guard: false,
-			hpp_headers: true
+			hpp_headers: true,
+			source_ext: "cpp".to_string(),
+			header_ext: "hpp".to_string(),
+			library: LibraryNames::new(),
+			include_prefix: "".to_string(),
+			checked_casts: false,
+			allow_reinterpret: false,
+			immutable_let: false,
+			align_assignments: false,
+			strict: false,
+			preserve_member_order: false,
+			fold_constants: false,
+			module_init: false,
+			emit_mocks: false,
+			warn_unused: false,
+			keep_comments: false,
+			infer_const_methods: false,
+			auto_constexpr: false,
+			json_type: "nlohmann::json".to_string(),
+			json_include: "nlohmann/json.hpp".to_string(),
+			constraint_style: ConstraintStyle::Requires,
+			include_order: IncludeOrder::SystemFirst,
+			target: CompilerTarget::Gnu,
+			line_ending: LineEnding::Lf,
+			default_visibility: Visibility::Default,
+			brace_style: BraceStyle::KAndR,
+			max_errors: 20
 		};
 	}
 }
@@ -47,30 +302,40 @@ pub fn read_file(path: &str) -> std::io::Result<String> {
 	Ok(result)
 }
 
-/// Reads a text file and returns the contents as a `String`.
+/// Reads the configuration files and returns the data they contain.
+/// If a configuration directory is provided, it must contain every configuration file.
+/// Otherwise, the `config` directory next to the executable is used,
+/// followed by the `config` directory in the working directory.
 ///
 /// # Arguments
 ///
-/// * `path` - The path of the file.
+/// * `config_dir` - The directory provided with `--config-dir`, if any.
 ///
 /// # Return
 ///
-/// The contents of the file.
-pub fn read_config_files() -> ConfigData {
-	return ConfigData {
-		operators: {
+/// The configuration data, or a message describing why it could not be read.
+pub fn read_config_files(config_dir: Option<&str>) -> Result<ConfigData, String> {
+	let operators_path = match config_dir {
+		Some(dir) => {
+			let path = std::path::Path::new(dir).join("operators.json");
+			if !path.is_file() {
+				return Err(format!("Config directory \"{}\" does not contain operators.json", dir));
+			}
+			path.to_string_lossy().to_string()
+		},
+		None => {
 			let mut dir = std::env::current_exe().expect("Could not get executable directory.");
 			dir.pop();
 			dir.push("config");
 			dir.push("operators.json");
-			let loc = dir.as_path().as_os_str().to_str().unwrap();
-			if std::path::Path::new(loc).exists() {
-				parse_operators_json(loc)
+			if dir.exists() {
+				dir.to_string_lossy().to_string()
 			} else {
-				parse_operators_json("config/operators.json")
+				"config/operators.json".to_string()
 			}
-		},
-		pragma_guard: false,
-		hpp_headers: true
+		}
 	};
+	let mut data = ConfigData::new();
+	data.operators = parse_operators_json(&operators_path)?;
+	Ok(data)
 }
@@ -24,6 +24,9 @@ pub struct Operator {
 
 pub type OperatorDataStructure = BTreeMap<String,Vec<Operator>>;
 
+/// The index of the `as` cast within the infix operators of `operators.json`.
+pub const AS_OPERATOR_ID: usize = 42;
+
 /// Parses the operator JSON data to a native Rust structure.
 ///
 /// # Arguments
@@ -33,10 +36,10 @@ pub type OperatorDataStructure = BTreeMap<String,Vec<Operator>>;
 /// # Return
 ///
 /// An instance of OperatorDataStructure containing all the
-/// information.
-pub fn parse_operators_json(path: &str) -> OperatorDataStructure {
-	let json_str = read_file(path).unwrap();
-	let operators_json: Map<String,Value> = serde_json::from_str(json_str.as_str()).unwrap();
+/// information, or a message if the file could not be read.
+pub fn parse_operators_json(path: &str) -> Result<OperatorDataStructure, String> {
+	let json_str = read_file(path).map_err(|e| format!("Could not read \"{}\": {}", path, e))?;
+	let operators_json: Map<String,Value> = serde_json::from_str(json_str.as_str()).map_err(|e| format!("Could not parse \"{}\": {}", path, e))?;
 	let mut operators = BTreeMap::new();
 	for op_key in operators_json.keys() {
 		let mut result = Vec::new();
@@ -76,5 +79,5 @@ pub fn parse_operators_json(path: &str) -> OperatorDataStructure {
 		}
 		operators.insert(op_key.to_string(), result);
 	}
-	return operators;
+	Ok(operators)
 }
@@ -0,0 +1,54 @@
+/**********************************************************
+ * --- Class Members ---
+ *
+ * Tracks the members of the class being transpiled so
+ * `--infer-const-methods` can find the functions that
+ * may modify their object.
+ **********************************************************/
+
+use crate::expression::value_type::Function;
+use crate::expression::variable_type::VarStyle;
+use crate::expression::function_type::FunStyle;
+
+#[derive(Clone)]
+pub struct ClassMembers {
+	pub variables: Vec<String>,
+	pub functions: Vec<(String, bool)>
+}
+
+impl ClassMembers {
+	pub fn new() -> ClassMembers {
+		ClassMembers {
+			variables: Vec::new(),
+			functions: Vec::new()
+		}
+	}
+
+	pub fn clear(&mut self) {
+		self.variables.clear();
+		self.functions.clear();
+	}
+
+	/// Adds a function, which can be called on a const object if it is static, const, or borrows `self`.
+	pub fn add_function(&mut self, func: &Function) {
+		let is_const = func.styles.contains(&FunStyle::Static) || func.is_const || func.self_style == Some(VarStyle::Borrow);
+		self.functions.push((func.name.clone(), is_const));
+	}
+
+	/// Marks the first function with the name that is not const as const.
+	pub fn set_const_function(&mut self, name: &str) {
+		if let Some(func) = self.functions.iter_mut().find(|f| f.0 == name && !f.1) {
+			func.1 = true;
+		}
+	}
+
+	/// Checks whether every function with the name can be called on a const object.
+	/// Returns `None` if the class has no function with the name.
+	pub fn is_const_function(&self, name: &str) -> Option<bool> {
+		let mut result = None;
+		for func in self.functions.iter().filter(|f| f.0 == name) {
+			result = Some(result.unwrap_or(true) && func.1);
+		}
+		result
+	}
+}
@@ -5,13 +5,19 @@
  * depending on the usage of certain classes and functions.
  **********************************************************/
 
-use crate::context_management::typing_context::TypingContext;
+use crate::context_management::typing_context::{ TypingContext, ContextType };
 use crate::context_management::header_context::HeaderContext;
 use crate::context_management::static_extension::{ StaticExtensionContext, StaticExtension };
 use crate::context_management::context_manager::ContextManager;
+use crate::context_management::class_members::ClassMembers;
+
+use crate::config_management::{ IncludeOrder, BraceStyle, LibraryNames };
 
 use crate::expression::variable_type::{ VariableType, Type, VarStyle };
-use crate::expression::value_type::NumberType;
+use crate::expression::value_type::{ NumberType, Function };
+use crate::expression::const_eval::MetaFunction;
+
+use std::collections::BTreeMap;
 
 pub struct Context {
 	pub typing: TypingContext,
@@ -19,11 +25,27 @@ pub struct Context {
 	pub headers: HeaderContext,
 	pub static_extends: StaticExtensionContext,
 	pub shared_modules: Vec<String>,
+	pub reexported_modules: Vec<String>,
+	pub namespaces: Vec<(String, bool)>,
 	pub align_lines: bool,
+	pub checked_casts: bool,
+	pub allow_reinterpret: bool,
+	pub immutable_let: bool,
+	pub brace_style: BraceStyle,
+	pub library: LibraryNames,
+	pub include_order: Option<IncludeOrder>,
 	pub convert_this_to_self: bool,
+	pub forward_functions: Vec<String>,
 	pub is_class: bool,
+	pub base_functions: Vec<Function>,
+	pub class_members: ClassMembers,
 	pub is_constructor: Option<(Vec<String>,Option<String>)>,
-	pub ltype: Option<VariableType>
+	pub ltype: Option<VariableType>,
+	pub loop_labels: Vec<(String, String)>,
+	pub function_labels: Vec<String>,
+	pub loop_value_type: Option<VariableType>,
+	pub function_return_type: Option<VariableType>,
+	pub meta_functions: BTreeMap<String, MetaFunction>
 }
 
 impl Context {
@@ -34,35 +56,219 @@ impl Context {
 			headers: HeaderContext::new(),
 			static_extends: StaticExtensionContext::new(),
 			shared_modules: Vec::new(),
+			reexported_modules: Vec::new(),
+			namespaces: Vec::new(),
 			align_lines: false,
+			checked_casts: false,
+			allow_reinterpret: false,
+			immutable_let: false,
+			brace_style: BraceStyle::KAndR,
+			library: LibraryNames::new(),
+			include_order: None,
 			convert_this_to_self: false,
+			forward_functions: Vec::new(),
 			is_class: false,
+			base_functions: Vec::new(),
+			class_members: ClassMembers::new(),
 			is_constructor: None,
-			ltype: None
+			ltype: None,
+			loop_labels: Vec::new(),
+			function_labels: Vec::new(),
+			loop_value_type: None,
+			function_return_type: None,
+			meta_functions: BTreeMap::new()
 		}
 	}
 
+	/// Declares a loop label, returning the name used for it in C++.
+	/// C++ labels are shared by the whole function, so reused names are numbered.
+	pub fn push_loop_label(&mut self, name: &str) -> String {
+		let mut result = name.to_string();
+		let mut count = 1;
+		while self.function_labels.contains(&result) {
+			count += 1;
+			result = format!("{}_{}", name, count);
+		}
+		self.function_labels.push(result.clone());
+		self.loop_labels.push((name.to_string(), result.clone()));
+		result
+	}
+
+	pub fn pop_loop_label(&mut self) {
+		self.loop_labels.pop();
+	}
+
+	/// Retrieves the C++ name of the innermost enclosing loop with the label.
+	pub fn find_loop_label(&self, name: &str) -> Option<String> {
+		self.loop_labels.iter().rev().find(|label| label.0 == name).map(|label| label.1.clone())
+	}
+
+	/// Checks whether opening braces should be placed on their own line.
+	/// Aligned lines keep the braces on the same line to preserve the line numbers.
+	pub fn uses_allman_braces(&self) -> bool {
+		self.brace_style.is_allman() && !self.align_lines
+	}
+
 	pub fn import_module(&mut self, ctx_module: String) {
 		self.shared_modules.push(ctx_module);
 	}
 
+	pub fn reexport_module(&mut self, ctx_module: String) {
+		self.reexported_modules.push(ctx_module);
+	}
+
+	/// Retrieves the imported modules along with the modules they re-export, transitively.
+	pub fn get_visible_modules(&self, manager: &ContextManager) -> Vec<String> {
+		Self::with_reexports(self.shared_modules.clone(), manager)
+	}
+
+	/// Finds an item through a visible module, such as `Shape` in `middle::Shape`.
+	/// Only the module's own items and the modules it re-exports are searched.
+	pub fn find_module_item(&self, module_name: &str, name: &str, manager: &ContextManager) -> Option<ContextType> {
+		let suffix = format!("/{}", module_name);
+		let module = self.get_visible_modules(manager).into_iter().find(|m| m == module_name || m.ends_with(&suffix))?;
+		for exported in Self::with_reexports(vec![module], manager) {
+			let item = manager.get_context_immut(&exported).module.get_item(name, None, Some(manager), true);
+			if item.is_some() {
+				return item;
+			}
+		}
+		None
+	}
+
+	/// Finds an item through a namespace it is a member of, such as `scale` in `v1::scale`.
+	/// The namespace can be declared by this module or by a visible one.
+	pub fn find_namespace_item(&self, namespace: &str, name: &str, manager: &ContextManager) -> Option<ContextType> {
+		let modules = self.get_visible_modules(manager);
+		let contexts = std::iter::once(self).chain(modules.iter().map(|module| manager.get_context_immut(module)));
+		for context in contexts {
+			if context.member_namespaces().iter().any(|names| names.last().map(|last| last == namespace).unwrap_or(false)) {
+				let item = context.module.get_item(name, None, Some(manager), true);
+				if item.is_some() {
+					return item;
+				}
+			}
+		}
+		None
+	}
+
+	/// Checks whether the declarations of another module can be used here without a namespace qualifier.
+	/// They can if they are members of this module's namespace or one that contains it.
+	pub fn sees_unqualified(&self, other: &Context) -> bool {
+		let names = self.get_namespace_names();
+		other.member_namespaces().iter().any(|namespace| names.starts_with(namespace))
+	}
+
+	/// Retrieves the names of the namespaces the module is declared in, outermost first.
+	pub fn get_namespace_names(&self) -> Vec<String> {
+		self.namespaces.iter().map(|namespace| namespace.0.clone()).collect()
+	}
+
+	/// Retrieves each namespace the module's declarations are members of.
+	/// Members of an inline namespace are also members of the namespace that contains it.
+	fn member_namespaces(&self) -> Vec<Vec<String>> {
+		let names = self.get_namespace_names();
+		let mut result = vec![names.clone()];
+		for (index, namespace) in self.namespaces.iter().enumerate().rev() {
+			if !namespace.1 {
+				break;
+			}
+			result.push(names[..index].to_vec());
+		}
+		result
+	}
+
+	/// Retrieves the modules along with the modules they re-export, transitively.
+	fn with_reexports(modules: Vec<String>, manager: &ContextManager) -> Vec<String> {
+		let mut result: Vec<String> = Vec::new();
+		let mut pending = modules;
+		let mut index = 0;
+		while index < pending.len() {
+			let module = pending[index].clone();
+			index += 1;
+			if result.contains(&module) || !manager.module_exists(&module) {
+				continue;
+			}
+			pending.extend(manager.get_context_immut(&module).reexported_modules.iter().cloned());
+			result.push(module);
+		}
+		result
+	}
+
+	/// Finds a meta function declared in this module or one of its visible modules.
+	pub fn find_meta_function(&self, name: &str, manager: &ContextManager) -> Option<MetaFunction> {
+		if let Some(func) = self.meta_functions.get(name) {
+			return Some(func.clone());
+		}
+		for module in self.get_visible_modules(manager) {
+			if let Some(func) = manager.get_context_immut(&module).meta_functions.get(name) {
+				return Some(func.clone());
+			}
+		}
+		None
+	}
+
 	pub fn add_header(&mut self, path: &str, is_system: bool) {
 		self.headers.add_header(path, is_system);
 	}
 
 	pub fn register_type(&mut self, var_type: &VariableType) {
+		if let (Type::Function(func), VarStyle::Ptr(_)) = (&var_type.var_type, &var_type.var_style) {
+			// Raw function pointers do not use std::function.
+			self.add_header("type_traits", true);
+			self.register_type(&func.return_type);
+			for param in &func.parameters {
+				self.register_type(&param.prop_type);
+			}
+			return;
+		}
 		self.register_type_only(&var_type.var_type);
 		match &var_type.var_style {
 			VarStyle::AutoPtr => self.add_header("memory", true),
 			VarStyle::UniquePtr => self.add_header("memory", true),
+			VarStyle::ClassPtr => self.add_header(&self.library.class_ptr_include.clone(), true),
 			_ => ()
 		}
+		if var_type.var_optional {
+			self.add_header("optional", true);
+		}
+	}
+
+	pub fn register_parameter_type(&mut self, var_type: &VariableType) {
+		self.register_type(var_type);
+		if var_type.is_string_view_parameter() {
+			self.add_header("string_view", true);
+		}
 	}
 
 	pub fn register_type_only(&mut self, var_type: &Type) {
 		match var_type {
-			Type::Function(_) => self.add_header("functional", true),
-			Type::Tuple(_) => self.add_header("tuple", true),
+			Type::Function(func) => {
+				self.add_header("functional", true);
+				self.register_type(&func.return_type);
+				for param in &func.parameters {
+					self.register_type(&param.prop_type);
+				}
+			},
+			Type::Tuple(types) => {
+				self.add_header("tuple", true);
+				for t in types {
+					self.register_type(t);
+				}
+			},
+			Type::InitializerList(init_type) => {
+				self.add_header("initializer_list", true);
+				self.register_type(init_type);
+			},
+			Type::Array(element_type, _) => {
+				self.add_header("array", true);
+				self.register_type(element_type);
+			},
+			Type::UndeclaredWParams(_, type_args) => {
+				for t in type_args {
+					self.register_type(t);
+				}
+			},
 			Type::Number(num_type) => {
 				match num_type {
 					NumberType::Size | NumberType::WChar => self.add_header("stddef.h", true),
@@ -81,6 +287,8 @@ impl Context {
 	pub fn register_module_attribute(&mut self, attribute: &str) {
 		if attribute == "TastyAlign" {
 			self.align_lines = true;
+		} else if let Some(order) = IncludeOrder::from_module_attribute(attribute) {
+			self.include_order = Some(order);
 		}
 	}
 
@@ -91,8 +299,8 @@ impl Context {
 		} else if result.is_none() {
 			if !recursive && manager.is_some() {
 				let manager_unwrap = manager.unwrap();
-				for module in &self.shared_modules {
-					let item = manager_unwrap.get_context_immut(module).find_static_extension(func_name, t, manager, true);
+				for module in self.get_visible_modules(manager_unwrap) {
+					let item = manager_unwrap.get_context_immut(&module).find_static_extension(func_name, t, manager, true);
 					if item.is_some() {
 						return item;
 					}
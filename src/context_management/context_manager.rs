@@ -11,11 +11,13 @@ use crate::context_management::typing_context::ContextType;
 use crate::expression::variable_type::VariableType;
 use crate::expression::value_type::{ Function, ClassType };
 
-use std::collections::BTreeMap;
+use std::collections::{ BTreeMap, BTreeSet };
 
 pub struct ContextManager {
 	pub contexts: BTreeMap<String,Context>,
 	pub data_refs: BTreeMap<usize,ContextType>,
+	pub imports: BTreeMap<String,Vec<String>>,
+	pub header_imports: BTreeMap<String,Vec<String>>,
 	pub max_id: usize
 }
 
@@ -24,6 +26,8 @@ impl ContextManager {
 		return ContextManager {
 			contexts: BTreeMap::new(),
 			data_refs: BTreeMap::new(),
+			imports: BTreeMap::new(),
+			header_imports: BTreeMap::new(),
 			max_id: 0
 		}
 	}
@@ -32,6 +36,35 @@ impl ContextManager {
 		self.contexts.insert(id, ctx);
 	}
 
+	/// Stores the modules the file imports. `header_imports` are the imports included by its header.
+	pub fn set_imports(&mut self, file: String, imports: Vec<String>, header_imports: Vec<String>) {
+		self.imports.insert(file.clone(), imports);
+		self.header_imports.insert(file, header_imports);
+	}
+
+	/// Retrieves every module the file depends on, including the imports of its imports.
+	pub fn get_dependencies(&self, file: &str) -> BTreeSet<String> {
+		let mut result = BTreeSet::new();
+		let mut pending = vec![file.to_string()];
+		while let Some(module) = pending.pop() {
+			if let Some(imports) = self.imports.get(&module) {
+				for import in imports {
+					if result.insert(import.clone()) {
+						pending.push(import.clone());
+					}
+				}
+			}
+		}
+		result
+	}
+
+	/// Finds the module that declares the item, so items that aren't imported can be reported.
+	pub fn find_declaring_module(&self, name: &str) -> Option<String> {
+		self.contexts.iter()
+			.find(|(_, ctx)| ctx.module.get_item(name, None, Some(self), true).is_some())
+			.map(|(file, _)| file.clone())
+	}
+
 	pub fn module_exists(&self, file: &String) -> bool {
 		return self.contexts.contains_key(file);
 	}
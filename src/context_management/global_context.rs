@@ -8,13 +8,21 @@
 use crate::declaration_parser::attribute_class_declaration::AttributeClassDeclaration;
 
 pub struct GlobalContext {
-	pub attribute_classes: Vec<AttributeClassDeclaration>
+	pub attribute_classes: Vec<AttributeClassDeclaration>,
+	pub tests: Vec<(String, String, String)>,
+	pub benches: Vec<(String, String, String)>,
+	pub module_inits: Vec<(String, String, String)>,
+	pub entry_point: Option<(String, String)>
 }
 
 impl GlobalContext {
 	pub fn new() -> GlobalContext {
 		return GlobalContext {
-			attribute_classes: Vec::new()
+			attribute_classes: Vec::new(),
+			tests: Vec::new(),
+			benches: Vec::new(),
+			module_inits: Vec::new(),
+			entry_point: None
 		};
 	}
 
@@ -22,6 +30,49 @@ impl GlobalContext {
 		self.attribute_classes.push(cls);
 	}
 
+	/// Registers a function marked with `@Test` so it can be run by the generated test runner.
+	///
+	/// # Arguments
+	///
+	/// * `name` - The name of the test.
+	/// * `func_name` - The name of the function to call.
+	/// * `header_path` - The path of the header declaring the function.
+	pub fn add_test(&mut self, name: String, func_name: String, header_path: String) {
+		self.tests.push((name, func_name, header_path));
+	}
+
+	/// Registers a function marked with `@Bench` so it can be timed by the generated benchmark runner.
+	///
+	/// # Arguments
+	///
+	/// * `name` - The name of the benchmark.
+	/// * `func_name` - The name of the function to call.
+	/// * `header_path` - The path of the header declaring the function.
+	pub fn add_bench(&mut self, name: String, func_name: String, header_path: String) {
+		self.benches.push((name, func_name, header_path));
+	}
+
+	/// Registers the function generated by `--module-init` to initialize a module's variables.
+	///
+	/// # Arguments
+	///
+	/// * `func_name` - The name of the init function.
+	/// * `header_path` - The path of the header declaring the function.
+	/// * `module` - The access path of the module, used to order it after its imports.
+	pub fn add_module_init(&mut self, func_name: String, header_path: String, module: String) {
+		self.module_inits.push((func_name, header_path, module));
+	}
+
+	/// Registers the function marked with `@EntryPoint`.
+	/// Only one is allowed per program, so the file of the existing one is returned if there is one.
+	pub fn set_entry_point(&mut self, func_name: String, file: String) -> Result<(), String> {
+		if let Some((_, existing_file)) = &self.entry_point {
+			return Err(existing_file.clone());
+		}
+		self.entry_point = Some((func_name, file));
+		Ok(())
+	}
+
 	pub fn find_attribute(&self, name: &str) -> Option<&AttributeClassDeclaration> {
 		for a in &self.attribute_classes {
 			if a.name == name {
@@ -12,10 +12,72 @@ pub mod header_context;
 pub mod global_context;
 pub mod context_manager;
 pub mod static_extension;
+pub mod class_members;
 
 use position::Position;
 
+use std::sync::atomic::{ AtomicUsize, AtomicBool, Ordering };
+
+static ERROR_COUNT: AtomicUsize = AtomicUsize::new(0);
+static ERROR_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+static FILE_ERROR_START: AtomicUsize = AtomicUsize::new(0);
+static MESSAGES_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Retrieves the number of errors printed so far.
+/// Comparing the count before and after a step shows whether the step failed.
+pub fn error_count() -> usize {
+	ERROR_COUNT.load(Ordering::Relaxed)
+}
+
+/// Sets the number of errors a single file may report before it is aborted.
+/// The value of `ConfigData.max_errors` is mirrored here once arguments are parsed.
+pub fn set_error_limit(limit: usize) {
+	ERROR_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Sends errors and warnings to stderr instead of stdout.
+/// This keeps stdout free for the generated code when it is streamed.
+pub fn set_messages_to_stderr(enabled: bool) {
+	MESSAGES_TO_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// Starts counting errors towards the limit for a file.
+///
+/// # Arguments
+///
+/// * `previous_errors` - The errors the file already reported in an earlier pass.
+pub fn begin_file_errors(previous_errors: usize) {
+	FILE_ERROR_START.store(error_count().saturating_sub(previous_errors), Ordering::Relaxed);
+}
+
+/// Retrieves the number of errors the current file has reported.
+pub fn file_error_count() -> usize {
+	error_count().saturating_sub(FILE_ERROR_START.load(Ordering::Relaxed))
+}
+
+/// Checks whether the current file has reported more errors than it may.
+/// Parsing loops stop once this is the case.
+pub fn error_limit_reached() -> bool {
+	file_error_count() > ERROR_LIMIT.load(Ordering::Relaxed)
+}
+
 pub fn print_code_error(title: &str, message: &str, position: &Position, file_content: &str) {
+	let limit = ERROR_LIMIT.load(Ordering::Relaxed);
+	ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
+	let file_errors = file_error_count();
+	if file_errors <= limit {
+		print_code_message(title, message, position, file_content);
+	}
+	if file_errors == limit.saturating_add(1) {
+		print_message("too many errors, aborting file.\n");
+	}
+}
+
+pub fn print_code_warning(title: &str, message: &str, position: &Position, file_content: &str) {
+	print_code_message(&format!("Warning: {}", title), message, position, file_content);
+}
+
+fn print_code_message(title: &str, message: &str, position: &Position, file_content: &str) {
 	let mut output = String::from("");
 
 	// title
@@ -27,34 +89,15 @@ pub fn print_code_error(title: &str, message: &str, position: &Position, file_co
 	let file_chars: Vec<char> = file_content.chars().collect();
 	let mut line_content = "".to_string();
 
-	let mut line = position.line.unwrap_or(1) - 1;
+	let mut line = position.line.unwrap_or(1).saturating_sub(1);
 	let mut start = position.start;
 	let mut end = position.end.unwrap_or(position.start + 1);
 
 	if position.line.is_none() {
-		let mut line_start = 0;
-		let mut i = 0;
-		loop {
-			if i >= file_chars.len() {
-				if i <= start {
-					start -= line_start;
-					end -= line_start;
-				}
-				break;
-			}
-			if file_chars[i] == '\n' {
-				if i > end {
-					break;
-				}
-				line += 1;
-				line_start = i;
-			}
-			if i == start {
-				start -= line_start;
-				end -= line_start;
-			}
-			i += 1;
-		}
+		let line_start = file_chars[..start.min(file_chars.len())].iter().rposition(|c| *c == '\n').map(|i| i + 1).unwrap_or(0);
+		line = file_chars[..line_start].iter().filter(|c| **c == '\n').count();
+		start -= line_start;
+		end -= line_start;
 	}
 
 	let mut i = 0;
@@ -82,11 +125,22 @@ pub fn print_code_error(title: &str, message: &str, position: &Position, file_co
 	output += format!("{} |    {}\n", line_text, line_content).as_str();
 	output += format!("{} |    {}{} {}\n", spaces, create_spacing(start, &line_content), repeat_char(b'^', end - start), message).as_str();
 
-	println!("{}\n\n", output);
+	print_message(&format!("{}\n\n", output));
+}
+
+fn print_message(message: &str) {
+	if MESSAGES_TO_STDERR.load(Ordering::Relaxed) {
+		eprintln!("{}", message);
+	} else {
+		println!("{}", message);
+	}
 }
 
 fn create_spacing(count: usize, line_content: &str) -> String {
 	let mut result = "".to_string();
+	if count == 0 {
+		return result;
+	}
 	let mut index = 0;
 	for c in line_content.chars() {
 		result.push(if c == '\t' {
@@ -22,4 +22,13 @@ impl Position {
 			end: end
 		};
 	}
+
+	/// Creates a position from the start of this position to the end of another.
+	/// If the other position is on a different line, this position is kept.
+	pub fn to(&self, other: &Position) -> Position {
+		if self.line != other.line {
+			return self.clone();
+		}
+		Position::new(self.file.clone(), self.line, self.start, other.end.or(self.end))
+	}
 }
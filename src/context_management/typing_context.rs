@@ -20,7 +20,8 @@ pub enum ContextType {
 	QuantumFunction(Vec<Function>),
 	Class(ClassType),
 	PrimitiveExtension(Vec<(Type, Function)>),
-	Namespace(BTreeMap<String,ContextType>)
+	Namespace(BTreeMap<String,ContextType>),
+	TypeParameter
 }
 
 #[derive(Clone)]
@@ -69,10 +70,13 @@ impl TypingContext {
 				}
 			}
 		}
-		if !recursive && curr_ctx.is_some() && manager.is_some() {
-			let manager_unwrap = manager.unwrap();
-			for module in &curr_ctx.unwrap().shared_modules {
-				let item = manager_unwrap.get_context_immut(module).module.get_item(name, curr_ctx, manager, true);
+		if let (false, Some(ctx), Some(manager_unwrap)) = (recursive, curr_ctx, manager) {
+			for module in ctx.get_visible_modules(manager_unwrap) {
+				let module_ctx = manager_unwrap.get_context_immut(&module);
+				if !ctx.sees_unqualified(module_ctx) {
+					continue;
+				}
+				let item = module_ctx.module.get_item(name, curr_ctx, manager, true);
 				if item.is_some() {
 					return item;
 				}
@@ -122,6 +126,13 @@ impl TypingContext {
 		return id;
 	}
 
+	/// Registers a type parameter of a generic so it takes precedence over outer types with the same name.
+	pub fn add_type_parameter(&mut self, name: String, manager: Option<&mut ContextManager>) -> usize {
+		let id = self.register_type(ContextType::TypeParameter, manager);
+		self.known_data.last_mut().unwrap().insert(name, id);
+		id
+	}
+
 	pub fn add_function(&mut self, name: String, func: Function, manager: Option<&mut ContextManager>) -> usize {
 		let mut result = 0;
 		let data_contains_key = self.known_data.last_mut().unwrap().contains_key(&name);
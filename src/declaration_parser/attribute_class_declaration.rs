@@ -176,6 +176,9 @@ impl AttributeClassDeclaration {
 				attr_params.push(Right(p.to_string()));
 			}
 			a.parameters = Some(attr_params);
+			a.line = attribute.line;
+			a.start_index = attribute.start_index;
+			a.end_index = attribute.end_index;
 			result.push(a);
 		}
 
@@ -28,7 +28,9 @@ type AttributeDeclarationResult = DeclarationResult<AttributeDeclaration>;
 pub struct AttributeDeclaration {
 	pub name: String,
 	pub parameters: Option<Vec<Either<(usize, usize),String>>>,
-	pub line: usize
+	pub line: usize,
+	pub start_index: usize,
+	pub end_index: usize
 }
 
 impl Declaration<AttributeDeclaration> for AttributeDeclaration {
@@ -46,6 +48,7 @@ impl CPPTranspiler for AttributeDeclaration {
 impl AttributeDeclaration {
 	pub fn new(parser: &mut Parser, mut store_params: bool) -> AttributeDeclarationResult {
 		let initial_line = parser.line;
+		let start_index = parser.index;
 
 		let mut next_char = parser.get_curr();
 		if next_char != '@' {
@@ -56,6 +59,7 @@ impl AttributeDeclaration {
 		// Parse Var Style
 		let mut attribute_name = "".to_string();
 		declare_parse_ascii!(attribute_name, parser);
+		let end_index = parser.index;
 
 		if !store_params && CACHE_ATTRIBUTES.contains(&attribute_name) {
 			store_params = true;
@@ -98,7 +102,9 @@ impl AttributeDeclaration {
 		return AttributeDeclarationResult::Ok(AttributeDeclaration {
 			name: attribute_name,
 			parameters: parameters,
-			line: initial_line
+			line: initial_line,
+			start_index,
+			end_index
 		});
 	}
 
@@ -11,16 +11,31 @@ use crate::declaration_parser::attribute_declaration::AttributeDeclaration;
 
 #[derive(Clone)]
 pub struct Attributes {
-	data: Option<Vec<AttributeDeclaration>>
+	data: Option<Vec<AttributeDeclaration>>,
+	comments: Vec<(usize, usize, usize)>
 }
 
 impl Attributes {
 	pub fn new(data: Option<Vec<AttributeDeclaration>>) -> Attributes {
 		return Attributes {
-			data: data
+			data,
+			comments: Vec::new()
 		};
 	}
 
+	/// Creates attributes that also store the comments written above the declaration.
+	/// Each comment is stored as its start index, end index, and line.
+	pub fn with_comments(data: Option<Vec<AttributeDeclaration>>, comments: Vec<(usize, usize, usize)>) -> Attributes {
+		Attributes {
+			data,
+			comments
+		}
+	}
+
+	pub fn get_comments(&self) -> &Vec<(usize, usize, usize)> {
+		&self.comments
+	}
+
 	pub fn has_attribute(&self, name: &str) -> bool {
 		if self.data.is_some() {
 			for a in self.data.as_ref().unwrap() {
@@ -32,6 +47,11 @@ impl Attributes {
 		return false;
 	}
 
+	/// Checks whether every attribute is one of the provided names.
+	pub fn only_has_attributes(&self, names: &[&str]) -> bool {
+		self.data.as_ref().map(|data| data.iter().all(|a| names.contains(&a.name.as_str()))).unwrap_or(true)
+	}
+
 	pub fn get_attribute(&self, name: &str) -> Option<&AttributeDeclaration> {
 		if self.data.is_some() {
 			for a in self.data.as_ref().unwrap() {
@@ -66,6 +86,21 @@ impl Attributes {
 		return result;
 	}
 
+	/// Returns the parameters of every attribute with the provided name, in order.
+	pub fn get_all_attribute_parameters(&self, name: &str, content: &str) -> Vec<String> {
+		let mut result = Vec::new();
+		if let Some(data) = &self.data {
+			for a in data {
+				if a.name == name {
+					for i in 0..a.params_length() {
+						result.push(a.get_param(i, content));
+					}
+				}
+			}
+		}
+		result
+	}
+
 	pub fn flatten_attributes(&mut self, global_context: &GlobalContext, content: &str) {
 		if self.data.is_some() {
 			let attributes = self.data.as_mut().unwrap();
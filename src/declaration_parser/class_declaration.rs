@@ -13,6 +13,7 @@ use crate::{
 	declare_parse_type
 };
 
+use crate::config_management::{ CompilerTarget, LibraryNames };
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use crate::context_management::context::Context;
@@ -36,6 +37,7 @@ use regex::Regex;
 lazy_static! {
 	pub static ref CLASS_REGEX: Regex = Regex::new(r"^\b(?:class|enum|abstract)\b").unwrap();
 	pub static ref FORWARD_REGEX: Regex = Regex::new(r"^\b(?:forward)\b").unwrap();
+	pub static ref INHERITANCE_ACCESS_REGEX: Regex = Regex::new(r"^\b(?:public|protected|private)\s").unwrap();
 }
 
 type ClassDeclarationResult = DeclarationResult<ClassDeclaration>;
@@ -45,6 +47,7 @@ pub struct ClassDeclaration {
 	pub name: String,
 	pub class_type: ClassStyle,
 	pub extensions: Option<Vec<Type>>,
+	pub extension_access: Vec<InheritanceAccess>,
 	pub declarations: Vec<DeclarationType>,
 	pub abstract_declarations: Option<Vec<DeclarationType>>,
 	pub declaration_id: usize
@@ -72,6 +75,36 @@ impl ClassStyle {
 		}
 		return false;
 	}
+
+	pub fn is_enum(&self) -> bool {
+		matches!(self, ClassStyle::Enum)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum InheritanceAccess {
+	Public,
+	Protected,
+	Private
+}
+
+impl InheritanceAccess {
+	pub fn new(name: &str) -> Option<InheritanceAccess> {
+		match name {
+			"public" => Some(InheritanceAccess::Public),
+			"protected" => Some(InheritanceAccess::Protected),
+			"private" => Some(InheritanceAccess::Private),
+			_ => None
+		}
+	}
+
+	pub fn get_name(&self) -> &str {
+		match self {
+			InheritanceAccess::Public => "public",
+			InheritanceAccess::Protected => "protected",
+			InheritanceAccess::Private => "private"
+		}
+	}
 }
 
 impl Declaration<ClassDeclaration> for ClassDeclaration {
@@ -81,7 +114,7 @@ impl Declaration<ClassDeclaration> for ClassDeclaration {
 }
 
 impl ClassDeclaration {
-	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure) -> ClassDeclarationResult {
+	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure, library: &LibraryNames) -> ClassDeclarationResult {
 
 		// Parse Var Style
 		let mut class_keyword = "".to_string();
@@ -100,6 +133,7 @@ impl ClassDeclaration {
 		declare_parse_required_whitespace!(parser);
 
 		let mut type_extensions = Vec::new();
+		let mut extension_access = Vec::new();
 		if parser.get_curr() != '{' {
 			let mut extend_keyword = "".to_string();
 			declare_parse_ascii!(extend_keyword, parser);
@@ -110,9 +144,17 @@ impl ClassDeclaration {
 				let mut and_text = "".to_string();
 				declare_parse_required_whitespace!(parser);
 				loop {
+					let mut access = InheritanceAccess::Public;
+					if INHERITANCE_ACCESS_REGEX.is_match(&parser.content[parser.index..]) {
+						let mut access_keyword = "".to_string();
+						declare_parse_ascii!(access_keyword, parser);
+						declare_parse_required_whitespace!(parser);
+						access = InheritanceAccess::new(&access_keyword).unwrap_or(InheritanceAccess::Public);
+					}
 					let var_type: Type;
 					declare_parse_type!(var_type, parser);
 					type_extensions.push(var_type);
+					extension_access.push(access);
 					declare_parse_whitespace!(parser);
 					if parser.get_curr() != '{' {
 						declare_parse_ascii!(and_text, parser);
@@ -132,6 +174,7 @@ impl ClassDeclaration {
 				let var_type: Type;
 				declare_parse_type!(var_type, parser);
 				type_extensions.push(var_type);
+				extension_access.push(InheritanceAccess::Public);
 				declare_parse_whitespace!(parser);
 			}
 		}
@@ -174,7 +217,7 @@ impl ClassDeclaration {
 					FunctionDeclarationType::Forward
 				} else {
 					FunctionDeclarationType::ClassLevel
-				}, Some(operator_data));
+				}, Some(operator_data), library);
 				if result.is_error() {
 					result.print_error(file_name.to_string(), &parser.content);
 				} else {
@@ -245,6 +288,7 @@ impl ClassDeclaration {
 			declarations: declarations,
 			abstract_declarations: abstract_declarations,
 			extensions: if type_extensions.is_empty() { None } else { Some(type_extensions) },
+			extension_access,
 			declaration_id: 0
 		});
 	}
@@ -258,56 +302,109 @@ impl ClassDeclaration {
 		return CLASS_REGEX.is_match(declare);
 	}
 
-	pub fn to_cpp(&self, attributes: &Attributes, content: &str) -> String {
-		return format!("{}{}{}{}{}{}{{",
+	pub fn to_cpp(&self, attributes: &Attributes, content: &str, target: CompilerTarget, library: &LibraryNames, visibility: &str, brace_separator: &str) -> String {
+		format!("{}{}{}{}{}{}{}{}{}{}{{",
 			self.class_type.get_name(),
+			if visibility.is_empty() { "".to_string() } else { format!(" {}", visibility.trim_end()) },
+			Self::get_layout_specifiers(attributes, content, target),
+			if attributes.has_attribute("ThreadSafe") && target.is_clang() && self.is_lock_type() { " __attribute__((capability(\"mutex\")))" } else { "" },
 			if attributes.has_attribute("DeclarePreName") {
 				format!(" {} ", attributes.get_attribute_parameters("DeclarePreName", content).join(" "))
 			} else {
 				" ".to_string()
 			},
 			self.name,
+			if attributes.has_attribute("Final") && self.class_type == ClassStyle::Class { " final" } else { "" },
 			if attributes.has_attribute("DeclarePostName") {
 				format!(" {}{}", attributes.get_attribute_parameters("DeclarePostName", content).join(" "),
 					if self.extensions.is_none() { "" } else { " " })
 			} else {
 				"".to_string()
 			},
-			if self.extensions.is_none() {
-				"".to_string()
-			} else {
-				format!(": {}", self.extensions.as_ref().unwrap().iter().map(|cls| format!("public {}", cls.to_cpp(false))).collect::<Vec<String>>().join(", "))
+			match &self.extensions {
+				None => "".to_string(),
+				Some(extensions) => format!(": {}", extensions.iter().zip(&self.extension_access).map(|(cls, access)| format!("{} {}", access.get_name(), cls.to_cpp(false, library))).collect::<Vec<String>>().join(", "))
 			},
 			if attributes.has_attribute("DeclarePreBracket") {
-				format!(" {} ", attributes.get_attribute_parameters("DeclarePreBracket", content).join(" "))
+				format!(" {}{}", attributes.get_attribute_parameters("DeclarePreBracket", content).join(" "), brace_separator)
 			} else {
-				" ".to_string()
+				brace_separator.to_string()
 			}
-		);
+		)
+	}
+
+	/// Checks whether the class can be used as a lock by `@ThreadSafe`, which requires `lock` and `unlock` methods.
+	pub fn is_lock_type(&self) -> bool {
+		let has_method = |name: &str| self.declarations.iter().any(|d| matches!(d, DeclarationType::Function(func_data, _) if func_data.name == name));
+		has_method("lock") && has_method("unlock")
+	}
+
+	/// Retrieves the alignment provided by `@Align`, if it is a power of two.
+	pub fn get_alignment(attributes: &Attributes, content: &str) -> Option<usize> {
+		let params = attributes.get_attribute_parameters("Align", content);
+		if params.len() != 1 {
+			return None;
+		}
+		params[0].trim().parse::<usize>().ok().filter(|a| a.is_power_of_two())
+	}
+
+	/// Generates the `to_json` and `from_json` functions for `@Serialize` using the non-static properties.
+	/// Returns the header declarations and the source definitions.
+	pub fn get_serialize_functions(&self, properties: &[Property], json_type: &str) -> (String, String) {
+		let fields: Vec<&Property> = properties.iter().filter(|p| !p.prop_type.is_only_static()).collect();
+		let to_json = format!("void to_json({}& j, const {}& value)", json_type, self.name);
+		let from_json = format!("void from_json(const {}& j, {}& value)", json_type, self.name);
+		let pairs = fields.iter().map(|p| format!("{{ \"{}\", value.{} }}", p.name, p.name)).collect::<Vec<String>>();
+		let mut definitions = vec![
+			format!("{} {{", to_json),
+			format!("\tj = {}{{ {} }};", json_type, pairs.join(", ")),
+			"}".to_string(),
+			"".to_string(),
+			format!("{} {{", from_json)
+		];
+		for p in fields {
+			definitions.push(format!("\tj.at(\"{}\").get_to(value.{});", p.name, p.name));
+		}
+		definitions.push("}".to_string());
+		(format!("{};\n{};", to_json, from_json), definitions.join("\n"))
+	}
+
+	fn get_layout_specifiers(attributes: &Attributes, content: &str, target: CompilerTarget) -> String {
+		let mut result = "".to_string();
+		if attributes.has_attribute("Packed") && !target.is_msvc() {
+			result += " [[gnu::packed]]";
+		}
+		if let Some(alignment) = Self::get_alignment(attributes, content) {
+			result += &format!(" alignas({})", alignment);
+		}
+		result
 	}
 
 	pub fn to_class(&self, context: &mut Context, manager: &mut ContextManager, content: &str, attributes: &Attributes) -> ClassType {
 		let mut properties = Vec::new();
 		let mut functions = Vec::new();
+		let mut constructors = Vec::new();
 		let mut operators: BTreeMap<usize,Vec<Function>> = BTreeMap::new();
 		for declaration in &self.declarations {
 			match declaration {
-				DeclarationType::Function(d, _) => {
+				DeclarationType::Function(d, func_attributes) => {
 					if d.function_type.is_operator() {
 						let op_type = d.function_type.get_operator_type();
-						let base_id = match op_type.as_str() { "suffix" => 100, "prefix" => 200, "infix" => 300, _ => panic!("Invalid operator type") };
+						let base_id = match op_type.as_str() { "suffix" => 100, "prefix" => 200, "infix" => 300, "call" => 400, _ => panic!("Invalid operator type") };
 						let op_id = d.function_type.get_operator_id() + base_id;
 						if operators.contains_key(&op_id) {
-							operators.get_mut(&op_id).unwrap().push(d.to_function(content));
+							operators.get_mut(&op_id).unwrap().push(d.to_typing_function(content, func_attributes));
 						} else {
-							let op_funcs = vec![d.to_function(content)];
+							let op_funcs = vec![d.to_typing_function(content, func_attributes)];
 							operators.insert(op_id, op_funcs);
 						}
+					} else if d.function_type.is_constructor() {
+						constructors.push(d.to_typing_function(content, func_attributes));
 					} else {
-						functions.push(d.to_function(content));
+						functions.push(d.to_typing_function(content, func_attributes));
 					}
 					for p in &d.parameters {
-						context.register_type(&p.0);
+						context.register_parameter_type(&p.0);
 					}
 					context.register_type(&d.return_type);
 				},
@@ -332,6 +429,7 @@ impl ClassDeclaration {
 			type_params: None,
 			properties: properties,
 			functions: functions,
+			constructors,
 			operators: operators,
 			required_includes: attributes.get_required_includes()
 		};
@@ -20,6 +20,15 @@ pub trait Declaration<T> {
 		return DeclarationResult::Err("Unexpected End", Self::out_of_space_error_msg(), index - 1, index);
 	}
 
+	/// Reports the first group left open by `parse_until_at_expr`, which is likely why the end of content was reached.
+	fn unterminated_group(parser: &Parser) -> DeclarationResult<T> {
+		match parser.unclosed_group {
+			Some(index) if parser.chars[index] == '(' => DeclarationResult::Err("Unterminated Group", "this '(' is never closed by a ')'", index, index + 1),
+			Some(index) => DeclarationResult::Err("Unterminated Group", "this '{' is never closed by a '}'", index, index + 1),
+			None => Self::out_of_space(parser.index)
+		}
+	}
+
 	fn unexpected_character(index: usize) -> DeclarationResult<T> {
 		return DeclarationResult::Err("Unexpected Symbol", "unexpected symbol", index, index + 1);
 	}
@@ -258,7 +267,7 @@ macro_rules! declare_parse_expr_until_next_char {
 	($c:expr, $parser:expr) => {
 		let mut result = ' ';
 		$parser.parse_until_at_expr($c, $c, &mut result);
-		if $parser.out_of_space { return Self::out_of_space($parser.index); }
+		if $parser.out_of_space { return Self::unterminated_group(&$parser); }
 	}
 }
 
@@ -267,7 +276,7 @@ macro_rules! declare_parse_expr_until_next_char {
 macro_rules! declare_parse_expr_until_either_char {
 	($c:expr, $c2:expr, $result:expr, $parser:expr) => {
 		$parser.parse_until_at_expr($c, $c2, &mut $result);
-		if $parser.out_of_space { return Self::out_of_space($parser.index); }
+		if $parser.out_of_space { return Self::unterminated_group(&$parser); }
 	}
 }
 
@@ -18,20 +18,29 @@ use crate::{
 	delcare_increment
 };
 
+use crate::config_management::{ ConfigData, ConstraintStyle, LibraryNames };
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use crate::expression::variable_type::{ VariableType, Type, VarStyle };
 use crate::expression::value_type::{ Function, Property };
-use crate::expression::function_type::FunStyle;
+use crate::expression::function_type::{ FunStyle, template_declaration };
+use crate::expression::expression_parser::ExpressionEndReason;
+use crate::expression::const_eval::MetaFunction;
+
+use crate::context_management::context_manager::ContextManager;
+
+use crate::scope_parser::return_parser::RETURN_REGEX;
 
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
+use crate::declaration_parser::attributes::Attributes;
 use crate::declaration_parser::cpp_transpiler::CPPTranspiler;
 
 use regex::Regex;
 
 lazy_static! {
 	pub static ref FUNC_REGEX: Regex = Regex::new(r"^(\b(?:static|extern|virtual|inline|meta|const|override)\b\s+)*\b(?:fn|op|constructor|destructor)\b").unwrap();
+	pub static ref WHERE_REGEX: Regex = Regex::new(r"^\bwhere\b").unwrap();
 }
 
 type FunctionDeclarationResult = DeclarationResult<FunctionDeclaration>;
@@ -43,6 +52,10 @@ pub struct FunctionDeclaration {
 	pub parameters: Vec<(VariableType, String, Option<usize>, Option<usize>, bool)>,
 	pub return_type: VariableType,
 	pub function_type: FunctionType,
+	pub type_params: Vec<String>,
+	pub constraints: Vec<(String, Type)>,
+	pub self_style: Option<VarStyle>,
+	pub is_const: bool,
 	pub line: usize,
 	pub start_index: Option<usize>,
 	pub end_index: Option<usize>,
@@ -152,7 +165,7 @@ impl CPPTranspiler for FunctionDeclaration {
 }
 
 impl FunctionDeclaration {
-	pub fn new(parser: &mut Parser, declare_type: FunctionDeclarationType, operator_data: Option<&OperatorDataStructure>) -> FunctionDeclarationResult {
+	pub fn new(parser: &mut Parser, declare_type: FunctionDeclarationType, operator_data: Option<&OperatorDataStructure>, library: &LibraryNames) -> FunctionDeclarationResult {
 		let initial_line = parser.line;
 
 		let mut func_type = FunctionType::Normal;
@@ -244,15 +257,50 @@ impl FunctionDeclaration {
 			}
 		}
 
+		// Parse Type Parameters
+		let mut type_params = Vec::new();
+		if func_type.is_normal() && parser.get_curr() == '@' {
+			delcare_increment!(parser);
+			if parser.get_curr() == '(' {
+				delcare_increment!(parser);
+				loop {
+					declare_parse_whitespace!(parser);
+					let mut type_param = "".to_string();
+					declare_parse_required_ascii!(type_param, "Type Parameter Missing", "type parameter name missing", parser);
+					type_params.push(type_param);
+					declare_parse_whitespace!(parser);
+					if parser.get_curr() == ',' {
+						delcare_increment!(parser);
+					} else if parser.get_curr() == ')' {
+						delcare_increment!(parser);
+						break;
+					} else {
+						return FunctionDeclarationResult::Err("Unexpected Symbol", "expected ',' or ')'", parser.index, parser.index + 1);
+					}
+				}
+			} else {
+				let mut type_param = "".to_string();
+				declare_parse_required_ascii!(type_param, "Type Parameter Missing", "type parameter name missing", parser);
+				type_params.push(type_param);
+			}
+		}
+
 		if func_type.is_operator() {
 			if operator_data.is_none() {
 				panic!("No operator data available!");
 			}
 			let mut found_operator = false;
+			if function_name == "()" {
+				func_type = FunctionType::Operator("call".to_string(), 0);
+				found_operator = true;
+			}
 			for (op_type, ops) in operator_data.unwrap() {
+				if found_operator {
+					break;
+				}
 				let mut index = 0;
 				for op in ops {
-					if *op.name.as_ref().unwrap() == function_name {
+					if op.name.as_ref() == Some(&function_name) {
 						func_type = FunctionType::Operator(op_type.to_string(), index);
 						found_operator = true;
 						break;
@@ -364,38 +412,110 @@ impl FunctionDeclaration {
 
 		} // !is_destructor
 
-		let return_type = {
-			if parser.get_curr() == '-' && func_type.is_normal_or_operator() {
+		let mut return_type: Option<VariableType> = None;
+		let mut constraints: Vec<(String, Type)> = Vec::new();
+		let mut self_style: Option<VarStyle> = None;
+		let mut parsed_where = false;
+		loop {
+			if !parsed_where && WHERE_REGEX.is_match(&parser.content[parser.index..]) {
+				// Parse Where Clause
+				parsed_where = true;
+				let mut where_keyword = "".to_string();
+				declare_parse_ascii!(where_keyword, parser);
+				loop {
+					declare_parse_whitespace!(parser);
+					let param_start = parser.index;
+					let mut type_param = "".to_string();
+					declare_parse_required_ascii!(type_param, "Type Parameter Missing", "type parameter name missing", parser);
+					if type_param == "self" {
+						// Parse Self Style
+						if !declare_type.is_class() || !func_type.is_normal_or_operator() {
+							return FunctionDeclarationResult::Err("Unexpected Self", "self constraints can only be used on class methods", param_start, parser.index);
+						}
+						if self_style.is_some() {
+							return FunctionDeclarationResult::Err("Duplicate Self", "self constraint already declared", param_start, parser.index);
+						}
+						declare_parse_whitespace!(parser);
+						declare_parse_required_next_char!(':', next_char, parser);
+						declare_parse_whitespace!(parser);
+						let style_start = parser.index;
+						let mut style_name = "".to_string();
+						declare_parse_required_ascii!(style_name, "Self Style Missing", "self style missing", parser);
+						let style = VarStyle::new(&style_name);
+						if style.to_cpp_self_qualifier().is_none() {
+							return FunctionDeclarationResult::Err("Invalid Self Style", "expected ref, borrow, move, or ptr", style_start, parser.index);
+						}
+						self_style = Some(style);
+						declare_parse_whitespace!(parser);
+						if parser.get_curr() == ',' {
+							delcare_increment!(parser);
+							continue;
+						} else {
+							break;
+						}
+					}
+					if type_params.is_empty() {
+						return FunctionDeclarationResult::Err("Unexpected Where", "where clause requires type parameters", param_start, parser.index);
+					}
+					if !type_params.contains(&type_param) {
+						return FunctionDeclarationResult::Err("Unknown Type Parameter", "not a type parameter of this function", param_start, parser.index);
+					}
+					declare_parse_whitespace!(parser);
+					declare_parse_required_next_char!(':', next_char, parser);
+					loop {
+						declare_parse_whitespace!(parser);
+						let constraint: Type;
+						declare_parse_type!(constraint, parser);
+						constraints.push((type_param.clone(), constraint));
+						declare_parse_whitespace!(parser);
+						if parser.get_curr() == '+' {
+							delcare_increment!(parser);
+						} else {
+							break;
+						}
+					}
+					if parser.get_curr() == ',' {
+						delcare_increment!(parser);
+					} else {
+						break;
+					}
+				}
+			} else if return_type.is_none() && parser.get_curr() == '-' && func_type.is_normal_or_operator() {
 				delcare_increment!(parser);
 				declare_parse_required_next_char!('>', next_char, parser);
 				declare_parse_whitespace!(parser);
 				let var_type: Type;
 				let var_style: VarStyle;
 				declare_parse_type_and_style!(var_type, var_style, parser);
-				VariableType {
+				return_type = Some(VariableType {
 					var_type: var_type,
 					var_style: var_style,
 					var_properties: None,
 					var_optional: false
-				}
+				});
 			} else {
-				VariableType {
-					var_type: Type::Void,
-					var_style: VarStyle::Copy,
-					var_properties: None,
-					var_optional: false
-				}
+				break;
 			}
-		};
-
-		declare_parse_whitespace!(parser);
+			declare_parse_whitespace!(parser);
+		}
+		if return_type.is_none() && func_type.is_operator() && function_name == "<=>" {
+			return_type = Some(VariableType::copy(Type::Undeclared(vec![library.std_name("strong_ordering")])));
+		}
+		let return_type = return_type.unwrap_or(VariableType {
+			var_type: Type::Void,
+			var_style: VarStyle::Copy,
+			var_properties: None,
+			var_optional: false
+		});
 
 		let mut start_index: Option<usize> = None;
 		let mut end_index: Option<usize> = None;
 
 		if is_extern || declare_type.is_assumption() || declare_type.is_forward() {
-			let mut next_char = ' ';
-			declare_parse_required_next_char!(';', next_char, parser);
+			// The parser is left on the ';', like it is left on the '}' of a body, so callers moving past the end don't skip a line.
+			if parser.get_curr() != ';' {
+				return FunctionDeclarationResult::Err("Unexpected Symbol", "expected ';' operator", parser.index, parser.index + 1);
+			}
 		} else {
 			let mut next_char = ' ';
 			declare_parse_required_next_char!('{', next_char, parser);
@@ -410,6 +530,10 @@ impl FunctionDeclaration {
 			parameters: parameters,
 			return_type: return_type,
 			function_type: func_type,
+			type_params,
+			constraints,
+			self_style,
+			is_const: false,
 			line: initial_line,
 			start_index: start_index,
 			end_index: end_index,
@@ -417,6 +541,65 @@ impl FunctionDeclaration {
 		});
 	}
 
+	pub fn is_meta(&self) -> bool {
+		self.props.contains(&FunStyle::Meta)
+	}
+
+	/// Checks whether `--infer-const-methods` may make this method `const`.
+	/// Virtual methods are skipped since changing them would change what they override.
+	/// Methods returning references are skipped since the references may be to members.
+	pub fn can_infer_const(&self) -> bool {
+		let returns_copy = matches!(self.return_type.var_style, VarStyle::Copy | VarStyle::Borrow | VarStyle::Infer);
+		self.function_type.is_normal_or_operator() && self.self_style.is_none() && !self.is_const && returns_copy &&
+			!self.props.iter().any(|s| *s == FunStyle::Static || s.is_virtual() || s.is_override() || s.is_extern())
+	}
+
+	/// Checks whether `--auto-constexpr` may make this function `constexpr`.
+	/// Only non-generic functions that take and return numbers or booleans by value are considered.
+	pub fn can_auto_constexpr(&self) -> bool {
+		let is_literal_value = |var_type: &VariableType| {
+			var_type.var_style == VarStyle::Copy && var_type.var_properties.is_none() && (var_type.is_number() || var_type.is_boolean())
+		};
+		self.function_type.is_normal() && !self.is_generic() && self.name != "main" &&
+			self.props.iter().all(|s| *s == FunStyle::Static) &&
+			is_literal_value(&self.return_type) &&
+			self.parameters.iter().all(|p| !p.4 && is_literal_value(&p.0))
+	}
+
+	/// Parses the body of a meta function so its calls can be evaluated while transpiling.
+	/// The body is only kept if it consists of a single return statement.
+	pub fn to_meta_function(&self, parser: &mut Parser, file_name: &str, config_data: &ConfigData, manager: &mut ContextManager) -> MetaFunction {
+		let mut meta_function = MetaFunction {
+			parameters: self.parameters.iter().map(|p| (p.1.clone(), p.0.clone())).collect(),
+			return_type: self.return_type.clone(),
+			body: None
+		};
+		let start_index = match self.start_index {
+			Some(start_index) => start_index,
+			None => return meta_function
+		};
+
+		let (old_index, old_line) = (parser.index, parser.line);
+		parser.reset(start_index, self.line);
+		parser.parse_whitespace();
+		if let Some(return_match) = RETURN_REGEX.find(&parser.content[parser.index..]) {
+			for _ in 0..return_match.end() { parser.increment(); }
+			parser.parse_whitespace();
+			let mut reason = ExpressionEndReason::Unknown;
+			let expr = parser.parse_expression(file_name.to_string(), config_data, None, manager, &mut reason, None);
+			parser.parse_whitespace();
+			if parser.get_curr() == ';' {
+				parser.increment();
+				parser.parse_whitespace();
+				if parser.get_curr() == '}' && !expr.is_invalid() {
+					meta_function.body = Some(expr);
+				}
+			}
+		}
+		parser.reset(old_index, old_line);
+		meta_function
+	}
+
 	pub fn is_declaration(parser: &mut Parser) -> bool {
 		return Self::is_func_declaration(&parser.content, parser.index);
 	}
@@ -445,10 +628,46 @@ impl FunctionDeclaration {
 			name: self.name.clone(),
 			parameters: params,
 			return_type: self.return_type.clone(),
-			styles: self.props.clone()
+			styles: self.props.clone(),
+			no_discard: false,
+			call_conv: None,
+			self_style: self.self_style.clone(),
+			is_const: self.is_const
+		}
+	}
+
+	/// Generates the `Function` used for typing. Types that are type parameters
+	/// are inferred, as they depend on the arguments of each call.
+	pub fn to_typing_function(&self, content: &str, attributes: &Attributes) -> Function {
+		let mut func = self.to_function(content);
+		func.no_discard = attributes.has_attribute("NoDiscard");
+		if self.is_generic() {
+			for param in &mut func.parameters {
+				self.erase_type_param(&mut param.prop_type);
+			}
+			self.erase_type_param(&mut func.return_type);
+		}
+		func
+	}
+
+	fn erase_type_param(&self, var_type: &mut VariableType) {
+		if let Type::Undeclared(names) = &var_type.var_type {
+			if names.len() == 1 && self.type_params.contains(&names[0]) {
+				var_type.var_type = Type::Inferred;
+			}
 		}
 	}
 
+	pub fn is_generic(&self) -> bool {
+		!self.type_params.is_empty()
+	}
+
+	/// Generates the `template` declaration for a generic function.
+	pub fn template_declaration(&self, style: ConstraintStyle, library: &LibraryNames) -> String {
+		let constraints = self.constraints.iter().map(|c| (c.0.clone(), c.1.to_cpp(false, library))).collect::<Vec<(String, String)>>();
+		template_declaration(&self.type_params, &constraints, style, library)
+	}
+
 	pub fn header_only(&self) -> bool {
 		for s in &self.props {
 			if s.is_extern() {
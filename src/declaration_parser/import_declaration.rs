@@ -18,6 +18,7 @@ use regex::Regex;
 
 lazy_static! {
 	pub static ref IMPORT_REGEX: Regex = Regex::new(r"^\b(?:import|derive)\b").unwrap();
+	pub static ref PUB_SUFFIX_REGEX: Regex = Regex::new(r"\s+\bpub$").unwrap();
 }
 
 type ImportDeclarationResult = DeclarationResult<ImportDeclaration>;
@@ -26,7 +27,10 @@ type ImportDeclarationResult = DeclarationResult<ImportDeclaration>;
 pub struct ImportDeclaration {
 	pub path: String,
 	pub line: usize,
-	pub is_header: bool
+	pub is_header: bool,
+	pub is_pub: bool,
+	pub start_index: usize,
+	pub end_index: usize
 }
 
 impl Declaration<ImportDeclaration> for ImportDeclaration {
@@ -57,12 +61,20 @@ impl ImportDeclaration {
 		let content_start = parser.index;
 		declare_parse_until_char!(';', parser);
 
-		let import_path = parser.content[content_start..parser.index].to_string();
+		let mut import_path = parser.content[content_start..parser.index].trim_end().to_string();
+		let is_pub = PUB_SUFFIX_REGEX.is_match(&import_path);
+		if is_pub {
+			import_path = PUB_SUFFIX_REGEX.replace(&import_path, "").to_string();
+		}
 
+		let path_length = import_path.len();
 		return ImportDeclarationResult::Ok(ImportDeclaration {
 			path: import_path,
 			line: initial_line,
-			is_header: import_keyword == "derive"
+			is_header: import_keyword == "derive",
+			is_pub,
+			start_index: content_start,
+			end_index: content_start + path_length
 		});
 	}
 
@@ -13,11 +13,15 @@ use crate::{
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
 use crate::declaration_parser::cpp_transpiler::CPPTranspiler;
+use crate::declaration_parser::attributes::Attributes;
+
+use crate::string_util::parse_string_parameter;
 
 use regex::Regex;
 
 lazy_static! {
 	pub static ref INCLUDE_REGEX: Regex = Regex::new(r"^\b(?:include|contain)\b").unwrap();
+	pub static ref MACRO_NAME_REGEX: Regex = Regex::new(r"^[A-Za-z_]\w*$").unwrap();
 }
 
 type IncludeDeclarationResult = DeclarationResult<IncludeDeclaration>;
@@ -131,6 +135,40 @@ impl IncludeDeclaration {
 		});
 	}
 
+	/// Retrieves the preprocessor condition provided by `@If`, if any.
+	pub fn get_condition(attributes: &Attributes, content: &str) -> Option<String> {
+		if !attributes.has_attribute("If") {
+			return None;
+		}
+		let condition = attributes.get_attribute_parameters("If", content).join(", ").trim().to_string();
+		if condition.is_empty() { None } else { Some(condition) }
+	}
+
+	/// Retrieves the symbols from `@IfUsed`, which delay the include until one of them is used in the output.
+	/// Returns `None` if the attribute is not used.
+	pub fn get_used_symbols(attributes: &Attributes, content: &str) -> Option<Vec<String>> {
+		if !attributes.has_attribute("IfUsed") {
+			return None;
+		}
+		Some(attributes.get_attribute_parameters("IfUsed", content).iter()
+			.map(|p| parse_string_parameter(p))
+			.filter(|p| !p.is_empty())
+			.collect())
+	}
+
+	/// Wraps the include line with the condition from `@If`.
+	/// A lone macro name is checked with `#ifdef`; anything else is used as an `#if` expression.
+	pub fn wrap_with_condition(include_line: String, condition: Option<&String>) -> String {
+		match condition {
+			Some(cond) => format!("{} {}\n{}\n#endif",
+				if MACRO_NAME_REGEX.is_match(cond) { "#ifdef" } else { "#if" },
+				cond,
+				include_line
+			),
+			None => include_line
+		}
+	}
+
 	pub fn is_declaration(parser: &mut Parser) -> bool {
 		return Self::is_include_declaration(&parser.content, parser.index);
 	}
@@ -19,6 +19,7 @@ pub mod attribute_declaration;
 pub mod attribute_class_declaration;
 pub mod include_declaration;
 pub mod import_declaration;
+pub mod namespace_declaration;
 pub mod inject_declaration;
 pub mod refurbish_declaration;
 pub mod cpp_transpiler;
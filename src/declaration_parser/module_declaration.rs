@@ -5,6 +5,7 @@
  * individual Tasty Fresh source file.
  **********************************************************/
 
+use crate::config_management::LibraryNames;
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use crate::declaration_parser::parser::Parser;
@@ -14,6 +15,7 @@ use crate::declaration_parser::assume_declaration::AssumeDeclaration;
 use crate::declaration_parser::attribute_declaration::AttributeDeclaration;
 use crate::declaration_parser::function_declaration::{ FunctionDeclaration, FunctionDeclarationType };
 use crate::declaration_parser::import_declaration::ImportDeclaration;
+use crate::declaration_parser::namespace_declaration::NamespaceDeclaration;
 use crate::declaration_parser::include_declaration::IncludeDeclaration;
 use crate::declaration_parser::variable_declaration::VariableDeclaration;
 use crate::declaration_parser::class_declaration::ClassDeclaration;
@@ -22,12 +24,15 @@ use crate::declaration_parser::attribute_class_declaration::AttributeClassDeclar
 use crate::declaration_parser::inject_declaration::InjectDeclaration;
 use crate::declaration_parser::attributes::Attributes;
 
+use crate::context_management::error_limit_reached;
+
 #[derive(Clone)]
 pub enum DeclarationType {
 	ModuleAttribute(ModuleAttributeDeclaration),
 	Assume(AssumeDeclaration, Attributes),
 	Function(FunctionDeclaration, Attributes),
 	Import(ImportDeclaration, Attributes),
+	Namespace(NamespaceDeclaration, Attributes),
 	Include(IncludeDeclaration, Attributes),
 	Variable(VariableDeclaration, Attributes),
 	Class(ClassDeclaration, Attributes),
@@ -37,51 +42,64 @@ pub enum DeclarationType {
 }
 
 pub struct ModuleDeclaration {
-	pub declarations: Vec<DeclarationType>
+	pub declarations: Vec<DeclarationType>,
+	pub error_count: usize
+}
+
+/// The number of declarations parsed from a module, including class members.
+#[derive(Default)]
+pub struct DeclarationCounts {
+	pub declarations: usize,
+	pub classes: usize,
+	pub functions: usize
+}
+
+/// Moves the pending attributes and comments into the `Attributes` of the declaration that follows them.
+fn take_attributes(attributes: &mut Vec<AttributeDeclaration>, comments: &mut Vec<(usize, usize, usize)>) -> Attributes {
+	Attributes::with_comments(if attributes.is_empty() {
+		None
+	} else {
+		Some(std::mem::take(attributes))
+	}, std::mem::take(comments))
 }
 
 macro_rules! parse_declaration {
-	($DeclarationClass:ty, $DeclarationType:ident, $parser:expr, $file_name:expr, $declarations:expr, $attributes:expr) => {
+	($DeclarationClass:ty, $DeclarationType:ident, $parser:expr, $file_name:expr, $declarations:expr, $attributes:expr, $comments:expr) => {
 		if <$DeclarationClass>::is_declaration($parser) {
 			let result = <$DeclarationClass>::new($parser);
 			if result.is_error() {
 				result.print_error($file_name.to_string(), &$parser.content);
 			} else {
-				$declarations.push(DeclarationType::$DeclarationType(result.unwrap_and_move(), Attributes::new(if $attributes.is_empty() {
-					None
-				} else {
-					Some(std::mem::replace(&mut $attributes, Vec::new()))
-				})));
+				$declarations.push(DeclarationType::$DeclarationType(result.unwrap_and_move(), take_attributes(&mut $attributes, &mut $comments)));
 			}
 			$attributes.clear();
+			$comments.clear();
 			continue;
 		}
 	}
 }
 
 macro_rules! parse_declaration_w_file_name {
-	($DeclarationClass:ty, $DeclarationType:ident, $parser:expr, $file_name:expr, $declarations:expr, $attributes:expr) => {
+	($DeclarationClass:ty, $DeclarationType:ident, $parser:expr, $file_name:expr, $declarations:expr, $attributes:expr, $comments:expr) => {
 		if <$DeclarationClass>::is_declaration($parser) {
 			let result = <$DeclarationClass>::new($parser, $file_name);
 			if result.is_error() {
 				result.print_error($file_name.to_string(), &$parser.content);
 			} else {
-				$declarations.push(DeclarationType::$DeclarationType(result.unwrap_and_move(), Attributes::new(if $attributes.is_empty() {
-					None
-				} else {
-					Some(std::mem::replace(&mut $attributes, Vec::new()))
-				})));
+				$declarations.push(DeclarationType::$DeclarationType(result.unwrap_and_move(), take_attributes(&mut $attributes, &mut $comments)));
 			}
 			$attributes.clear();
+			$comments.clear();
 			continue;
 		}
 	}
 }
 
 impl ModuleDeclaration {
-	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure) -> ModuleDeclaration {
+	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure, library: &LibraryNames) -> ModuleDeclaration {
 		let mut declarations = Vec::new();
 		let mut attributes = Vec::new();
+		let mut comments = Vec::new();
 
 		while !parser.out_of_space {
 			parser.parse_whitespace();
@@ -98,8 +116,10 @@ impl ModuleDeclaration {
 			}
 		}
 
-		while !parser.out_of_space {
+		while !parser.out_of_space && !error_limit_reached() {
+			let comment_count = parser.comments.len();
 			parser.parse_whitespace();
+			comments.extend_from_slice(&parser.comments[comment_count..]);
 
 			let initial_index = parser.index;
 
@@ -114,57 +134,50 @@ impl ModuleDeclaration {
 			}
 
 			if FunctionDeclaration::is_declaration(parser) {
-				let result = FunctionDeclaration::new(parser, FunctionDeclarationType::ModuleLevel, None);
+				let result = FunctionDeclaration::new(parser, FunctionDeclarationType::ModuleLevel, None, library);
 				if result.is_error() {
 					result.print_error(file_name.to_string(), &parser.content);
+					parser.skip_declaration();
 				} else {
-					declarations.push(DeclarationType::Function(result.unwrap_and_move(), Attributes::new(if attributes.is_empty() {
-						None
-					} else {
-						Some(std::mem::replace(&mut attributes, Vec::new()))
-					})));
+					declarations.push(DeclarationType::Function(result.unwrap_and_move(), take_attributes(&mut attributes, &mut comments)));
 				}
 				attributes.clear();
+				comments.clear();
 				continue;
 			}
 
 			if ClassDeclaration::is_declaration(parser) {
-				let result = ClassDeclaration::new(parser, file_name, operator_data);
+				let result = ClassDeclaration::new(parser, file_name, operator_data, library);
 				if result.is_error() {
 					result.print_error(file_name.to_string(), &parser.content);
 				} else {
-					declarations.push(DeclarationType::Class(result.unwrap_and_move(), Attributes::new(if attributes.is_empty() {
-						None
-					} else {
-						Some(std::mem::replace(&mut attributes, Vec::new()))
-					})));
+					declarations.push(DeclarationType::Class(result.unwrap_and_move(), take_attributes(&mut attributes, &mut comments)));
 				}
 				attributes.clear();
+				comments.clear();
 				continue;
 			}
 
 			if RefurbishDeclaration::is_declaration(parser) {
-				let result = RefurbishDeclaration::new(parser, file_name, operator_data);
+				let result = RefurbishDeclaration::new(parser, file_name, operator_data, library);
 				if result.is_error() {
 					result.print_error(file_name.to_string(), &parser.content);
 				} else {
-					declarations.push(DeclarationType::Refurbish(result.unwrap_and_move(), Attributes::new(if attributes.is_empty() {
-						None
-					} else {
-						Some(std::mem::replace(&mut attributes, Vec::new()))
-					})));
+					declarations.push(DeclarationType::Refurbish(result.unwrap_and_move(), take_attributes(&mut attributes, &mut comments)));
 				}
 				attributes.clear();
+				comments.clear();
 				continue;
 			}
 
-			parse_declaration!(AssumeDeclaration, Assume, parser, file_name, declarations, attributes);
-			parse_declaration!(ImportDeclaration, Import, parser, file_name, declarations, attributes);
-			parse_declaration!(IncludeDeclaration, Include, parser, file_name, declarations, attributes);
-			parse_declaration!(VariableDeclaration, Variable, parser, file_name, declarations, attributes);
-			parse_declaration!(InjectDeclaration, Injection, parser, file_name, declarations, attributes);
+			parse_declaration!(AssumeDeclaration, Assume, parser, file_name, declarations, attributes, comments);
+			parse_declaration!(NamespaceDeclaration, Namespace, parser, file_name, declarations, attributes, comments);
+			parse_declaration!(ImportDeclaration, Import, parser, file_name, declarations, attributes, comments);
+			parse_declaration!(IncludeDeclaration, Include, parser, file_name, declarations, attributes, comments);
+			parse_declaration!(VariableDeclaration, Variable, parser, file_name, declarations, attributes, comments);
+			parse_declaration!(InjectDeclaration, Injection, parser, file_name, declarations, attributes, comments);
 
-			parse_declaration_w_file_name!(AttributeClassDeclaration, AttributeClass, parser, file_name, declarations, attributes);
+			parse_declaration_w_file_name!(AttributeClassDeclaration, AttributeClass, parser, file_name, declarations, attributes, comments);
 
 			if !parser.out_of_space { parser.increment(); }
 
@@ -174,7 +187,31 @@ impl ModuleDeclaration {
 		}
 
 		return ModuleDeclaration {
-			declarations: declarations
+			declarations,
+			error_count: 0
 		}
 	}
-}
\ No newline at end of file
+
+	pub fn count_declarations(&self) -> DeclarationCounts {
+		let mut counts = DeclarationCounts::default();
+		Self::add_declaration_counts(&self.declarations, &mut counts);
+		counts
+	}
+
+	fn add_declaration_counts(declarations: &[DeclarationType], counts: &mut DeclarationCounts) {
+		for declaration in declarations {
+			counts.declarations += 1;
+			match declaration {
+				DeclarationType::Function(..) => counts.functions += 1,
+				DeclarationType::Class(class_declaration, _) => {
+					counts.classes += 1;
+					Self::add_declaration_counts(&class_declaration.declarations, counts);
+					if let Some(abstract_declarations) = &class_declaration.abstract_declarations {
+						Self::add_declaration_counts(abstract_declarations, counts);
+					}
+				},
+				_ => ()
+			}
+		}
+	}
+}
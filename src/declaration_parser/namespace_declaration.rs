@@ -0,0 +1,93 @@
+/**********************************************************
+ * --- Namespace Declaration ---
+ *
+ * Represents and parses a namespace statement, which
+ * places the declarations of the module within a C++
+ * namespace.
+ **********************************************************/
+
+use crate::{
+	declare_parse_required_whitespace,
+	declare_parse_whitespace,
+	declare_parse_ascii,
+	declare_parse_required_ascii
+};
+
+use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
+use crate::declaration_parser::parser::Parser;
+use crate::declaration_parser::cpp_transpiler::CPPTranspiler;
+
+use regex::Regex;
+
+lazy_static! {
+	pub static ref NAMESPACE_REGEX: Regex = Regex::new(r"^\b(?:inline\s+)?namespace\b").unwrap();
+}
+
+type NamespaceDeclarationResult = DeclarationResult<NamespaceDeclaration>;
+
+#[derive(Clone)]
+pub struct NamespaceDeclaration {
+	pub name: String,
+	pub is_inline: bool,
+	pub line: usize,
+	pub start_index: usize,
+	pub end_index: usize
+}
+
+impl Declaration<NamespaceDeclaration> for NamespaceDeclaration {
+	fn out_of_space_error_msg() -> &'static str {
+		"unexpected end of namespace"
+	}
+}
+
+impl CPPTranspiler for NamespaceDeclaration {
+	fn to_cpp(&self) -> String {
+		format!("{}namespace {} {{", if self.is_inline { "inline " } else { "" }, self.name)
+	}
+}
+
+impl NamespaceDeclaration {
+	pub fn new(parser: &mut Parser) -> NamespaceDeclarationResult {
+		let initial_line = parser.line;
+		let start_index = parser.index;
+
+		let mut keyword = "".to_string();
+		declare_parse_ascii!(keyword, parser);
+		let is_inline = keyword == "inline";
+		if is_inline {
+			declare_parse_required_whitespace!(parser);
+			declare_parse_ascii!(keyword, parser);
+		}
+		if keyword != "namespace" {
+			return NamespaceDeclarationResult::Err("Unexpected Keyword", "\"namespace\" keyword expected", parser.index - keyword.len(), parser.index);
+		}
+
+		declare_parse_required_whitespace!(parser);
+
+		let mut name = "".to_string();
+		declare_parse_required_ascii!(name, "Namespace Name Missing", "namespace name missing", parser);
+
+		declare_parse_whitespace!(parser);
+
+		if parser.get_curr() != ';' {
+			return NamespaceDeclarationResult::Err("Unexpected Symbol", "expected ';' operator", parser.index, parser.index + 1);
+		}
+
+		NamespaceDeclarationResult::Ok(NamespaceDeclaration {
+			name,
+			is_inline,
+			line: initial_line,
+			start_index,
+			end_index: parser.index + 1
+		})
+	}
+
+	pub fn is_declaration(parser: &mut Parser) -> bool {
+		Self::is_namespace_declaration(&parser.content, parser.index)
+	}
+
+	pub fn is_namespace_declaration(content: &str, index: usize) -> bool {
+		let declare = &content[index..];
+		NAMESPACE_REGEX.is_match(declare)
+	}
+}
@@ -13,6 +13,7 @@ use crate::expression::expression_parser::{ ExpressionParser, ExpressionEndReaso
 
 use crate::config_management::ConfigData;
 
+use crate::context_management::{ print_code_error, error_count };
 use crate::context_management::position::Position;
 use crate::context_management::context::Context;
 use crate::context_management::context_manager::ContextManager;
@@ -28,12 +29,15 @@ use std::rc::Rc;
 /// * `index` - The index of the `char` the parser is currently parsing.
 /// * `line` - This is incremented whenever a new line character (`\n`) is encountered.
 /// * `out_of_space` - This is set to `true` if the parser hits the end of `chars`.
+/// * `unclosed_group` - The index of the first `(` or `{` left open when `parse_until_at_expr` hits the end of `chars`.
 pub struct Parser {
 	pub content: String,
 	pub chars: Vec<char>,
 	pub index: usize,
 	pub line: usize,
-	pub out_of_space: bool
+	pub out_of_space: bool,
+	pub unclosed_group: Option<usize>,
+	pub comments: Vec<(usize, usize, usize)>
 }
 
 impl Parser {
@@ -44,7 +48,9 @@ impl Parser {
 			chars: chars,
 			index: 0,
 			line: 0,
-			out_of_space: false
+			out_of_space: false,
+			unclosed_group: None,
+			comments: Vec::new()
 		}
 	}
 
@@ -177,7 +183,8 @@ impl Parser {
 	pub fn curr_is_valid_op_char(&self) -> bool {
 		return match self.get_curr() {
 			'!' => true, '%' => true, '^' => true, '&' => true, '*' => true, '[' => true, ']' => true,
-			'+' => true, '-' => true, '<' => true, '>' => true, '?' => true, '/' => true, _ => false
+			'+' => true, '-' => true, '<' => true, '>' => true, '?' => true, '/' => true, '=' => true,
+			'|' => true, '~' => true, _ => false
 		};
 	}
 
@@ -235,9 +242,14 @@ impl Parser {
 		return true;
 	}
 
+	/// Skips the comment at the current index, if there is one.
+	/// The start index, end index, and line of each comment are recorded in `comments`.
 	pub fn check_and_parse_comments(&mut self) -> bool {
+		let start = self.index;
+		let start_line = self.line;
 		if self.check_ahead("//") {
 			self.parse_until('\n');
+			self.comments.push((start, self.index, start_line));
 			self.increment();
 			self.line += 1;
 			return true;
@@ -249,6 +261,7 @@ impl Parser {
 				}
 			}
 			self.increment();
+			self.comments.push((start, self.index, start_line));
 			return true;
 		}
 		return false;
@@ -299,6 +312,7 @@ impl Parser {
 			if self.increment() {
 				return result;
 			} else if self.get_curr() == ')' {
+				self.increment();
 				return "()".to_string();
 			}
 		}
@@ -352,6 +366,9 @@ impl Parser {
 		}
 		let mut brackets = 0;
 		let mut parentheses = 0;
+		let mut open_groups = Vec::new();
+		let mut mismatched_group = None;
+		self.unclosed_group = None;
 		while !self.out_of_space {
 			if self.parse_string() {
 				if self.increment() {
@@ -370,6 +387,19 @@ impl Parser {
 				')' => parentheses -= 1,
 				_ => ()
 			}
+			match self.get_curr() {
+				'{' | '(' => open_groups.push(self.index),
+				'}' | ')' => {
+					// A group closed by the wrong character was never closed itself.
+					let open_char = if self.get_curr() == '}' { '{' } else { '(' };
+					if let Some(index) = open_groups.pop() {
+						if self.chars[index] != open_char && mismatched_group.is_none() {
+							mismatched_group = Some(index);
+						}
+					}
+				},
+				_ => ()
+			}
 			if brackets <= 0 && parentheses <= 0 {
 				if self.get_curr() == c && ((c != '}' || brackets < 0) && (c != ')' || parentheses < 0)) ||
 					self.get_curr() == c2 && ((c2 != '}' || brackets < 0) && (c2 != ')' || parentheses < 0)) {
@@ -378,9 +408,42 @@ impl Parser {
 				}
 			}
 		}
+		if self.out_of_space {
+			self.unclosed_group = mismatched_group.or(open_groups.first().copied());
+		}
 		return true;
 	}
 
+	/// Brings the parser past the rest of a declaration that could not be parsed,
+	/// so its remaining content is not reported as more invalid declarations.
+	/// The declaration ends after its body, or after a `;` if it has none.
+	pub fn skip_declaration(&mut self) {
+		let mut brackets = 0;
+		while !self.out_of_space {
+			if self.parse_whitespace() {
+				continue;
+			}
+			if !self.parse_string() {
+				match self.get_curr() {
+					';' if brackets == 0 => {
+						self.increment();
+						return;
+					},
+					'{' => brackets += 1,
+					'}' => {
+						brackets -= 1;
+						if brackets <= 0 {
+							self.increment();
+							return;
+						}
+					},
+					_ => ()
+				}
+			}
+			self.increment();
+		}
+	}
+
 	/// Checks if the immediate content is a valid `int`, `float`, `double`, or `long` literal.
 	///
 	/// # Return
@@ -402,8 +465,10 @@ impl Parser {
 	/// Returns `true` if the content is a `string` literal; otherwise `false`.
 	pub fn check_for_string(&mut self) -> bool {
 		let old_index = self.index;
+		let old_line = self.line;
 		let result = self.parse_string();
 		self.index = old_index;
+		self.line = old_line;
 		return result;
 	}
 
@@ -421,15 +486,10 @@ impl Parser {
 				return false;
 			}
 			match self.get_curr() {
-				'"' => {
-					if !is_raw {
-						break;
-					}
-				},
-				'\\' => {
-					if !self.parse_escape_char() {
-						return false;
-					}
+				'"' if !is_raw => break,
+				'\\' if !is_raw && !self.parse_escape_char() => return false,
+				'\n' => {
+					self.line += 1;
 				},
 				')' => {
 					if is_raw {
@@ -539,9 +599,30 @@ impl Parser {
 	///
 	/// Returns the configuration data that's taken ownership of.
 	pub fn parse_expression(&mut self, file_name: String, config_data: &ConfigData, mut context: Option<&mut Context>, manager: &mut ContextManager, reason: &mut ExpressionEndReason, final_desired_type: Option<VariableType>) -> Rc<Expression> {
-		let expr_parser = ExpressionParser::new(self, Position::new(file_name, Some(self.line), self.index, None), config_data, &mut context, manager, None, final_desired_type);
+		let expr_start = self.index + self.content[self.index..].len() - self.content[self.index..].trim_start_matches([' ', '\t']).len();
+		let errors_before = error_count();
+		let column = self.index - self.chars[..self.index.min(self.chars.len())].iter().rposition(|c| *c == '\n').map(|i| i + 1).unwrap_or(0);
+		let expr_parser = ExpressionParser::new(self, Position::new(file_name.clone(), Some(self.line + 1), column, None), config_data, &mut context, manager, None, final_desired_type);
 		self.line += expr_parser.position.line_offset;
 		*reason = expr_parser.end_data.reason;
+		if config_data.immutable_let {
+			if let Some(name) = expr_parser.expression.find_const_modification() {
+				let position = Position::new(file_name.clone(), None, expr_start, Some(self.index));
+				print_code_error("Const Modification", &format!("cannot modify \"{}\" because it is const", name), &position, &self.content);
+			}
+		}
+		let allow_reinterpret = context.as_ref().map(|c| c.allow_reinterpret).unwrap_or(config_data.allow_reinterpret);
+		if !allow_reinterpret && expr_parser.expression.find_reinterpret_cast().is_some() {
+			let position = Position::new(file_name.clone(), None, expr_start, Some(self.index));
+			print_code_error("Unsafe Cast", "reinterpret casts require @Unsafe on the function or --allow-reinterpret", &position, &self.content);
+		}
+		if expr_parser.expression.is_invalid() && error_count() == errors_before {
+			let position = Position::new(file_name, None, expr_start, Some(self.index.max(expr_start + 1)));
+			print_code_error("Invalid Expression", "could not parse this expression", &position, &self.content);
+		}
+		if config_data.fold_constants {
+			return expr_parser.expression.simplify(&self.content);
+		}
 		return expr_parser.expression;
 	}
 
@@ -556,6 +637,41 @@ impl Parser {
 	///
 	/// Returns the `Type` as a primitive, `Inferred`, `Undeclared` or `UndeclaredWParams`.
 	pub fn parse_type(&mut self, unexpected_character: &mut bool, conflicting_specifiers: &mut Option<&'static str>) -> Type {
+		let mut result = self.parse_type_without_array(unexpected_character, conflicting_specifiers);
+
+		// Check for Fixed-Size Array Dimensions
+		let mut sizes = Vec::new();
+		loop {
+			let old_index = self.index;
+			let old_line = self.line;
+			self.parse_whitespace();
+			if self.out_of_space || self.get_curr() != '[' {
+				self.reset(old_index, old_line);
+				break;
+			}
+			self.increment();
+			self.parse_whitespace();
+			let mut size = "".to_string();
+			while !self.out_of_space && self.curr_is_numeric() {
+				size.push(self.get_curr());
+				self.increment();
+			}
+			self.parse_whitespace();
+			if size.is_empty() || self.get_curr() != ']' {
+				self.reset(old_index, old_line);
+				break;
+			}
+			self.increment();
+			sizes.push(size.parse::<usize>().unwrap_or(0));
+		}
+		for size in sizes.iter().rev() {
+			result = Type::Array(Box::new(VariableType::copy(result)), *size);
+		}
+		result
+	}
+
+	/// Parses a Tasty Fresh type without any fixed-size array dimensions following it.
+	fn parse_type_without_array(&mut self, unexpected_character: &mut bool, conflicting_specifiers: &mut Option<&'static str>) -> Type {
 
 		// Ensure Content Exists
 		if self.check_for_end() { return Type::Inferred; }
@@ -594,6 +710,7 @@ impl Parser {
 					}
 					if self.get_curr() == ',' {
 						self.increment();
+						self.parse_whitespace();
 					} else if self.get_curr() == ')' {
 						self.increment();
 						if !is_function {
@@ -631,7 +748,11 @@ impl Parser {
 						name: "".to_string(),
 						parameters: final_parameters,
 						return_type: ret_type,
-						styles: Vec::<FunStyle>::new()
+						styles: Vec::<FunStyle>::new(),
+						no_discard: false,
+						call_conv: None,
+						self_style: None,
+						is_const: false
 					}));
 				}
 			} else {
@@ -639,7 +760,11 @@ impl Parser {
 					name: "".to_string(),
 					parameters: final_parameters,
 					return_type: VariableType::void(),
-					styles: Vec::new()
+					styles: Vec::new(),
+					no_discard: false,
+					call_conv: None,
+					self_style: None,
+					is_const: false
 				}));
 			}
 		}
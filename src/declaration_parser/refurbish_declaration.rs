@@ -12,6 +12,7 @@ use crate::{
 	declare_parse_type
 };
 
+use crate::config_management::LibraryNames;
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use crate::expression::variable_type::Type;
@@ -44,7 +45,7 @@ impl Declaration<RefurbishDeclaration> for RefurbishDeclaration {
 }
 
 impl RefurbishDeclaration {
-	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure) -> RefurbishDeclarationResult {
+	pub fn new(parser: &mut Parser, file_name: &str, operator_data: &OperatorDataStructure, library: &LibraryNames) -> RefurbishDeclarationResult {
 
 		let mut refurbish_keyword = "".to_string();
 		declare_parse_ascii!(refurbish_keyword, parser);
@@ -81,7 +82,7 @@ impl RefurbishDeclaration {
 			}
 
 			if FunctionDeclaration::is_declaration(parser) {
-				let result = FunctionDeclaration::new(parser, FunctionDeclarationType::ClassLevel, Some(operator_data));
+				let result = FunctionDeclaration::new(parser, FunctionDeclarationType::ClassLevel, Some(operator_data), library);
 				if result.is_error() {
 					result.print_error(file_name.to_string(), &parser.content);
 				} else {
@@ -124,8 +125,8 @@ impl RefurbishDeclaration {
 		return REFURBISH_REGEX.is_match(declare);
 	}
 
-	pub fn make_name(&self) -> String {
+	pub fn make_name(&self, library: &LibraryNames) -> String {
 		let re = Regex::new(r"(?:\.|::|<|>|,|\s)").unwrap();
-		return re.replace_all(&self.refurbish_type.to_cpp(false), "_").to_string();
+		re.replace_all(&self.refurbish_type.to_cpp(false, library), "_").to_string()
 	}
 }
\ No newline at end of file
@@ -22,15 +22,18 @@ use crate::expression::variable_type::{ VariableType, Type, VarStyle, VarProps }
 
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
+use crate::declaration_parser::attributes::Attributes;
 
 use crate::context_management::context::Context;
 
+use crate::config_management::LibraryNames;
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use regex::Regex;
 
 lazy_static! {
 	pub static ref VAR_PROP_REGEX: Regex = Regex::new(r"^\b(?:copy|ref|borrow|move|ptr|autoptr|uniqueptr|classptr|let|ptr2|ptr3|ptr4|ptr5|ptr6|ptr7|ptr8|ptr9)\b").unwrap();
+	pub static ref MUT_REGEX: Regex = Regex::new(r"^\bmut\s").unwrap();
 	pub static ref VAR_STYLE_REGEX: Regex = Regex::new(r"^\b(?:const|constexpr|constinit|extern|mutable|forever|thread_local|volatile|declare)\b").unwrap();
 }
 
@@ -43,6 +46,10 @@ pub struct VariableDeclaration {
 	pub line: usize,
 	pub value: Option<(usize, usize)>,
 	pub pure_assign: bool,
+	pub is_mut: bool,
+	pub bindings: Option<Vec<String>>,
+	pub uninit: Option<(usize, usize)>,
+	pub alignment: Option<usize>,
 	pub declaration_id: usize
 }
 
@@ -87,9 +94,50 @@ impl VariableDeclaration {
 		// Parse Whitespace
 		declare_parse_required_whitespace!(parser);
 
+		// Parse Mut
+		let mut is_mut = false;
+		if var_style.is_inferred() && MUT_REGEX.is_match(&parser.content[parser.index..]) {
+			let mut mut_keyword = "".to_string();
+			declare_parse_ascii!(mut_keyword, parser);
+			declare_parse_required_whitespace!(parser);
+			is_mut = true;
+		}
+
+		// Parse Structured Binding Names
+		let mut bindings: Option<Vec<String>> = None;
+		if parser.get_curr() == '(' {
+			let mut names = Vec::new();
+			let mut next_char = ' ';
+			declare_parse_required_next_char!('(', next_char, parser);
+			loop {
+				declare_parse_whitespace!(parser);
+				let mut binding_name = "".to_string();
+				declare_parse_required_ascii!(binding_name, "Binding Name Missing", "binding name missing", parser);
+				names.push(binding_name);
+				declare_parse_whitespace!(parser);
+				if parser.get_curr() == ',' {
+					delcare_increment!(parser);
+				} else if parser.get_curr() == ')' {
+					delcare_increment!(parser);
+					break;
+				} else {
+					return VariableDeclarationResult::Err("Unexpected Symbol", "expected ',' or ')'", parser.index, parser.index + 1);
+				}
+			}
+			declare_parse_whitespace!(parser);
+			if parser.get_curr() != '=' {
+				return VariableDeclarationResult::Err("Value Expected", "structured bindings require a value", parser.index, parser.index + 1);
+			}
+			bindings = Some(names);
+		}
+
 		// Parse Var Name
 		let mut variable_name = "".to_string();
-		declare_parse_required_ascii!(variable_name, "Variable Name Missing", "variable name missing", parser);
+		if let Some(names) = &bindings {
+			variable_name = names.join(", ");
+		} else {
+			declare_parse_required_ascii!(variable_name, "Variable Name Missing", "variable name missing", parser);
+		}
 
 		// Parse Whitespace
 		declare_parse_whitespace!(parser);
@@ -133,11 +181,27 @@ impl VariableDeclaration {
 
 		// Parse Expression
 		let mut value: Option<(usize, usize)> = None;
+		let mut uninit: Option<(usize, usize)> = None;
 		if has_value {
 			let start = parser.index;
 			declare_parse_expr_until_next_char!(';', parser);
 			let end = parser.index;
-			value = Some((start, end));
+			let value_content = &parser.content[start..end];
+			if value_content.trim() == "uninit" {
+				let uninit_start = start + value_content.find("uninit").unwrap();
+				if var_type.is_inferred() || bindings.is_some() {
+					return VariableDeclarationResult::Err("Unknown Variable Type", "uninit requires an explicit type", uninit_start, uninit_start + 6);
+				}
+				match var_style {
+					VarStyle::Ref | VarStyle::Borrow | VarStyle::Move => {
+						return VariableDeclarationResult::Err("Invalid Uninit", "references cannot be left uninitialized", uninit_start, uninit_start + 6);
+					},
+					_ => ()
+				}
+				uninit = Some((uninit_start, uninit_start + 6));
+			} else {
+				value = Some((start + value_content.len() - value_content.trim_start().len(), end));
+			}
 		}
 
 		return VariableDeclarationResult::Ok(VariableDeclaration {
@@ -151,6 +215,10 @@ impl VariableDeclaration {
 			line: initial_line,
 			value: value,
 			pure_assign: pure_assign,
+			is_mut,
+			bindings,
+			uninit,
+			alignment: None,
 			declaration_id: 0
 		});
 	}
@@ -167,10 +235,108 @@ impl VariableDeclaration {
 		return VAR_STYLE_REGEX.is_match(declare);
 	}
 
+	/// Makes a `let` binding const if it is not marked `mut`.
+	/// Pointer styles and bindings without a value are left as they are.
+	pub fn apply_immutable_let(&mut self) {
+		if self.is_mut || self.value.is_none() || !self.var_type.var_style.is_ptr().map(|is_ptr| !is_ptr).unwrap_or(false) {
+			return;
+		}
+		if let Some(props) = &mut self.var_type.var_properties {
+			if !props.contains(&VarProps::Const) && !props.contains(&VarProps::Constexpr) {
+				props.insert(0, VarProps::Const);
+			}
+		}
+	}
+
 	pub fn is_only_static(&self) -> bool {
 		return self.var_type.is_only_static();
 	}
 
+	/// Retrieves the width provided to `@Bitfield`.
+	/// Returns `None` unless it is a positive integer that fits within the variable's integer type.
+	pub fn get_bitfield_width(&self, attributes: &Attributes, content: &str) -> Option<usize> {
+		let params = attributes.get_attribute_parameters("Bitfield", content);
+		if params.len() != 1 {
+			return None;
+		}
+		let bits = match &self.var_type.var_type {
+			Type::Number(number_type) => number_type.integer_range().map(|range| range.0)?,
+			_ => return None
+		};
+		params[0].trim().parse::<usize>().ok().filter(|width| *width > 0 && *width <= bits)
+	}
+
+	/// Generates the `alignas` specifier for a variable with `@Align`.
+	pub fn alignment_to_cpp(&self) -> String {
+		match self.alignment {
+			Some(alignment) => format!("alignas({}) ", alignment),
+			None => "".to_string()
+		}
+	}
+
+	fn props_to_cpp(&self) -> String {
+		if self.var_type.var_properties.is_none() {
+			return "".to_string();
+		}
+		let mut result = Vec::new();
+		for prop in self.var_type.var_properties.as_ref().unwrap() {
+			let name = prop.get_name();
+			if !name.is_empty() {
+				result.push(name);
+			}
+		}
+		if result.is_empty() {
+			"".to_string()
+		} else {
+			format!("{} ", result.join(" "))
+		}
+	}
+
+	/// Generates the class header declaration for a member with `@Bitfield`.
+	/// Bitfields cannot have default member initializers, so none is added.
+	pub fn to_bitfield_cpp(&self, width: usize, library: &LibraryNames) -> String {
+		format!("{}{} {} : {};", self.props_to_cpp(), self.var_type.to_cpp(library), self.name, width)
+	}
+
+	/// Checks whether the initializer of this module variable can be moved into the module's init function.
+	/// Constants, references, literal initializers, and constructed values must stay where the variable is declared,
+	/// since the variable is default constructed before the init function assigns it.
+	pub fn can_move_to_module_init(&self, expr: &Rc<Expression>) -> bool {
+		if self.var_type.is_const() || self.var_type.is_inferred() || self.is_only_static() || self.bindings.is_some() || expr.is_literal() {
+			return false;
+		}
+		match self.var_type.var_style {
+			VarStyle::Ref | VarStyle::Borrow | VarStyle::Move => false,
+			VarStyle::Copy | VarStyle::Infer => !expr.is_construction_call(),
+			_ => true
+		}
+	}
+
+	/// Generates the assignment that initializes this variable within the module's init function.
+	pub fn to_module_init_cpp(&self, expr: &Rc<Expression>, operators: &OperatorDataStructure, context: &mut Context) -> String {
+		let var_type = &self.var_type;
+		if expr.is_construction_call() {
+			let var_type_name = var_type.var_type.to_cpp(false, &context.library);
+			let params_str = expr.get_parameters(operators, context).join(", ");
+			let value = match var_type.var_style {
+				VarStyle::AutoPtr => format!("{}<{}>({})", context.library.std_name("make_shared"), var_type_name, params_str),
+				VarStyle::UniquePtr => format!("{}<{}>({})", context.library.std_name("make_unique"), var_type_name, params_str),
+				VarStyle::ClassPtr => format!("{}(new {}({}))", var_type.to_cpp(&context.library), var_type_name, params_str),
+				_ => format!("new {}({})", var_type_name, params_str)
+			};
+			return format!("{} = {};", self.name, value);
+		}
+		let right_str = expr.to_string(operators, context);
+		format!("{} = {};",
+			self.name,
+			if self.pure_assign || expr.get_type().is_inferred() {
+				right_str
+			} else {
+				expr.get_type().convert_between_styles(var_type, &right_str, &context.library).unwrap_or(right_str.to_string())
+			}
+		)
+	}
+
 	pub fn to_cpp(&self,
 		expr: &Option<Rc<Expression>>,
 		operators: &OperatorDataStructure,
@@ -180,22 +346,18 @@ impl VariableDeclaration {
 
 		let var_type = &self.var_type;
 		let default_value = var_type.default_value();
-		let props = if var_type.var_properties.is_some() && !export_type.is_class_source() {
-			let mut result = Vec::new();
-			for prop in var_type.var_properties.as_ref().unwrap() {
-				let name = prop.get_name();
-				if !name.is_empty() {
-					result.push(name);
-				}
-			}
-			if result.is_empty() {
-				"".to_string()
-			} else {
-				format!("{} ", result.join(" "))
-			}
-		} else {
-			"".to_string()
-		};
+		let props = if !export_type.is_class_source() { format!("{}{}", self.alignment_to_cpp(), self.props_to_cpp()) } else { "".to_string() };
+
+		if let Some(names) = &self.bindings {
+			let binding_type = match var_type.var_style {
+				VarStyle::Ref => "auto&",
+				VarStyle::Borrow => "const auto&",
+				VarStyle::Move => "auto&&",
+				_ => "auto"
+			};
+			let right_str = expr.as_ref().map(|e| e.to_string(operators, context)).unwrap_or("".to_string());
+			return format!("{}{} [{}] = {};", props, binding_type, names.join(", "), right_str);
+		}
 
 		let final_name = if !export_type.is_class_source() { self.name.to_string() } else { format!("{}::{}", export_type.get_source_name(), self.name) };
 
@@ -204,8 +366,8 @@ impl VariableDeclaration {
 			let is_construction = expr.as_ref().unwrap().is_construction_call();
 
 			if is_construction {
-				let var_type_output = var_type.to_cpp();
-				let var_type_name = var_type.var_type.to_cpp(false);
+				let var_type_output = var_type.to_cpp(&context.library);
+				let var_type_name = var_type.var_type.to_cpp(false, &context.library);
 				let params = expr.as_ref().unwrap().get_parameters(operators, context);
 				let params_str = params.join(", ");
 				match var_type.var_style {
@@ -229,10 +391,13 @@ impl VariableDeclaration {
 						}
 					},
 					VarStyle::AutoPtr => {
-						return format!("{}std::shared_ptr<{}> {} = std::make_shared<{}>({});", props, var_type_output, final_name, var_type_name, params_str);
+						return format!("{}{}<{}> {} = {}<{}>({});", props, context.library.std_name("shared_ptr"), var_type_output, final_name, context.library.std_name("make_shared"), var_type_name, params_str);
 					},
 					VarStyle::UniquePtr => {
-						return format!("{}std::unique_ptr<{}> {} = std::make_unique<{}>({});", props, var_type_output, final_name, var_type_name, params_str);
+						return format!("{}{}<{}> {} = {}<{}>({});", props, context.library.std_name("unique_ptr"), var_type_output, final_name, context.library.std_name("make_unique"), var_type_name, params_str);
+					},
+					VarStyle::ClassPtr => {
+						return format!("{}{} {} = {}(new {}({}));", props, var_type_output, final_name, var_type_output, var_type_name, params_str);
 					},
 					_ => ()
 				}
@@ -241,19 +406,19 @@ impl VariableDeclaration {
 			let right_str = expr.as_ref().unwrap().to_string(operators, context);
 			return format!("{}{} {} = {};",
 				props,
-				var_type.to_cpp(),
+				var_type.to_cpp(&context.library),
 				final_name,
 				if self.pure_assign || expr.as_ref().unwrap().get_type().is_inferred(){
 					right_str
 				} else {
-					expr.as_ref().unwrap().get_type().convert_between_styles(var_type, &right_str).unwrap_or(right_str.to_string())
+					expr.as_ref().unwrap().get_type().convert_between_styles(var_type, &right_str, &context.library).unwrap_or(right_str.to_string())
 				}
 			);
-		} else if default_value.is_some() {
-			return format!("{}{} {} = {};", props, var_type.to_cpp(), final_name, default_value.unwrap());
+		} else if default_value.is_some() && self.uninit.is_none() {
+			format!("{}{} {} = {};", props, var_type.to_cpp(&context.library), final_name, default_value.unwrap())
 		} else {
-			return format!("{}{} {};", props, var_type.to_cpp(), final_name);
-		};
+			format!("{}{} {};", props, var_type.to_cpp(&context.library), final_name)
+		}
 	}
 }
 
@@ -0,0 +1,222 @@
+/**********************************************************
+ * --- Const Eval ---
+ *
+ * Evaluates calls to meta functions while transpiling
+ * so they can be replaced with their constant result.
+ **********************************************************/
+
+use crate::expression::Expression;
+use crate::expression::variable_type::{ VariableType, Type };
+use crate::expression::value_type::NumberType;
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+/// A meta function that has been registered for evaluation.
+/// Its body must consist of a single return statement.
+#[derive(Clone)]
+pub struct MetaFunction {
+	pub parameters: Vec<(String, VariableType)>,
+	pub return_type: VariableType,
+	pub body: Option<Rc<Expression>>
+}
+
+#[derive(Clone, PartialEq)]
+pub enum ConstValue {
+	Int(i64),
+	Float(f64),
+	Bool(bool),
+	Str(String)
+}
+
+impl ConstValue {
+	/// Parses the content of a literal value.
+	/// Strings keep their escape sequences so they can be output unchanged.
+	pub fn from_literal(content: &str) -> Option<ConstValue> {
+		if content == "true" || content == "false" {
+			return Some(ConstValue::Bool(content == "true"));
+		}
+		if content.len() >= 2 && content.starts_with('"') && content.ends_with('"') {
+			return Some(ConstValue::Str(content[1..content.len() - 1].to_string()));
+		}
+		let number = content.replace('\'', "");
+		if let Ok(value) = number.parse::<i64>() {
+			return Some(ConstValue::Int(value));
+		}
+		if number.starts_with(|c: char| c.is_ascii_digit()) {
+			if let Ok(value) = number.parse::<f64>() {
+				return Some(ConstValue::Float(value));
+			}
+		}
+		None
+	}
+
+	pub fn to_cpp(&self) -> String {
+		match self {
+			ConstValue::Int(value) => value.to_string(),
+			ConstValue::Float(value) => format!("{:?}", value),
+			ConstValue::Bool(value) => value.to_string(),
+			ConstValue::Str(value) => format!("\"{}\"", value)
+		}
+	}
+
+	/// Converts numbers to match the numeric type they are assigned to.
+	pub fn convert_to(self, var_type: &VariableType) -> ConstValue {
+		if let Type::Number(number_type) = &var_type.var_type {
+			if let NumberType::UnknownNumber = number_type {
+				return self;
+			}
+			return match (self, number_type.integer_range().is_some()) {
+				(ConstValue::Int(value), false) => ConstValue::Float(value as f64),
+				(ConstValue::Float(value), true) => ConstValue::Int(value as i64),
+				(value, _) => value
+			};
+		}
+		self
+	}
+
+	pub fn is_zero(&self) -> bool {
+		match self {
+			ConstValue::Int(value) => *value == 0,
+			ConstValue::Float(value) => *value == 0.0,
+			_ => false
+		}
+	}
+
+	fn as_float(&self) -> Option<f64> {
+		match self {
+			ConstValue::Int(value) => Some(*value as f64),
+			ConstValue::Float(value) => Some(*value),
+			_ => None
+		}
+	}
+}
+
+/// Calls a meta function with the provided constant arguments.
+pub fn evaluate_meta_call(func: &MetaFunction, arguments: Vec<ConstValue>) -> Option<ConstValue> {
+	let body = func.body.as_ref()?;
+	if func.parameters.len() != arguments.len() {
+		return None;
+	}
+	let variables = func.parameters.iter().zip(arguments)
+		.map(|(param, value)| (param.0.clone(), value.convert_to(&param.1)))
+		.collect::<BTreeMap<String, ConstValue>>();
+	evaluate(body, &variables).map(|result| result.convert_to(&func.return_type))
+}
+
+/// Evaluates the expression using the provided variables.
+/// Returns `None` if the expression uses anything outside the supported numeric, boolean, and string operations.
+pub fn evaluate(expr: &Expression, variables: &BTreeMap<String, ConstValue>) -> Option<ConstValue> {
+	match expr {
+		Expression::Value(content, _, _) => {
+			match variables.get(content) {
+				Some(value) => Some(value.clone()),
+				None => ConstValue::from_literal(content)
+			}
+		},
+		Expression::Expressions(exprs, _, _) => {
+			if exprs.len() == 1 { evaluate(&exprs[0], variables) } else { None }
+		},
+		Expression::Prefix(inner, id, _, _) => {
+			let value = evaluate(inner, variables)?;
+			match (*id, value) {
+				(2, ConstValue::Int(v)) => Some(ConstValue::Int(v)),
+				(2, ConstValue::Float(v)) => Some(ConstValue::Float(v)),
+				(3, ConstValue::Int(v)) => v.checked_neg().map(ConstValue::Int),
+				(3, ConstValue::Float(v)) => Some(ConstValue::Float(-v)),
+				(4, ConstValue::Bool(v)) => Some(ConstValue::Bool(!v)),
+				(5, ConstValue::Int(v)) => Some(ConstValue::Int(!v)),
+				_ => None
+			}
+		},
+		Expression::Infix(left, right, id, _, _) => {
+			let left_value = evaluate(left, variables)?;
+			let right_value = evaluate(right, variables)?;
+			evaluate_infix(*id, left_value, right_value)
+		},
+		Expression::Ternary(condition, if_true, if_false, _, _) => {
+			match evaluate(condition, variables)? {
+				ConstValue::Bool(true) => evaluate(if_true, variables),
+				ConstValue::Bool(false) => evaluate(if_false, variables),
+				_ => None
+			}
+		},
+		_ => None
+	}
+}
+
+/// Folds an arithmetic operation on two numbers for `Expression::simplify`.
+/// Only `+`, `-`, `*`, and `/` are folded. The result must still be checked with `fits_number_type`.
+/// Integers are promoted to floats if either side is a float, like in C++.
+pub fn fold_arithmetic(id: usize, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+	if id != 10 && id != 11 && id != 13 && id != 14 {
+		return None;
+	}
+	evaluate_infix(id, left, right)
+}
+
+/// Checks whether a number fits the provided number type without narrowing.
+/// Integers must fit within the range of integer types, and floats never fit them.
+pub fn fits_number_type(value: &ConstValue, var_type: &VariableType) -> bool {
+	match (value, &var_type.var_type) {
+		(ConstValue::Int(value), Type::Number(number_type)) => {
+			match number_type.integer_range() {
+				Some((bits, true)) => bits >= 64 || (*value >= -(1i64 << (bits - 1)) && *value < (1i64 << (bits - 1))),
+				Some((bits, false)) => *value >= 0 && (bits >= 64 || *value < (1i64 << bits)),
+				None => true
+			}
+		},
+		(ConstValue::Float(_), Type::Number(number_type)) => number_type.integer_range().is_none(),
+		(ConstValue::Int(_), _) | (ConstValue::Float(_), _) => true,
+		_ => false
+	}
+}
+
+fn evaluate_infix(id: usize, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+	if let (ConstValue::Int(l), ConstValue::Int(r)) = (&left, &right) {
+		let (l, r) = (*l, *r);
+		return match id {
+			10 => l.checked_mul(r).map(ConstValue::Int),
+			11 => l.checked_div(r).map(ConstValue::Int),
+			12 => l.checked_rem(r).map(ConstValue::Int),
+			13 => l.checked_add(r).map(ConstValue::Int),
+			14 => l.checked_sub(r).map(ConstValue::Int),
+			15 => u32::try_from(r).ok().and_then(|r| l.checked_shl(r)).map(ConstValue::Int),
+			16 => u32::try_from(r).ok().and_then(|r| l.checked_shr(r)).map(ConstValue::Int),
+			18 => Some(ConstValue::Bool(l < r)),
+			19 => Some(ConstValue::Bool(l <= r)),
+			20 => Some(ConstValue::Bool(l > r)),
+			21 => Some(ConstValue::Bool(l >= r)),
+			22 => Some(ConstValue::Bool(l == r)),
+			23 => Some(ConstValue::Bool(l != r)),
+			24 => Some(ConstValue::Int(l & r)),
+			25 => Some(ConstValue::Int(l ^ r)),
+			26 => Some(ConstValue::Int(l | r)),
+			_ => None
+		};
+	}
+	if let (Some(l), Some(r)) = (left.as_float(), right.as_float()) {
+		return match id {
+			10 => Some(ConstValue::Float(l * r)),
+			11 => Some(ConstValue::Float(l / r)),
+			13 => Some(ConstValue::Float(l + r)),
+			14 => Some(ConstValue::Float(l - r)),
+			18 => Some(ConstValue::Bool(l < r)),
+			19 => Some(ConstValue::Bool(l <= r)),
+			20 => Some(ConstValue::Bool(l > r)),
+			21 => Some(ConstValue::Bool(l >= r)),
+			22 => Some(ConstValue::Bool(l == r)),
+			23 => Some(ConstValue::Bool(l != r)),
+			_ => None
+		};
+	}
+	match (id, left, right) {
+		(13, ConstValue::Str(l), ConstValue::Str(r)) => Some(ConstValue::Str(l + &r)),
+		(22, l, r) => Some(ConstValue::Bool(l == r)),
+		(23, l, r) => Some(ConstValue::Bool(l != r)),
+		(27, ConstValue::Bool(l), ConstValue::Bool(r)) => Some(ConstValue::Bool(l && r)),
+		(28, ConstValue::Bool(l), ConstValue::Bool(r)) => Some(ConstValue::Bool(l || r)),
+		_ => None
+	}
+}
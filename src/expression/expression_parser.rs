@@ -10,11 +10,13 @@ use crate::expression::expression_piece::ExpressionPiece;
 use crate::expression::variable_type::{ VariableType, VarStyle, Type };
 
 use crate::config_management::ConfigData;
-use crate::config_management::operator_data::Operator;
+use crate::config_management::operator_data::{ Operator, AS_OPERATOR_ID };
 
 use crate::context_management::position::Position;
+use crate::context_management::print_code_error;
 use crate::context_management::context::Context;
 use crate::context_management::context_manager::ContextManager;
+use crate::context_management::typing_context::ContextType;
 
 use crate::declaration_parser::parser::Parser;
 
@@ -26,6 +28,7 @@ use regex::Regex;
 
 lazy_static! {
 	pub static ref EXPR_FUNC_REGEX: Regex = Regex::new(r"^\b(?:fn|proc)\b").unwrap();
+	pub static ref INIT_CAPTURE_REGEX: Regex = Regex::new(r"^\s*(&?)\s*([A-Za-z_]\w*)\s*:=").unwrap();
 }
 
 /// Parses an expression represented as a String.
@@ -40,6 +43,7 @@ pub struct ExpressionParser<'a> {
 	pub parts: Vec<ExpressionPiece>,
 	pub end_data: ExpressionEnd,
 	pub expect_type: bool,
+	pub expect_type_or_value: bool,
 
 	pub config_data: &'a ConfigData,
 	pub context_manager: &'a mut ContextManager
@@ -48,7 +52,6 @@ pub struct ExpressionParser<'a> {
 /// Tracks the positional information of the parser.
 pub struct ExpressionParserPosition {
 	pub line_offset: usize,
-	pub line_start: usize,
 	pub index: usize,
 	pub start_position: Position
 }
@@ -57,7 +60,8 @@ pub struct ExpressionParserPosition {
 pub struct ExpressionEnd {
 	pub until_chars: Vec<char>,
 	pub end_index: usize,
-	pub reason: ExpressionEndReason
+	pub reason: ExpressionEndReason,
+	pub unterminated_group: bool
 }
 
 /// Stores important data to be retrieved after the parser ends.
@@ -104,7 +108,6 @@ impl<'a> ExpressionParser<'a> {
 			expr_str: parser.content.to_string(),
 			position: ExpressionParserPosition {
 				line_offset: 0,
-				line_start: 0,
 				index: start_position.start,
 				start_position: start_position
 			},
@@ -114,13 +117,20 @@ impl<'a> ExpressionParser<'a> {
 			end_data: ExpressionEnd {
 				until_chars: end_chars.unwrap_or(Vec::new()),
 				end_index: 0,
-				reason: ExpressionEndReason::Unknown
+				reason: ExpressionEndReason::Unknown,
+				unterminated_group: false
 			},
 			expect_type: false,
+			expect_type_or_value: false,
 			context_manager: context_manager
 		};
 		result.parse_expr_str(parser, context);
-		result.expression = ExpressionPiece::parse_expr_parts(&mut result, context, &parser.content, final_desired_type);
+		// The unterminated group has already been reported, so the incomplete expression is not checked.
+		result.expression = if result.end_data.unterminated_group {
+			Rc::new(Expression::Invalid)
+		} else {
+			ExpressionPiece::parse_expr_parts(&mut result, context, &parser.content, final_desired_type)
+		};
 		return result;
 	}
 
@@ -138,7 +148,7 @@ impl<'a> ExpressionParser<'a> {
 				break;
 			}
 			self.parse(&mut state, parser, context);
-			if state == ParseState::End {
+			if state == ParseState::End || self.end_data.unterminated_group {
 				break;
 			}
 		}
@@ -176,6 +186,8 @@ impl<'a> ExpressionParser<'a> {
 			ParseState::Value => {
 				if self.expect_type {
 					self.parse_out_type(state, parser);
+				} else if self.expect_type_or_value && self.parse_out_type_operand(parser, context) {
+					*state = ParseState::Suffix;
 				} else if !self.parse_value(parser, context) {
 					self.set_end_reason(ExpressionEndReason::NoValueError);
 					*state = ParseState::End;
@@ -228,35 +240,70 @@ impl<'a> ExpressionParser<'a> {
 		*state = ParseState::Suffix;
 	}
 
-	fn generate_pos(&self, start: usize, end: Option<usize>) -> Position {
-		let pos_start = {
-			if self.position.line_offset == 0 {
-				start + self.position.start_position.start
-			} else {
-				start - self.position.line_start
-			}
-		};
-		let pos_end = {
-			if end.is_none() {
-				None
-			} else if self.position.line_offset == 0 {
-				Some(end.unwrap() + self.position.start_position.start)
-			} else {
-				Some(end.unwrap() - self.position.line_start)
-			}
+	/// Parses the operand of `sizeof` or `alignof` as a type if it is a parenthesized
+	/// type that does not name a variable. Otherwise, the parser is left untouched
+	/// so the operand can be parsed as a value.
+	fn parse_out_type_operand(&mut self, parser: &mut Parser, context: &mut Option<&mut Context>) -> bool {
+		self.expect_type_or_value = false;
+		let start_index = parser.index;
+		let start_line = parser.line;
+		if parser.get_curr() != '(' {
+			return false;
+		}
+		parser.increment();
+		parser.parse_whitespace();
+
+		let mut unexpected_char = false;
+		let mut specifier_error: Option<&'static str> = None;
+		let tf_type = VariableType::from_type_style(parser.parse_type_and_style(&mut unexpected_char, &mut specifier_error));
+		parser.parse_whitespace();
+
+		let mut base_type = &tf_type.var_type;
+		while let Type::Array(element_type, _) = base_type {
+			base_type = &element_type.var_type;
+		}
+		let names_variable = match base_type {
+			Type::Undeclared(names) if names.len() == 1 => {
+				match context.as_ref() {
+					Some(ctx) => {
+						let item = ctx.typing.get_item(&names[0], None, None, false)
+							.or_else(|| ctx.module.get_item(&names[0], Some(ctx), Some(self.context_manager), false));
+						!matches!(item, Some(ContextType::Class(_)) | Some(ContextType::TypeParameter) | None)
+					},
+					None => false
+				}
+			},
+			Type::Inferred => true,
+			_ => false
 		};
+
+		if unexpected_char || specifier_error.is_some() || names_variable || parser.get_curr() != ')' {
+			parser.reset(start_index, start_line);
+			return false;
+		}
+		parser.increment();
+		self.add_type(tf_type, start_index, parser.index);
+		true
+	}
+
+	/// Creates the position of the content between the indexes.
+	/// The line is counted from one and the indexes become columns within it, as errors expect.
+	fn generate_pos(&self, start: usize, end: Option<usize>) -> Position {
+		let line_start = self.expr_str.as_bytes()[..start.min(self.expr_str.len())].iter().rposition(|c| *c == b'\n').map(|i| i + 1).unwrap_or(0);
 		return Position::new(
 			self.position.start_position.file.clone(),
 			Some(self.position.start_position.line.unwrap_or(1) + self.position.line_offset),
-			pos_start,
-			pos_end
+			start - line_start,
+			end.map(|e| e - line_start)
 		);
 	}
 
 	fn add_prefix_op(&mut self, op: usize, start: usize, end: usize) {
 		//println!("Added prefix: {}", op);
-		if op == 8 || op == 9 {
+		if op == 9 {
 			self.expect_type = true;
+		} else if op == 8 || op == 15 {
+			self.expect_type_or_value = true;
 		}
 		self.parts.push(ExpressionPiece::Prefix(op, self.generate_pos(start, Some(end))));
 	}
@@ -273,7 +320,7 @@ impl<'a> ExpressionParser<'a> {
 
 	fn add_infix_op(&mut self, op: usize, start: usize, end: usize) {
 		//println!("Added infix: {}", op);
-		if op >= 6 && op <= 9 {
+		if (6..=9).contains(&op) || op == AS_OPERATOR_ID {
 			self.expect_type = true;
 		}
 		self.parts.push(ExpressionPiece::Infix(op, self.generate_pos(start, Some(end))));
@@ -284,8 +331,8 @@ impl<'a> ExpressionParser<'a> {
 		self.parts.push(ExpressionPiece::Ternary(op, expr, self.generate_pos(start, Some(end))));
 	}
 
-	fn add_encapsulated_values(&mut self, expressions: Vec<Rc<Expression>>, start: usize, end: usize) {
-		self.parts.push(ExpressionPiece::EncapsulatedValues(Rc::new(expressions), self.generate_pos(start, Some(end))));
+	fn add_encapsulated_values(&mut self, expressions: Vec<Rc<Expression>>, is_tuple: bool, start: usize, end: usize) {
+		self.parts.push(ExpressionPiece::EncapsulatedValues(Rc::new(expressions), is_tuple, self.generate_pos(start, Some(end))));
 	}
 
 	fn add_initilizer_list(&mut self, expressions: Vec<Rc<Expression>>, start: usize, end: usize) {
@@ -353,14 +400,18 @@ impl<'a> ExpressionParser<'a> {
 		} else {
 			let mut finish = false;
 			let mut number_offset = 0;
+			let mut string_lines = 0;
 			if parser.check_for_number(&mut number_offset) {
 				offset += number_offset - 1;
 				finish = true;
 			} else if parser.check_for_string() {
 				let old_index = parser.index;
+				let old_line = parser.line;
 				parser.parse_string();
 				offset = parser.index - old_index + 1;
+				string_lines = parser.line - old_line;
 				parser.index = old_index;
+				parser.line = old_line;
 				finish = true;
 			} else {
 				loop {
@@ -382,6 +433,7 @@ impl<'a> ExpressionParser<'a> {
 				let substr = self.gen_substr(value_start, value_start + offset).to_string();
 				if !substr.is_empty() {
 					self.add_value(substr, value_start, value_start + offset);
+					self.position.line_offset += string_lines;
 					parser.index += offset;
 					return true;
 				}
@@ -426,6 +478,12 @@ impl<'a> ExpressionParser<'a> {
 		}
 		if possible_operators.len() > 0 {
 			let op = possible_operators.remove(0);
+			if self.get_operator(op_type, op).name.as_ref().unwrap().ends_with(|c: char| c.is_ascii_alphabetic()) {
+				let next_char = self.expr_str[operator_start + offset..].chars().next();
+				if next_char.map(|c| c.is_ascii_alphanumeric() || c == '_').unwrap_or(false) {
+					return false;
+				}
+			}
 			match op_type {
 				"prefix" => self.add_prefix_op(op, operator_start, operator_start + offset),
 				"suffix" => self.add_suffix_op(op, operator_start, operator_start + offset),
@@ -502,8 +560,8 @@ impl<'a> ExpressionParser<'a> {
 		if real_second_char == end_char {
 			let empty = Vec::new();
 			match end_char {
-				')' => self.add_function_params(empty, parser.index, parser.index + space_offset),
-				']' => self.add_array_access_params(empty, parser.index, parser.index + space_offset),
+				')' => self.add_function_params(empty, parser.index, parser.index + space_offset + 1),
+				']' => self.add_array_access_params(empty, parser.index, parser.index + space_offset + 1),
 				_ => {}
 			}
 			parser.index += space_offset + 1;
@@ -517,29 +575,74 @@ impl<'a> ExpressionParser<'a> {
 		let mut expressions = Vec::new();
 		let start_pos = parser.index;
 		let mut final_line_offset = 0;
+		let mut is_tuple = false;
+		let mut is_terminated = false;
+		let mut group_line_offset = 0;
+		let mut recovery_point: Option<(usize, usize)> = None;
 		loop {
 			let chars = vec!(end_char, ',');
 			if self.index_within_bounds(parser) {
+				// A trailing comma makes a single value a tuple rather than a grouping.
+				if is_value && end_char == ')' && !expressions.is_empty() && self.is_trailing_comma(end_char, parser) {
+					let space_offset = self.parse_next_whitespace(parser);
+					parser.index += space_offset + 1;
+					is_tuple = true;
+					is_terminated = true;
+					break;
+				}
 				let expr_parser = ExpressionParser::new(parser, self.generate_pos(parser.index, None), self.config_data, context, self.context_manager, Some(chars), None);
+				group_line_offset += expr_parser.position.line_offset;
+				if expr_parser.end_data.unterminated_group {
+					// The inner group already reported the error and moved the parser to where it recovered.
+					return self.end_unterminated_group(group_line_offset);
+				}
 				expressions.push(expr_parser.expression);
-				parser.index += 1;
-				final_line_offset = parser.line + expr_parser.position.line_offset;
-				if let ExpressionEndReason::ReachedChar(c) = expr_parser.end_data.reason {
-					if end_char == c {
+				match expr_parser.end_data.reason {
+					ExpressionEndReason::ReachedChar(c) => {
+						if end_char == c {
+							is_terminated = true;
+						}
+					},
+					// A statement cannot end within a group, so the group was never closed.
+					_ if parser.get_curr() == ';' => {
+						recovery_point = Some((parser.index, group_line_offset));
 						break;
+					},
+					// The group should have ended here, so parsing can resume from this point if it is never closed.
+					_ => if recovery_point.is_none() {
+						recovery_point = Some((parser.index, group_line_offset));
 					}
 				}
+				parser.index += 1;
+				final_line_offset = parser.line + expr_parser.position.line_offset;
+				if is_terminated {
+					break;
+				}
 			} else {
 				break;
 			}
 		}
+		if !is_terminated {
+			let open_char = match end_char {
+				')' => '(',
+				']' => '[',
+				_ => '{'
+			};
+			let pos = Position::new(self.position.start_position.file.clone(), None, start_pos - 1, Some(start_pos));
+			print_code_error("Unterminated Group", &format!("this '{}' is never closed by a '{}'", open_char, end_char), &pos, &parser.content);
+			if let Some((index, line_offset)) = recovery_point {
+				parser.index = index;
+				group_line_offset = line_offset;
+			}
+			return self.end_unterminated_group(group_line_offset);
+		}
 		match end_char {
 			')' => {
 				if is_value {
 					if !expressions.is_empty() {
 						parser.line += final_line_offset - expressions.last().as_ref().unwrap().get_line_number().unwrap_or(final_line_offset);
 					}
-					self.add_encapsulated_values(expressions, start_pos, parser.index);
+					self.add_encapsulated_values(expressions, is_tuple, start_pos, parser.index);
 				} else {
 					self.add_function_params(expressions, start_pos, parser.index);
 				}
@@ -551,6 +654,18 @@ impl<'a> ExpressionParser<'a> {
 		return true;
 	}
 
+	/// Stops the expression after a group that is never closed, so it results in `Expression::Invalid`.
+	fn end_unterminated_group(&mut self, line_offset: usize) -> bool {
+		self.position.line_offset += line_offset;
+		self.end_data.reason = ExpressionEndReason::EndOfExpression;
+		self.end_data.unterminated_group = true;
+		false
+	}
+
+	fn is_trailing_comma(&self, end_char: char, parser: &Parser) -> bool {
+		parser.chars[parser.index..].iter().find(|c| !c.is_whitespace()) == Some(&end_char)
+	}
+
 	fn check_operator(&self, op_str: &str, op_type: &str, exact: bool) -> Vec<usize> {
 		let mut result = Vec::new();
 		let operators = &self.config_data.operators[op_type];
@@ -566,8 +681,41 @@ impl<'a> ExpressionParser<'a> {
 		return result;
 	}
 
+	/// Checks a lambda capture against the captures that come before it.
+	/// A capture default (`&` or `=`) must come first, and named captures cannot repeat what the default already captures.
+	fn check_lambda_capture(captures: &[String], capture: &str) -> Result<(), (&'static str, &'static str)> {
+		let default = captures.first().filter(|c| *c == "&" || *c == "=");
+		if capture == "&" || capture == "=" {
+			if default.is_some() {
+				return Err(("Duplicate Capture Default", "lambdas can only have one capture default"));
+			}
+			if !captures.is_empty() {
+				return Err(("Invalid Capture Default", "the capture default must be the first capture"));
+			}
+			return Ok(());
+		}
+		let name = capture.split('=').next().unwrap_or("").trim();
+		let by_reference = name.starts_with('&');
+		let name = name.trim_start_matches('&').trim();
+		let is_init_capture = capture.contains('=');
+		if name != "this" && name != "*this" && !is_init_capture {
+			if default.map(|d| d == "&").unwrap_or(false) && by_reference {
+				return Err(("Redundant Capture", "variable is already captured by reference through the & default"));
+			}
+			if default.map(|d| d == "=").unwrap_or(false) && !by_reference {
+				return Err(("Redundant Capture", "variable is already captured by copy through the = default"));
+			}
+		}
+		let is_duplicate = captures.iter().any(|c| c.split('=').next().unwrap_or("").trim().trim_start_matches('&').trim() == name);
+		if is_duplicate {
+			return Err(("Duplicate Capture", "variable is captured more than once"));
+		}
+		Ok(())
+	}
+
 	fn parse_function(&mut self, parser: &mut Parser, context: &mut Option<&mut Context>) -> bool {
 		let mut scope_vars = Vec::new();
+		let mut init_captures = Vec::new();
 		let parser_start = parser.index;
 		if parser.check_ahead("fn") {
 			for _ in 0..2 { parser.increment(); }
@@ -583,18 +731,54 @@ impl<'a> ExpressionParser<'a> {
 				loop {
 					let mut result = ' ';
 					let start = parser.index;
-					parser.parse_until_at_expr(',', ')', &mut result);
+					let mut init_capture = None;
+					if let Some(caps) = INIT_CAPTURE_REGEX.captures(&parser.content[start..]) {
+						// Init-captures are written "name := expression" and declare the name within the lambda.
+						let prefix = format!("{}{} = ", &caps[1], &caps[2]);
+						let capture_name = caps[2].to_string();
+						parser.index = start + caps[0].len();
+						let expr_parser = ExpressionParser::new(parser, self.generate_pos(parser.index, None), self.config_data, context, self.context_manager, Some(vec!(',', ')')), None);
+						result = match expr_parser.end_data.reason {
+							ExpressionEndReason::ReachedChar(c) => c,
+							_ => return false
+						};
+						let expression = expr_parser.expression;
+						if expression.is_invalid() { return false; }
+						let value = expression.to_string(&self.config_data.operators, context.as_mut().unwrap());
+						init_captures.push((capture_name, expression.get_type()));
+						init_capture = Some(prefix + &value);
+					} else {
+						parser.parse_until_at_expr(',', ')', &mut result);
+					}
 					if parser.out_of_space { return false; }
 					let end = parser.index;
 					if result != ')' && result != ',' { return false; }
-					scope_vars.push(parser.content[start..end].to_string());
+					let raw_capture = &parser.content[start..end];
+					let start = start + (raw_capture.len() - raw_capture.trim_start().len());
+					let capture = init_capture.unwrap_or(raw_capture.trim().to_string());
+					if !capture.is_empty() {
+						match Self::check_lambda_capture(&scope_vars, &capture) {
+							Ok(()) => scope_vars.push(capture),
+							Err((title, msg)) => print_code_error(title, msg, &Position::new(self.position.start_position.file.clone(), None, start, Some(end)), &parser.content)
+						}
+					}
+					parser.increment();
 					if result == ')' {
-						parser.increment();
 						break;
 					}
 				}
 			} else {
-				scope_vars.push(parser.parse_ascii_char_name());
+				let start = parser.index;
+				let capture = if parser.get_curr() == '&' || parser.get_curr() == '=' {
+					parser.increment();
+					parser.content[start..parser.index].to_string()
+				} else {
+					parser.parse_ascii_char_name()
+				};
+				match Self::check_lambda_capture(&scope_vars, &capture) {
+					Ok(()) => scope_vars.push(capture),
+					Err((title, msg)) => print_code_error(title, msg, &Position::new(self.position.start_position.file.clone(), None, start, Some(parser.index)), &parser.content)
+				}
 			}
 		}
 
@@ -726,9 +910,16 @@ impl<'a> ExpressionParser<'a> {
 
 		parser.parse_whitespace();
 
+		context.as_mut().unwrap().typing.push_context();
+		for init_capture in &init_captures {
+			context.as_mut().unwrap().typing.add_variable(init_capture.0.clone(), init_capture.1.clone(), None);
+		}
+
 		let scope: ScopeExpression;
 		if parser.get_curr() == '{' {
+			let outer_return_type = context.as_mut().unwrap().function_return_type.replace(return_type.clone());
 			scope = ScopeExpression::new(parser, None, parser.index + 1, parser.line, "", self.config_data, context.as_mut().unwrap(), self.context_manager, None);
+			context.as_mut().unwrap().function_return_type = outer_return_type;
 			if parser.get_curr() == '}' {
 				parser.increment();
 			}
@@ -737,9 +928,12 @@ impl<'a> ExpressionParser<'a> {
 			let expression = Some(parser.parse_expression("".to_string(), self.config_data, Some(context.as_mut().unwrap()), self.context_manager, &mut reason, Some(VariableType::boolean())));
 			if expression.is_none() { return false; }
 			match reason {
-				ExpressionEndReason::Unknown => return false,
-				ExpressionEndReason::EndOfContent =>  return false,
-				ExpressionEndReason::NoValueError => return false,
+				ExpressionEndReason::Unknown |
+				ExpressionEndReason::EndOfContent |
+				ExpressionEndReason::NoValueError => {
+					context.as_mut().unwrap().typing.pop_context();
+					return false;
+				},
 				_ => ()
 			}
 
@@ -748,6 +942,8 @@ impl<'a> ExpressionParser<'a> {
 			//scope = ScopeExpression::new(parser, Some(1), parser.index, parser.line, "", self.config_data, context.as_mut().unwrap(), None);
 		}
 
+		context.as_mut().unwrap().typing.pop_context();
+
 		let func_piece = ExpressionPiece::Function(Rc::new(scope),
 			scope_vars,
 			parameters,
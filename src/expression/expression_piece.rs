@@ -9,10 +9,15 @@ use crate::declaration_parser::parser::Parser;
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionParser;
-use crate::expression::value_type::{ NumberType, StringType };
-use crate::expression::variable_type::{ VariableType, Type };
+use crate::expression::value_type::{ Function, NumberType, StringType };
+use crate::expression::variable_type::{ VariableType, Type, VarStyle };
+use crate::expression::function_type::FunStyle;
+use crate::expression::const_eval::{ evaluate, evaluate_meta_call };
 
-use crate::context_management::print_code_error;
+use crate::config_management::LibraryNames;
+use crate::config_management::operator_data::AS_OPERATOR_ID;
+
+use crate::context_management::{ print_code_error, print_code_warning };
 use crate::context_management::position::Position;
 use crate::context_management::context::Context;
 use crate::context_management::typing_context::ContextType;
@@ -28,7 +33,7 @@ pub enum ExpressionPiece {
 	Suffix(usize, Position),
 	Infix(usize, Position),
 	Ternary(usize, Rc<Expression>, Position),
-	EncapsulatedValues(Rc<Vec<Rc<Expression>>>, Position),
+	EncapsulatedValues(Rc<Vec<Rc<Expression>>>, bool, Position),
 	InitializerList(Rc<Vec<Rc<Expression>>>, Position),
 	FunctionParameters(Rc<Vec<Rc<Expression>>>, Position),
 	ArrayAccessParameters(Rc<Vec<Rc<Expression>>>, Position),
@@ -54,10 +59,45 @@ impl ExpressionPiece {
 		}
 	}
 
+	/// Describes the construct for error messages.
+	pub fn get_name(&self) -> &'static str {
+		match self {
+			ExpressionPiece::Expression(..) => "expression",
+			ExpressionPiece::Prefix(..) => "prefix operator",
+			ExpressionPiece::Value(..) => "value",
+			ExpressionPiece::Suffix(..) => "suffix operator",
+			ExpressionPiece::Infix(..) => "infix operator",
+			ExpressionPiece::Ternary(..) => "ternary operator",
+			ExpressionPiece::EncapsulatedValues(..) => "parenthesized expression",
+			ExpressionPiece::InitializerList(..) => "initializer list",
+			ExpressionPiece::FunctionParameters(..) => "function call",
+			ExpressionPiece::ArrayAccessParameters(..) => "array access",
+			ExpressionPiece::Type(..) => "type",
+			ExpressionPiece::Function(..) => "lambda"
+		}
+	}
+
+	pub fn get_position(&self) -> Option<&Position> {
+		match self {
+			ExpressionPiece::Expression(..) => None,
+			ExpressionPiece::Prefix(_, pos) |
+			ExpressionPiece::Value(_, pos) |
+			ExpressionPiece::Suffix(_, pos) |
+			ExpressionPiece::Infix(_, pos) |
+			ExpressionPiece::Ternary(_, _, pos) |
+			ExpressionPiece::EncapsulatedValues(_, _, pos) |
+			ExpressionPiece::InitializerList(_, pos) |
+			ExpressionPiece::FunctionParameters(_, pos) |
+			ExpressionPiece::ArrayAccessParameters(_, pos) |
+			ExpressionPiece::Type(_, pos) |
+			ExpressionPiece::Function(_, _, _, _, _, pos) => Some(pos)
+		}
+	}
+
 	pub fn get_encapsulated_type(&self) -> Option<VariableType> {
 		return match self {
-			ExpressionPiece::EncapsulatedValues(exprs, _) => {
-				if exprs.len() > 1 {
+			ExpressionPiece::EncapsulatedValues(exprs, is_tuple, _) => {
+				if exprs.len() > 1 || (*is_tuple && exprs.len() == 1) {
 					let mut result = Vec::new();
 					for e in exprs.iter() {
 						result.push(((**e)).get_type());
@@ -99,9 +139,12 @@ impl ExpressionPiece {
 	}
 
 	pub fn parse_expr_parts(parser: &mut ExpressionParser, context: &mut Option<&mut Context>, file_content: &str, _final_desired_type: Option<VariableType>) -> Rc<Expression> {
+		if parser.end_data.unterminated_group {
+			return Rc::new(Expression::Invalid);
+		}
 		let mut error = false;
 		if parser.parts.len() == 1 {
-			match Self::get_expression_from_piece(&parser.parts[0], context) {
+			match Self::get_expression_from_piece(&parser.parts[0], &parser.config_data.library, context) {
 				Some(expr) => parser.parts[0] = ExpressionPiece::Expression(expr),
 				None => return Rc::new(Expression::Invalid) // TODO: error
 			}
@@ -110,7 +153,22 @@ impl ExpressionPiece {
 			if let ExpressionPiece::Prefix(index, pos) = &parser.parts[0] {
 				if *index == 9 {
 					if let ExpressionPiece::Type(tf_type, _) = &parser.parts[1] {
-						if let ExpressionPiece::FunctionParameters(exprs, _) = &parser.parts[2] {
+						if let ExpressionPiece::FunctionParameters(exprs, params_pos) = &parser.parts[2] {
+							let construct_pos = pos.to(params_pos);
+							let mut construct_type = tf_type.clone();
+							if context.is_some() {
+								construct_type.resolve(context.as_ref().unwrap(), parser.context_manager);
+							}
+							if let Some(cls) = construct_type.var_type.get_class_type() {
+								match cls.find_constructor(exprs, context.as_deref(), parser.context_manager) {
+									Err(mismatch) if mismatch.is_error() => {
+										print_code_error("Invalid Construction", mismatch.get_message(), &construct_pos, file_content);
+										return Rc::new(Expression::Invalid);
+									},
+									Err(mismatch) => print_code_warning("Unverified Construction", mismatch.get_message(), &construct_pos, file_content),
+									Ok(_) => ()
+								}
+							}
 							return Rc::new(Expression::ConstructCall(tf_type.var_type.clone(), Rc::clone(exprs), tf_type.clone(), pos.clone()));
 						}
 					}
@@ -201,11 +259,12 @@ impl ExpressionPiece {
 							break;
 						}
 					},
-					_ => {
-						for p in &parser.parts {
-							p.print_type();
+					piece => {
+						let message = format!("{} cannot be used here", piece.get_name());
+						match piece.get_position() {
+							Some(pos) => print_code_error("Unsupported Expression", &message, pos, file_content),
+							None => println!("Unsupported Expression: {}", message)
 						}
-						println!("No support for this expression atm!");
 						error = true;
 						break;
 					}
@@ -236,26 +295,38 @@ impl ExpressionPiece {
 
 	fn expect_type(operator_id: usize, is_prefix: bool) -> bool {
 		if is_prefix {
-			return operator_id == 8 || operator_id == 9;
+			return operator_id == 8 || operator_id == 9 || operator_id == 15;
 		}
 		return operator_id == 6;
 	}
 
 	fn parse_prefix(parser: &ExpressionParser, part_index: &usize, operator_id: usize, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>) {
+		if *part_index >= parser.parts.len() {
+			return (None, Some(position));
+		}
 		let mut final_type = VariableType::inferred();
 		if operator_id <= 3 {
-			let result = Self::get_expression_from_piece(&parser.parts[*part_index], context);
+			let result = Self::get_expression_from_piece(&parser.parts[*part_index], &parser.config_data.library, context);
 			if result.is_some() && result.as_ref().unwrap().get_type().is_number() {
 				final_type = (*result.unwrap()).get_type().clone();
 			}
 		}
 		if Self::expect_type(operator_id, true) {
 			let tf_type = Self::get_type_from_piece(&parser.parts[*part_index]);
-			let result = Self::get_expression_from_piece(&parser.parts[*part_index], context);
+			let mut result = Self::get_expression_from_piece(&parser.parts[*part_index], &parser.config_data.library, context);
+			if result.is_some() && operator_id == 15 && tf_type.is_none() {
+				// alignof only accepts types, so the type of a value operand is used.
+				let operand = match &*result.unwrap() {
+					expr @ Expression::Expressions(exprs, _, _) if expr.is_grouping() => Rc::clone(&exprs[0]),
+					expr => Rc::new(expr.clone())
+				};
+				let decltype = Rc::new(Expression::Value("decltype".to_string(), VariableType::inferred(), position.clone()));
+				result = Some(Rc::new(Expression::FunctionCall(decltype, Rc::new(vec![operand]), VariableType::inferred(), position.clone())));
+			}
 			if result.is_some() {
 				return (Some(ExpressionPiece::Expression(Rc::new(Expression::Prefix(result.unwrap(), operator_id,
-				if operator_id == 8 {
-					VariableType::copy(Type::Number(NumberType::UInt))
+				if operator_id == 8 || operator_id == 15 {
+					VariableType::copy(Type::Number(NumberType::Size))
 				} else if tf_type.is_some() {
 					(*tf_type.unwrap()).clone()
 				} else {
@@ -263,7 +334,7 @@ impl ExpressionPiece {
 				}, position)))), None);
 			}
 		} else {
-			let result = Self::get_expression_from_piece(&parser.parts[*part_index], context);
+			let result = Self::get_expression_from_piece(&parser.parts[*part_index], &parser.config_data.library, context);
 			if result.is_some() {
 				return (Some(ExpressionPiece::Expression(Rc::new(Expression::Prefix(result.unwrap(), operator_id, final_type, position)))), None);
 			}
@@ -272,7 +343,7 @@ impl ExpressionPiece {
 	}
 
 	fn parse_suffix(parser: &ExpressionParser, part_index: &usize, operator_id: usize, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>) {
-		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], context);
+		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], &parser.config_data.library, context);
 		if result.is_some() {
 			return (Some(ExpressionPiece::Expression(Rc::new(Expression::Suffix(result.unwrap(), operator_id, VariableType::inferred(), position)))), None);
 		}
@@ -280,8 +351,11 @@ impl ExpressionPiece {
 	}
 
 	fn parse_infix(parser: &mut ExpressionParser, part_index: &usize, operator_id: usize, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>) {
-		let left_result = Self::get_expression_from_piece(&parser.parts[part_index - 1], context);
-		let right_result = Self::get_expression_from_piece(&parser.parts[*part_index], context);
+		if *part_index == 0 || *part_index >= parser.parts.len() {
+			return (None, Some(position));
+		}
+		let left_result = Self::get_expression_from_piece(&parser.parts[part_index - 1], &parser.config_data.library, context);
+		let right_result = Self::get_expression_from_piece(&parser.parts[*part_index], &parser.config_data.library, context);
 		let mut final_type = VariableType::inferred();
 
 		// all access :: . -> .* ->*
@@ -296,9 +370,23 @@ impl ExpressionPiece {
 			}
 		}
 
-		// cast operators # ## #* #~
+		// namespace access ::
+		if let (0, Some(left_expr), Some(right_expr), Some(ctx)) = (operator_id, &left_result, &right_result, context.as_ref()) {
+			let namespace = match &**left_expr {
+				Expression::Value(name, _, _) => Some(name),
+				Expression::Infix(_, inner, 0, _, _) => if let Expression::Value(name, _, _) = &**inner { Some(name) } else { None },
+				_ => None
+			};
+			if let (Some(namespace), Expression::Value(name, _, _)) = (namespace, &**right_expr) {
+				if let Some(item) = ctx.find_namespace_item(namespace, name, parser.context_manager) {
+					final_type = Self::get_type_from_context_type(item);
+				}
+			}
+		}
+
+		// cast operators # ## #* #~ as
 		if left_result.is_some() && right_result.is_some() {
-			if operator_id >= 6 && operator_id <= 9 {
+			if (6..=9).contains(&operator_id) || operator_id == AS_OPERATOR_ID {
 				let left_type = left_result.as_ref().unwrap().get_type();
 				let right_type = right_result.as_ref().unwrap().get_type();
 				if right_type.is_inferred_style() {
@@ -337,6 +425,15 @@ impl ExpressionPiece {
 				if context.is_some() {
 					left_type.resolve(context.as_ref().unwrap(), parser.context_manager);
 				}
+				if operator_id == 2 {
+					if let (Type::Tuple(tuple_types), Some(right)) = (&left_type.var_type, right_result.as_ref()) {
+						if let Expression::Value(s, _, _) = &**right {
+							if let Some(element_type) = s.parse::<usize>().ok().and_then(|index| tuple_types.get(index)) {
+								final_type = element_type.clone();
+							}
+						}
+					}
+				}
 				let left_type_cls = left_type.var_type.get_class_type();
 				if left_type_cls.is_some() {
 					let cls = left_type_cls.unwrap();
@@ -346,6 +443,19 @@ impl ExpressionPiece {
 				}
 			}
 
+			// overloaded operators of classes
+			if let (true, Some(left), Some(right)) = ((10..=41).contains(&operator_id), left_result.as_ref(), right_result.as_ref()) {
+				let mut left_type = left.get_type();
+				if let Some(ctx) = context.as_ref() {
+					left_type.resolve(ctx, parser.context_manager);
+				}
+				if let Some(cls) = left_type.var_type.get_class_type() {
+					if let Some(op_type) = cls.get_infix_operator_type(operator_id, &right.get_type()) {
+						final_type = op_type;
+					}
+				}
+			}
+
 			if (operator_id >= 10 && operator_id <= 14) || (operator_id >= 24 && operator_id <= 26) {
 				let left_type = left_result.as_ref().unwrap().get_type();
 				if left_type.is_number() && final_type.is_inferred() {
@@ -354,22 +464,57 @@ impl ExpressionPiece {
 			}
 		}
 
+		// null-safe access ?.
+		if let (Some(left_expr), Some(right_expr), 43) = (&left_result, &right_result, operator_id) {
+			let mut left_type = left_expr.get_type().optional_inner();
+			if let Some(ctx) = context.as_ref() {
+				left_type.resolve(ctx, parser.context_manager);
+			}
+			if let Expression::Value(s, _, pos) = &**right_expr {
+				// The member keeps its own type so the emitted chain knows how to check each link.
+				let member_type = left_type.var_type.get_class_type().map(|cls| cls.get_field(s)).unwrap_or(VariableType::inferred());
+				final_type = member_type.to_null_safe();
+				let member = Rc::new(Expression::Value(s.clone(), member_type, pos.clone()));
+				return (Some(ExpressionPiece::Expression(Rc::new(Expression::Infix(Rc::clone(left_expr), member, operator_id, final_type, position)))), None);
+			}
+		}
+
+		// elvis ?:
+		if let (Some(left_expr), Some(right_expr), 44) = (&left_result, &right_result, operator_id) {
+			let mut left_type = left_expr.get_type();
+			if let Some(ctx) = context.as_ref() {
+				left_type.resolve(ctx, parser.context_manager);
+			}
+			let right_type = right_expr.get_type();
+			// The result is the value within the optional, or the pointer itself unless the default is a value.
+			let value_type = if left_type.is_optional_value() {
+				left_type.optional_inner()
+			} else if Expression::elvis_dereferences(&left_type, &right_type) {
+				let mut pointee = left_type.clone();
+				pointee.var_style = VarStyle::Copy;
+				pointee
+			} else {
+				left_type
+			};
+			final_type = if value_type.is_inferred() { right_type } else { value_type };
+		}
+
 		if left_result.is_some() && right_result.is_some() {
 			return (Some(ExpressionPiece::Expression(Rc::new(Expression::Infix(left_result.unwrap(), right_result.unwrap(), operator_id, final_type, position)))), None);
 		}
 		return (None, Some(position));
 	}
 
-	fn parse_ternary(parser: &ExpressionParser, part_index: &usize, expr: Rc<Expression>, operator_id: usize, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>,Option<usize>) {
+	fn parse_ternary(parser: &mut ExpressionParser, part_index: &usize, expr: Rc<Expression>, operator_id: usize, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>,Option<usize>) {
 		if parser.parts.len() <= *part_index {
 			return (None, Some(position), Some(3));
 		}
-		let left_result = Self::get_expression_from_piece(&parser.parts[part_index - 1], context);
-		let right_result = Self::get_expression_from_piece(&parser.parts[*part_index], context);
+		let left_result = Self::get_expression_from_piece(&parser.parts[part_index - 1], &parser.config_data.library, context);
+		let right_result = Self::get_expression_from_piece(&parser.parts[*part_index], &parser.config_data.library, context);
 		if left_result.is_some() && right_result.is_some() {
 			let left_type = expr.get_type();
 			let right_type = right_result.as_ref().unwrap().get_type();
-			let mut result_type = left_type.compare_types(&right_type);
+			let mut result_type = left_type.compare_types(&right_type, context.as_deref(), parser.context_manager);
 			if result_type.is_none() {
 				if left_type.is_inferred() {
 					result_type = Some(right_type.clone());
@@ -387,10 +532,8 @@ impl ExpressionPiece {
 	}
 
 	fn parse_function_call(parser: &ExpressionParser, part_index: &usize, exprs: Rc<Vec<Rc<Expression>>>, context: &Option<&mut Context>, position: Position, file_content: &str) -> (Option<ExpressionPiece>,Option<Position>) {
-		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], context);
-		if result.is_some() {
-			let left_expr = result.unwrap();
-
+		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], &parser.config_data.library, context);
+		if let Some(mut left_expr) = result {
 			if let Expression::Infix(lexpr, rexpr, infix_id, _, _) = &*left_expr {
 				if *infix_id >= 2 && *infix_id <= 5 {
 					if let Expression::Value(name, _, pos) = &**rexpr {
@@ -399,6 +542,7 @@ impl ExpressionPiece {
 						let static_extend = ctx.find_static_extension(&name, &internal_left_type, Some(parser.context_manager), false);
 						if static_extend.is_some() {
 							let static_extend_unwrap = static_extend.unwrap();
+							Self::check_self_style(&static_extend_unwrap.func, *infix_id, lexpr, &pos.to(&position), file_content);
 
 							let final_type = static_extend_unwrap.func.return_type.clone();
 
@@ -424,30 +568,112 @@ impl ExpressionPiece {
 				is_new_call = id == 9;
 			}
 			let mut left_type = left_expr.get_type();
+			let mut module_callee = false;
+			if left_type.is_inferred() && context.is_some() {
+				if let Expression::Value(name, _, name_pos) = &*left_expr {
+					let ctx = context.as_ref().unwrap();
+					match ctx.module.get_item(name, Some(ctx), Some(parser.context_manager), false) {
+						Some(ContextType::Function(function)) => { left_type = VariableType::function(function); module_callee = true; },
+						Some(ContextType::QuantumFunction(functions)) => { left_type = VariableType::quantum_function(functions); module_callee = true; },
+						None if ctx.typing.get_item(name, None, None, false).is_none() => {
+							if let Some(module) = parser.context_manager.find_declaring_module(name) {
+								let namespaces = parser.context_manager.get_context_immut(&module).get_namespace_names();
+								let msg = if ctx.get_visible_modules(parser.context_manager).contains(&module) {
+									format!("\"{}\" is declared in namespace \"{}\", so it must be qualified here", name, namespaces.join("::"))
+								} else {
+									format!("\"{}\" is declared in module \"{}\", which is not imported here", name, module)
+								};
+								print_code_error("Unresolved Symbol", &msg, name_pos, file_content);
+							}
+						},
+						_ => ()
+					}
+				}
+			}
 			if left_type.is_quantum_function() {
 				let left_type_resolved = left_type.resolve_quantum_function(Rc::clone(&exprs));
 				if left_type_resolved.is_ok() {
 					left_type = left_type_resolved.unwrap();
 				} else {
-					print_code_error("Function Error", left_type_resolved.err().unwrap(), &position, file_content);
+					let call_pos = left_expr.get_position().map(|pos| pos.to(&position)).unwrap_or(position.clone());
+					print_code_error("Function Error", left_type_resolved.err().unwrap(), &call_pos, file_content);
 					left_type = VariableType::inferred();
 				}
 			}
+			if let Expression::Infix(lexpr, rexpr, infix_id, _, _) = &*left_expr {
+				if let (Type::Function(func), Expression::Value(_, _, pos)) = (&left_type.var_type, &**rexpr) {
+					if *infix_id >= 2 && *infix_id <= 5 {
+						Self::check_self_style(func, *infix_id, lexpr, &pos.to(&position), file_content);
+					}
+				}
+			}
+			if module_callee {
+				if let Expression::Value(name, _, pos) = &*left_expr {
+					left_expr = Rc::new(Expression::Value(name.clone(), left_type.clone(), pos.clone()));
+				}
+			}
 			let final_type = if is_new_call { left_type } else { left_type.get_function_call_return().unwrap_or(VariableType::inferred()) };
+			if module_callee {
+				if let Some(folded) = Self::fold_meta_call(parser, &left_expr, &exprs, context, &final_type, &position, file_content) {
+					return (Some(ExpressionPiece::Expression(Rc::new(folded))), None);
+				}
+			}
 			return (Some(ExpressionPiece::Expression(Rc::new(Expression::FunctionCall(left_expr, Rc::clone(&exprs), final_type, position)))), None);
 		}
 		return (None, Some(position));
 	}
 
+	/// Ensures the receiver of a method call matches the method's `where self` constraint.
+	fn check_self_style(func: &Function, infix_id: usize, receiver: &Expression, position: &Position, file_content: &str) {
+		let error = match &func.self_style {
+			Some(VarStyle::Ref) if receiver.is_temporary() => Some("requires an lvalue receiver, but was called on a temporary"),
+			Some(VarStyle::Move) if !receiver.is_temporary() => Some("requires a temporary receiver"),
+			Some(VarStyle::Ptr(_)) if infix_id != 3 && infix_id != 5 && receiver.get_type().var_style.is_ptr() != Some(true) => Some("requires a pointer receiver"),
+			_ => None
+		};
+		if let Some(msg) = error {
+			print_code_error("Invalid Receiver", &format!("\"{}\" {}", func.name, msg), position, file_content);
+		}
+	}
+
+	/// Replaces a call to a meta function with its result, which is evaluated while transpiling.
+	/// Meta functions can only be called with constant arguments.
+	fn fold_meta_call(parser: &ExpressionParser, left_expr: &Rc<Expression>, exprs: &Rc<Vec<Rc<Expression>>>, context: &Option<&mut Context>, final_type: &VariableType, position: &Position, file_content: &str) -> Option<Expression> {
+		if let Expression::Value(name, VariableType { var_type: Type::Function(func), .. }, name_pos) = &**left_expr {
+			if !func.styles.contains(&FunStyle::Meta) {
+				return None;
+			}
+			let meta_function = context.as_ref().unwrap().find_meta_function(name, parser.context_manager)?;
+			let mut arguments = Vec::new();
+			for e in exprs.iter() {
+				match evaluate(e, &std::collections::BTreeMap::new()) {
+					Some(value) => arguments.push(value),
+					None => {
+						let argument_pos = e.get_position().unwrap_or(position.clone());
+						print_code_error("Non-Constant Meta Call", "meta functions can only be called with constant arguments", &argument_pos, file_content);
+						return None;
+					}
+				}
+			}
+			let call_pos = name_pos.to(position);
+			match evaluate_meta_call(&meta_function, arguments) {
+				Some(result) => return Some(Expression::Value(result.to_cpp(), final_type.clone(), call_pos)),
+				None => print_code_error("Meta Evaluation Failed", "could not evaluate this meta function call while transpiling", &call_pos, file_content)
+			}
+		}
+		None
+	}
+
 	fn parse_array_access(parser: &ExpressionParser, part_index: &usize, exprs: Rc<Vec<Rc<Expression>>>, context: &Option<&mut Context>, position: Position) -> (Option<ExpressionPiece>,Option<Position>) {
-		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], context);
-		if result.is_some() {
-			return (Some(ExpressionPiece::Expression(Rc::new(Expression::ArrayAccess(result.unwrap(), exprs, VariableType::inferred(), position)))), None);
+		let result = Self::get_expression_from_piece(&parser.parts[part_index - 1], &parser.config_data.library, context);
+		if let Some(expr) = result {
+			let final_type = expr.get_type().get_element_type().unwrap_or(VariableType::inferred());
+			return (Some(ExpressionPiece::Expression(Rc::new(Expression::ArrayAccess(expr, exprs, final_type, position)))), None);
 		}
 		return (None, Some(position));
 	}
 
-	fn get_expression_from_piece(piece: &ExpressionPiece, context: &Option<&mut Context>) -> Option<Rc<Expression>> {
+	fn get_expression_from_piece(piece: &ExpressionPiece, library: &LibraryNames, context: &Option<&mut Context>) -> Option<Rc<Expression>> {
 		return match piece {
 			ExpressionPiece::Value(value, position) => {
 				let mut final_val = value.clone();
@@ -457,14 +683,14 @@ impl ExpressionPiece {
 			ExpressionPiece::Expression(expr) => {
 				Some(Rc::clone(expr))
 			},
-			ExpressionPiece::EncapsulatedValues(expressions, position) => {
+			ExpressionPiece::EncapsulatedValues(expressions, _, position) => {
 				Some(Rc::new(Expression::Expressions(Rc::clone(expressions), piece.get_encapsulated_type().unwrap_or(VariableType::inferred()), position.clone())))
 			},
 			ExpressionPiece::InitializerList(expressions, position) => {
 				Some(Rc::new(Expression::InitializerList(Rc::clone(expressions), piece.get_encapsulated_type().unwrap_or(VariableType::inferred()), position.clone())))
 			},
 			ExpressionPiece::Type(tf_type, position) => {
-				Some(Rc::new(Expression::Value(tf_type.to_cpp(), (*tf_type).clone(), position.clone())))
+				Some(Rc::new(Expression::Value(tf_type.to_cpp(library), (*tf_type).clone(), position.clone())))
 			},
 			ExpressionPiece::Function(scope, captures, params, return_type, end_line, position) => {
 				Some(Rc::new(Expression::Function(Rc::clone(scope), captures.clone(), params.clone(), return_type.clone(), *end_line, position.clone())))
@@ -502,21 +728,25 @@ impl ExpressionPiece {
 			return VariableType::boolean();
 		} else if context.is_some() {
 			let c = context.as_ref().unwrap();
-			let ct = c.typing.get_item(value, None, None, false);
-			if ct.is_some() {
-				return match ct.unwrap() {
-					ContextType::Variable(variable_type) => variable_type,
-					ContextType::Function(function) => VariableType::function(function),
-					ContextType::QuantumFunction(functions) => VariableType::quantum_function(functions),
-					ContextType::Class(class_type) => VariableType::class(class_type),
-					ContextType::Namespace(_) => VariableType::namespace(),
-					_ => VariableType::inferred()
-				}
+			if let Some(ct) = c.typing.get_item(value, None, None, false) {
+				return Self::get_type_from_context_type(ct);
 			}
 		}
 		return VariableType::inferred();
 	}
 
+	/// Retrieves the type of a value that refers to an item of the typing context.
+	fn get_type_from_context_type(ctx_type: ContextType) -> VariableType {
+		match ctx_type {
+			ContextType::Variable(variable_type) => variable_type,
+			ContextType::Function(function) => VariableType::function(function),
+			ContextType::QuantumFunction(functions) => VariableType::quantum_function(functions),
+			ContextType::Class(class_type) => VariableType::class(class_type),
+			ContextType::Namespace(_) => VariableType::namespace(),
+			_ => VariableType::inferred()
+		}
+	}
+
 	fn infer_number_type(value: &mut String) -> Type {
 		return Type::Number(NumberType::from_value_text(value));
 	}
@@ -568,3 +798,32 @@ impl ExpressionPiece {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::config_management::read_config_files;
+	use crate::context_management::error_count;
+	use crate::context_management::context_manager::ContextManager;
+
+	/// Pieces the parser cannot combine, such as two values without an operator, report a positioned error.
+	#[test]
+	fn unsupported_pieces_report_an_error() {
+		let content = "a b";
+		let config_data = read_config_files(Some(concat!(env!("CARGO_MANIFEST_DIR"), "/config"))).unwrap();
+		let mut parser = Parser::new(content.to_string());
+		let mut context_manager = ContextManager::new();
+		let mut context = Context::new();
+		let mut expr_parser = ExpressionParser::new(&mut parser, Position::new("test.tasty".to_string(), None, 0, None), &config_data, &mut Some(&mut context), &mut context_manager, None, None);
+		expr_parser.parts = vec![
+			ExpressionPiece::Value("a".to_string(), Position::new("test.tasty".to_string(), None, 0, Some(1))),
+			ExpressionPiece::Value("b".to_string(), Position::new("test.tasty".to_string(), None, 2, Some(3)))
+		];
+
+		let errors_before = error_count();
+		let expression = ExpressionPiece::parse_expr_parts(&mut expr_parser, &mut Some(&mut context), content, None);
+		assert!(expression.is_invalid());
+		assert_eq!(error_count(), errors_before + 1);
+	}
+}
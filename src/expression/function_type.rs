@@ -5,6 +5,8 @@
  * of quirks available for functions.
  **********************************************************/
 
+use crate::config_management::{ ConstraintStyle, LibraryNames };
+
 lazy_static! {
 	pub static ref FUNCTION_STYLES: Vec<&'static str> = vec!("static", "extern", "virtual", "inline", "meta", "const", "override");
 }
@@ -115,3 +117,30 @@ impl FunStyle {
 		}
 	}
 }
+
+/// Generates the template declaration for a generic function, including the
+/// constraints from its `where` clause.
+///
+/// # Arguments
+///
+/// * `type_params` - The names of the type parameters.
+/// * `constraints` - Pairs of type parameter names and the C++ constraint they must satisfy.
+/// * `style` - Whether constraints are written as a `requires` clause or with `std::enable_if_t`.
+/// * `library` - The names used for the standard library.
+pub fn template_declaration(type_params: &[String], constraints: &[(String, String)], style: ConstraintStyle, library: &LibraryNames) -> String {
+	let mut params = type_params.iter().map(|p| format!("typename {}", p)).collect::<Vec<String>>();
+	if constraints.is_empty() {
+		return format!("template<{}>", params.join(", "));
+	}
+	match style {
+		ConstraintStyle::Requires => {
+			let conditions = constraints.iter().map(|c| format!("{}<{}>", c.1, c.0)).collect::<Vec<String>>();
+			format!("template<{}> requires {}", params.join(", "), conditions.join(" && "))
+		},
+		ConstraintStyle::EnableIf => {
+			let conditions = constraints.iter().map(|c| format!("{}<{}>::value", c.1, c.0)).collect::<Vec<String>>();
+			params.push(format!("typename = {}<{}>", library.std_name("enable_if_t"), conditions.join(" && ")));
+			format!("template<{}>", params.join(", "))
+		}
+	}
+}
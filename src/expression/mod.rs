@@ -11,14 +11,18 @@ pub mod expression_piece;
 pub mod value_type;
 pub mod variable_type;
 pub mod function_type;
+pub mod const_eval;
 
-use crate::config_management::operator_data::OperatorDataStructure;
+use crate::config_management::operator_data::{ OperatorDataStructure, AS_OPERATOR_ID };
 
-use crate::expression::variable_type::{ Type, VariableType };
+use crate::expression::variable_type::{ Type, VariableType, VarStyle };
 use crate::expression::value_type::{ Property, Function };
+use crate::expression::const_eval::{ ConstValue, fold_arithmetic, fits_number_type };
 
 use crate::context_management::position::Position;
 use crate::context_management::context::Context;
+use crate::context_management::class_members::ClassMembers;
+use crate::context_management::print_code_warning;
 
 use crate::scope_parser::ScopeExpression;
 
@@ -45,6 +49,37 @@ pub enum Expression {
 }
 
 impl Expression {
+	pub fn is_invalid(&self) -> bool {
+		matches!(self, Expression::Invalid)
+	}
+
+	/// Checks whether the expression is a single value within parentheses.
+	/// A single value followed by a trailing comma is a tuple instead.
+	pub fn is_grouping(&self) -> bool {
+		if let Expression::Expressions(exprs, var_type, _) = self {
+			if let Type::Tuple(_) = var_type.var_type {
+				return false;
+			}
+			return exprs.len() == 1;
+		}
+		false
+	}
+
+	/// Checks whether the expression creates a temporary value that cannot be bound to an lvalue reference.
+	pub fn is_temporary(&self) -> bool {
+		match self {
+			Expression::ConstructCall(..) | Expression::InitializerList(..) => true,
+			Expression::FunctionCall(_, _, result_type, _) => {
+				match result_type.var_style {
+					VarStyle::Copy | VarStyle::Move => !result_type.is_inferred(),
+					_ => false
+				}
+			},
+			Expression::Expressions(exprs, _, _) => exprs.len() == 1 && exprs[0].is_temporary(),
+			_ => false
+		}
+	}
+
 	pub fn get_type(&self) -> VariableType {
 		if let Expression::Invalid = self {
 			return VariableType::inferred();
@@ -71,7 +106,11 @@ impl Expression {
 						name: "".to_string(),
 						parameters: props,
 						return_type: return_type.clone(),
-						styles: Vec::new()
+						styles: Vec::new(),
+						no_discard: false,
+						call_conv: None,
+						self_style: None,
+						is_const: false
 					})
 				}
 			} else {
@@ -95,14 +134,9 @@ impl Expression {
 	pub fn get_line_number(&self) -> Option<usize> {
 		if let Expression::Ternary(e, _, _, _, _) = self {
 			return e.get_line_number();
-		} else {
-			let pos = self.get_position();
-			if pos.is_some() {
-				return Some(pos.unwrap().line.unwrap_or(0));
-			} else {
-				return None;
-			}
 		}
+		// Positions count lines from one, but lines are tracked from zero.
+		self.get_position().map(|pos| pos.line.unwrap_or(1).saturating_sub(1))
 	}
 
 	pub fn get_position(&self) -> Option<Position> {
@@ -193,12 +227,20 @@ impl Expression {
 						}
 					}
 					result
+				} else if *id == 8 || *id == 15 {
+					let operand = expr.to_string(operators, context);
+					let operator_data = &operators["prefix"][*id];
+					if let Expression::Expressions(..) = &**expr {
+						format!("{}{}", operator_data.name.as_ref().unwrap(), operand)
+					} else {
+						format!("{}({})", operator_data.name.as_ref().unwrap(), operand)
+					}
 				} else {
 					let operator_data = &operators["prefix"][*id];
 					format!("{}{}{}",
 						operator_data.name.as_ref().unwrap_or(&"".to_string()),
 						if operator_data.cannot_touch { " " } else { "" },
-						expr.to_string(operators, context)
+						Self::operand_to_string(expr, operator_data.priority, false, operators, context)
 					)
 				}
 			},
@@ -208,17 +250,20 @@ impl Expression {
 			Expression::Infix(expr_left, expr_right, id, tf_type, _) => {
 				if *id == 1 {
 					let insides = expr_right.to_string(operators, context);
+					let make_tuple = format!("{}(", context.library.std_name("make_tuple"));
 					format!("{}<{}>", expr_left.to_string(operators, context), 
 						if insides.starts_with('(') && insides.ends_with(')') {
 							&insides[1..insides.len() - 1]
-						} else if insides.starts_with("std::make_tuple(") && insides.ends_with(')') {
-							&insides[16..insides.len() - 1]
+						} else if insides.starts_with(&make_tuple) && insides.ends_with(')') {
+							&insides[make_tuple.len()..insides.len() - 1]
 						} else { &insides }
 					)
 				} else if *id == 2 {
 					let expr_right_str = expr_right.to_string(operators, context);
-					if expr_right.get_type().is_int() {
-						format!("std::get<{}>({})", expr_right_str, expr_left.to_string(operators, context))
+					// Only a number is a tuple index, since a member of the class can also be an int.
+					if expr_right.get_type().is_int() && expr_right.is_literal() {
+						context.add_header("tuple", true);
+						format!("{}<{}>({})", context.library.std_name("get"), expr_right_str, expr_left.to_string(operators, context))
 					} else {
 						let op = expr_left.get_type().access_operator();
 						format!("{}{}{}", expr_left.to_string(operators, context), op, expr_right_str)
@@ -230,7 +275,7 @@ impl Expression {
 						expr_right.to_string(operators, context)
 					)
 				} else if *id >= 6 && *id <= 9 {
-					let mut right = tf_type.to_cpp(); // expr_right.to_string(operators, context);
+					let mut right = tf_type.to_cpp(&context.library); // expr_right.to_string(operators, context);
 					right = match *id {
 						6 => format!("({})", right),
 						7 => format!("static_cast<{}>", right),
@@ -244,18 +289,45 @@ impl Expression {
 					} else {
 						format!("{}({})", right, left)
 					}
+				} else if *id == AS_OPERATOR_ID {
+					let cast_type = tf_type.to_cpp(&context.library);
+					let left = match **expr_left {
+						Expression::Expressions(..) => expr_left.to_string(operators, context),
+						_ => format!("({})", expr_left.to_string(operators, context))
+					};
+					let is_narrowing = match (&expr_left.get_type().var_type, &tf_type.var_type) {
+						(Type::Number(from), Type::Number(to)) => from.is_narrowing_to(to),
+						_ => false
+					};
+					if context.checked_casts && is_narrowing {
+						context.add_header("utility", true);
+						context.add_header("stdexcept", true);
+						format!("[](auto value) {{ if(!{}<{}>(value)) throw {}(\"checked cast out of range\"); return static_cast<{}>(value); }}{}",
+							context.library.std_name("in_range"), cast_type, context.library.std_name("out_of_range"), cast_type, left)
+					} else {
+						format!("static_cast<{}>{}", cast_type, left)
+					}
+				} else if *id == 43 {
+					self.null_safe_to_string(operators, context)
+				} else if *id == 44 {
+					self.elvis_to_string(operators, context)
 				} else if *id == 29 || *id == 30 {
 					context.ltype = Some(expr_left.get_type().clone());
 					let right_str = expr_right.to_string(operators, context);
 					context.ltype = None;
 					let right_str_final = if *id == 29 && !expr_right.get_type().is_inferred() {
-						expr_right.get_type().convert_between_styles(&expr_left.get_type(), &right_str).unwrap_or(right_str.to_string())
+						expr_right.get_type().convert_between_styles(&expr_left.get_type(), &right_str, &context.library).unwrap_or(right_str.to_string())
 					} else {
 						right_str
 					};
 					format!("{} {} {}", expr_left.to_string(operators, context), "=", right_str_final)
 				} else {
-					format!("{} {} {}", expr_left.to_string(operators, context), operators["infix"][*id].name.as_ref().unwrap_or(&"".to_string()), expr_right.to_string(operators, context))
+					let operator_data = &operators["infix"][*id];
+					format!("{} {} {}",
+						Self::operand_to_string(expr_left, operator_data.priority, operator_data.reverse_priority, operators, context),
+						operator_data.name.as_ref().unwrap_or(&"".to_string()),
+						Self::operand_to_string(expr_right, operator_data.priority, !operator_data.reverse_priority, operators, context)
+					)
 				}
 			},
 			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
@@ -288,10 +360,11 @@ impl Expression {
 					};
 					expr_list.push(prefix + &e.to_string(operators, context));
 				}
-				if expr_list.len() == 1 {
+				if self.is_grouping() {
 					format!("({})", expr_list.first().unwrap())
 				} else {
-					format!("std::make_tuple({})", expr_list.join(", "))
+					context.add_header("tuple", true);
+					format!("{}({})", context.library.std_name("make_tuple"), expr_list.join(", "))
 				}
 			},
 			Expression::InitializerList(exprs, _, _) => {
@@ -303,10 +376,16 @@ impl Expression {
 			},
 			Expression::FunctionCall(expr, _, _, _) => {
 				let expr_list = self.get_parameters(operators, context);
+				// Forwarded functions of abstracts are called on the underlying value.
+				if let Expression::Value(name, _, _) = &**expr {
+					if context.convert_this_to_self && context.forward_functions.contains(name) {
+						return format!("self.{}({})", name, expr_list.join(", "));
+					}
+				}
 				format!("{}({})", expr.to_string(operators, context), expr_list.join(", "))
 			},
 			Expression::ConstructCall(tf_type, _, _, _) => {
-				format!("{}({})", tf_type.to_cpp(false), self.get_parameters(operators, context).join(", "))
+				format!("{}({})", tf_type.to_cpp(false, &context.library), self.get_parameters(operators, context).join(", "))
 			},
 			Expression::ArrayAccess(expr, exprs, _, _) => {
 				let mut expr_list = Vec::new();
@@ -319,7 +398,7 @@ impl Expression {
 				let mut prop_list = Vec::new();
 				for p in params {
 					prop_list.push({
-						let var_cpp = p.0.to_cpp();
+						let var_cpp = p.0.to_cpp(&context.library);
 						if var_cpp == "auto" {
 							p.1.clone()
 						} else if p.2.is_some() {
@@ -333,22 +412,22 @@ impl Expression {
 				let scope_str = scope.to_string(operators, 0, 1, context);
 				let final_scope_str =  if context.align_lines {
 					let re = Regex::new("(?:\n\r|\r\n|\r|\n)").unwrap();
-					let mut final_line = *pos.line.as_ref().unwrap() - 1;
-					for _ in re.split(&scope_str) {
+					let mut final_line = pos.line.unwrap_or(1) - 1;
+					for _ in re.split(&scope_str).skip(1) {
 						final_line += 1;
 					}
-					format!("{{{}{}}}", scope_str, if final_line == *end_line { " " } else { "\n" })
+					format!(" {{{}{}}}", scope_str, if final_line == *end_line { " " } else { "\n" })
 				} else {
-					format!("{{\n\t{}\n}}", scope_str.trim())
+					format!("{}{{\n\t{}\n}}", if context.uses_allman_braces() { "\n" } else { " " }, scope_str.trim())
 				};
 
 				if return_type.is_void() {
-					format!("[{}]({}) {}", captures.join(", "), prop_list.join(", "), final_scope_str)
+					format!("[{}]({}){}", captures.join(", "), prop_list.join(", "), final_scope_str)
 				} else {
-					format!("[{}]({}) -> {} {}",
+					format!("[{}]({}) -> {}{}",
 						captures.join(", "),
 						prop_list.join(", "),
-						return_type.to_cpp(),
+						return_type.to_cpp(&context.library),
 						final_scope_str
 					)
 				}
@@ -356,6 +435,174 @@ impl Expression {
 		}
 	}
 
+	/// Checks whether `a ?: b` should produce the value `a` points to, as `b` is a value rather than a pointer.
+	pub fn elvis_dereferences(left_type: &VariableType, right_type: &VariableType) -> bool {
+		left_type.var_style.is_ptr().unwrap_or(false) && !right_type.is_inferred() && !right_type.var_style.is_ptr().unwrap_or(true)
+	}
+
+	/// Emits the Elvis operator `a ?: b`.
+	/// Optionals use `value_or`, while anything else is checked for truthiness.
+	/// Unless `a` is a plain value, it is passed to a lambda so it is only evaluated once.
+	fn elvis_to_string(&self, operators: &OperatorDataStructure, context: &mut Context) -> String {
+		if let Expression::Infix(expr_left, expr_right, _, _, _) = self {
+			let left_type = expr_left.get_type();
+			let left = match **expr_left {
+				Expression::Infix(..) | Expression::Prefix(..) | Expression::Ternary(..) => format!("({})", expr_left.to_string(operators, context)),
+				_ => expr_left.to_string(operators, context)
+			};
+			let right = expr_right.to_string(operators, context);
+			if left_type.is_optional_value() {
+				return format!("{}.value_or({})", left, right);
+			}
+			let deref = if Self::elvis_dereferences(&left_type, &expr_right.get_type()) { "*" } else { "" };
+			if let Expression::Value(..) = **expr_left {
+				return format!("({} ? {}{} : {})", left, deref, left, right);
+			}
+			return format!("[&](auto elvis_value) {{ return elvis_value ? {}elvis_value : {}; }}({})", deref, right, left);
+		}
+		"".to_string()
+	}
+
+	/// Emits a chain of null-safe accesses `a?.b?.c` as nested checks of each link.
+	fn null_safe_to_string(&self, operators: &OperatorDataStructure, context: &mut Context) -> String {
+		let mut members = Vec::new();
+		let mut base = self;
+		while let Expression::Infix(left, right, 43, _, _) = base {
+			members.push(right);
+			base = left;
+		}
+		members.reverse();
+
+		let mut access = base.to_string(operators, context);
+		let mut access_type = base.get_type();
+		let mut checks = Vec::new();
+		for member in members {
+			let name = member.to_string(operators, context);
+			if access_type.is_optional_value() {
+				checks.push(format!("{}.has_value()", access));
+				access = format!("(*{}).{}", access, name);
+			} else if access_type.is_inferred() || access_type.var_style.is_ptr().unwrap_or(false) {
+				checks.push(access.clone());
+				access = format!("{}->{}", access, name);
+			} else {
+				access = format!("{}.{}", access, name);
+			}
+			access_type = member.get_type();
+		}
+
+		let (mut result, empty) = if access_type.var_style.is_ptr().unwrap_or(false) {
+			(access, "nullptr".to_string())
+		} else {
+			context.add_header("optional", true);
+			if access_type.is_optional_value() {
+				(access, context.library.std_name("nullopt"))
+			} else {
+				(format!("{}({})", context.library.std_name("make_optional"), access), context.library.std_name("nullopt"))
+			}
+		};
+		for check in checks.iter().rev() {
+			result = format!("({} ? {} : {})", check, result, empty);
+		}
+		result
+	}
+
+	pub fn is_literal(&self) -> bool {
+		match self {
+			Expression::Value(content, _, _) => {
+				content == "true" || content == "false" || content == "nullptr" ||
+				content.starts_with(|c: char| c.is_ascii_digit() || c == '"' || c == '\'')
+			},
+			Expression::Prefix(expr, id, _, _) => *id >= 2 && *id <= 5 && expr.is_literal(),
+			Expression::Expressions(exprs, _, _) => self.is_grouping() && exprs[0].is_literal(),
+			_ => false
+		}
+	}
+
+	/// Folds arithmetic on numeric literals into a single literal, such as `2 + 3 * 4` into `14`.
+	/// Divisions by zero are left unchanged and reported as a warning.
+	pub fn simplify(self: &Rc<Self>, content: &str) -> Rc<Expression> {
+		match &**self {
+			Expression::Infix(left, right, id, var_type, position) => {
+				let left = left.simplify(content);
+				let right = right.simplify(content);
+				if let (Some(left_value), Some(right_value)) = (left.get_number_literal(), right.get_number_literal()) {
+					if *id == 11 && right_value.is_zero() {
+						print_code_warning("Division By Zero", "this division was not folded because it divides by zero", position, content);
+					} else if let Some(result) = fold_arithmetic(*id, left_value, right_value) {
+						if fits_number_type(&result, var_type) {
+							return Rc::new(Expression::Value(result.to_cpp(), var_type.clone(), position.clone()));
+						}
+						if let Type::Number(number_type) = &var_type.var_type {
+							let message = format!("this expression was not folded because its value, {}, does not fit {}", result.to_cpp(), number_type.to_cpp());
+							print_code_warning("Narrowing Conversion", &message, position, content);
+						}
+					}
+				}
+				Rc::new(Expression::Infix(left, right, *id, var_type.clone(), position.clone()))
+			},
+			Expression::Prefix(expr, id, var_type, position) => {
+				let expr = expr.simplify(content);
+				if *id == 3 {
+					if let Some(value) = expr.get_number_literal() {
+						let result = match value {
+							ConstValue::Int(value) => value.checked_neg().map(ConstValue::Int),
+							ConstValue::Float(value) => Some(ConstValue::Float(-value)),
+							_ => None
+						};
+						if let Some(result) = result.filter(|result| fits_number_type(result, var_type)) {
+							return Rc::new(Expression::Value(result.to_cpp(), var_type.clone(), position.clone()));
+						}
+					}
+				}
+				Rc::new(Expression::Prefix(expr, *id, var_type.clone(), position.clone()))
+			},
+			Expression::Suffix(expr, id, var_type, position) => {
+				Rc::new(Expression::Suffix(expr.simplify(content), *id, var_type.clone(), position.clone()))
+			},
+			Expression::Ternary(condition, if_true, if_false, id, var_type) => {
+				Rc::new(Expression::Ternary(condition.simplify(content), if_true.simplify(content), if_false.simplify(content), *id, var_type.clone()))
+			},
+			Expression::Expressions(exprs, var_type, position) => {
+				let exprs = Self::simplify_list(exprs, content);
+				if exprs.len() == 1 && exprs[0].get_number_literal().is_some() {
+					return exprs[0].clone();
+				}
+				Rc::new(Expression::Expressions(exprs, var_type.clone(), position.clone()))
+			},
+			Expression::InitializerList(exprs, var_type, position) => {
+				Rc::new(Expression::InitializerList(Self::simplify_list(exprs, content), var_type.clone(), position.clone()))
+			},
+			Expression::FunctionCall(expr, exprs, var_type, position) => {
+				Rc::new(Expression::FunctionCall(expr.clone(), Self::simplify_list(exprs, content), var_type.clone(), position.clone()))
+			},
+			Expression::ConstructCall(construct_type, exprs, var_type, position) => {
+				Rc::new(Expression::ConstructCall(construct_type.clone(), Self::simplify_list(exprs, content), var_type.clone(), position.clone()))
+			},
+			Expression::ArrayAccess(expr, exprs, var_type, position) => {
+				Rc::new(Expression::ArrayAccess(expr.simplify(content), Self::simplify_list(exprs, content), var_type.clone(), position.clone()))
+			},
+			_ => self.clone()
+		}
+	}
+
+	fn simplify_list(exprs: &Rc<Vec<Rc<Expression>>>, content: &str) -> Rc<Vec<Rc<Expression>>> {
+		Rc::new(exprs.iter().map(|expr| expr.simplify(content)).collect())
+	}
+
+	/// Retrieves the value of a numeric literal.
+	fn get_number_literal(&self) -> Option<ConstValue> {
+		if let Expression::Value(content, var_type, _) = self {
+			if let Type::Number(_) = var_type.var_type {
+				return match ConstValue::from_literal(content) {
+					Some(ConstValue::Int(value)) => Some(ConstValue::Int(value)),
+					Some(ConstValue::Float(value)) => Some(ConstValue::Float(value)),
+					_ => None
+				};
+			}
+		}
+		None
+	}
+
 	pub fn is_construction_call(&self) -> bool {
 		if let Expression::ConstructCall(..) = self {
 			return true;
@@ -381,11 +628,269 @@ impl Expression {
 		return result;
 	}
 
+	/// Finds a variable declared const that is assigned, incremented, or decremented within the expression.
+	///
+	/// # Return
+	///
+	/// The name of the variable if one is found.
+	pub fn find_const_modification(&self) -> Option<String> {
+		let target = match self {
+			Expression::Infix(left, _, id, _, _) if *id >= 29 && *id <= 40 => Some(left),
+			Expression::Prefix(operand, id, _, _) if *id <= 1 => Some(operand),
+			Expression::Suffix(operand, id, _, _) if *id <= 1 => Some(operand),
+			_ => None
+		};
+		if let Some(name) = target.and_then(|target_expr| target_expr.get_const_target()) {
+			return Some(name);
+		}
+		match self {
+			Expression::Prefix(expr, _, _, _) | Expression::Suffix(expr, _, _, _) => expr.find_const_modification(),
+			Expression::Infix(left, right, _, _, _) => left.find_const_modification().or_else(|| right.find_const_modification()),
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.find_const_modification()
+					.or_else(|| expr_2.find_const_modification())
+					.or_else(|| expr_3.find_const_modification())
+			},
+			Expression::Expressions(exprs, _, _) |
+			Expression::InitializerList(exprs, _, _) |
+			Expression::ConstructCall(_, exprs, _, _) => exprs.iter().find_map(|e| e.find_const_modification()),
+			Expression::FunctionCall(expr, exprs, _, _) | Expression::ArrayAccess(expr, exprs, _, _) => {
+				expr.find_const_modification().or_else(|| exprs.iter().find_map(|e| e.find_const_modification()))
+			},
+			_ => None
+		}
+	}
+
+	/// Retrieves the name of the const variable that would be modified by assigning to this expression.
+	/// A const pointer points to const data, so the pointer itself may be reassigned, but not dereferenced.
+	fn get_const_target(&self) -> Option<String> {
+		match self {
+			Expression::Value(name, var_type, _) if var_type.is_const() && var_type.var_style.is_ptr() != Some(true) => Some(name.clone()),
+			Expression::Prefix(operand, 6, _, _) | Expression::ArrayAccess(operand, _, _, _) => {
+				match &**operand {
+					Expression::Value(name, var_type, _) if var_type.is_const() => Some(name.clone()),
+					_ => None
+				}
+			},
+			_ => None
+		}
+	}
+
+	/// Finds the position of the first reinterpret cast (`#*`) within the expression.
+	pub fn find_reinterpret_cast(&self) -> Option<Position> {
+		match self {
+			Expression::Infix(_, _, 8, _, position) => Some(position.clone()),
+			Expression::Prefix(expr, _, _, _) | Expression::Suffix(expr, _, _, _) => expr.find_reinterpret_cast(),
+			Expression::Infix(left, right, _, _, _) => left.find_reinterpret_cast().or_else(|| right.find_reinterpret_cast()),
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.find_reinterpret_cast()
+					.or_else(|| expr_2.find_reinterpret_cast())
+					.or_else(|| expr_3.find_reinterpret_cast())
+			},
+			Expression::Expressions(exprs, _, _) |
+			Expression::InitializerList(exprs, _, _) |
+			Expression::ConstructCall(_, exprs, _, _) => exprs.iter().find_map(|e| e.find_reinterpret_cast()),
+			Expression::FunctionCall(expr, exprs, _, _) | Expression::ArrayAccess(expr, exprs, _, _) => {
+				expr.find_reinterpret_cast().or_else(|| exprs.iter().find_map(|e| e.find_reinterpret_cast()))
+			},
+			_ => None
+		}
+	}
+
+	/// Checks whether the expression accesses `this` or a member of the class, rather than a local variable.
+	pub fn is_member_access(&self, members: &ClassMembers, locals: &Vec<String>) -> bool {
+		match self {
+			Expression::Value(name, _, _) => name == "this" || (members.variables.contains(name) && !locals.contains(name)),
+			Expression::Infix(left, _, id, _, _) if *id >= 2 && *id <= 5 => left.is_member_access(members, locals),
+			Expression::Prefix(expr, 6, _, _) | Expression::ArrayAccess(expr, _, _, _) => expr.is_member_access(members, locals),
+			Expression::Expressions(exprs, _, _) => self.is_grouping() && exprs[0].is_member_access(members, locals),
+			_ => false
+		}
+	}
+
+	/// Checks whether the expression may modify the object of the class it is used within.
+	/// Accesses that cannot be checked, such as passing a member to a function, are assumed to modify it.
+	pub fn may_modify_members(&self, members: &ClassMembers, locals: &Vec<String>) -> bool {
+		let modifies = match self {
+			Expression::Infix(left, _, id, _, _) if *id >= 29 && *id <= 40 => left.is_member_access(members, locals),
+			Expression::Prefix(expr, id, _, _) if *id <= 1 || *id == 7 || *id == 10 || *id == 14 => expr.is_member_access(members, locals),
+			Expression::Suffix(expr, _, _, _) => expr.is_member_access(members, locals),
+			Expression::FunctionCall(func, exprs, _, _) => {
+				let is_const_call = match &**func {
+					Expression::Value(name, _, _) if !locals.contains(name) => members.is_const_function(name).unwrap_or(true),
+					Expression::Infix(object, method, id, _, _) if (*id == 2 || *id == 3) && object.is_member_access(members, locals) => {
+						match (&**object, &**method) {
+							(Expression::Value(object_name, _, _), Expression::Value(name, _, _)) if object_name == "this" => {
+								members.is_const_function(name).unwrap_or(false)
+							},
+							_ => false
+						}
+					},
+					_ => true
+				};
+				!is_const_call || exprs.iter().any(|e| e.is_member_access(members, locals))
+			},
+			Expression::ConstructCall(_, exprs, _, _) => exprs.iter().any(|e| e.is_member_access(members, locals)),
+			Expression::Function(..) => true,
+			_ => false
+		};
+		if modifies {
+			return true;
+		}
+		match self {
+			Expression::Prefix(expr, _, _, _) | Expression::Suffix(expr, _, _, _) => expr.may_modify_members(members, locals),
+			Expression::Infix(left, right, _, _, _) => left.may_modify_members(members, locals) || right.may_modify_members(members, locals),
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.may_modify_members(members, locals) ||
+				expr_2.may_modify_members(members, locals) ||
+				expr_3.may_modify_members(members, locals)
+			},
+			Expression::Expressions(exprs, _, _) |
+			Expression::InitializerList(exprs, _, _) |
+			Expression::ConstructCall(_, exprs, _, _) => exprs.iter().any(|e| e.may_modify_members(members, locals)),
+			Expression::FunctionCall(expr, exprs, _, _) | Expression::ArrayAccess(expr, exprs, _, _) => {
+				expr.may_modify_members(members, locals) || exprs.iter().any(|e| e.may_modify_members(members, locals))
+			},
+			_ => false
+		}
+	}
+
+	/// Checks whether the expression could be used within a `constexpr` function for `--auto-constexpr`.
+	/// Only literals, the `locals`, and calls to the `constexpr_functions` are allowed, along with operators that do not allocate or throw.
+	pub fn is_constexpr_compatible(&self, locals: &Vec<String>, constexpr_functions: &Vec<String>) -> bool {
+		match self {
+			Expression::Value(name, _, _) => self.is_literal() || locals.contains(name),
+			Expression::Prefix(expr, id, _, _) => *id <= 7 && expr.is_constexpr_compatible(locals, constexpr_functions),
+			Expression::Suffix(expr, _, _, _) => expr.is_constexpr_compatible(locals, constexpr_functions),
+			Expression::Infix(left, right, id, var_type, _) => {
+				// Casts are only allowed between numbers, since pointer casts may be reinterpret casts.
+				let is_allowed = match *id {
+					0 | 2 | 3 | 4 | 5 => false,
+					AS_OPERATOR_ID => var_type.is_number() || var_type.is_boolean(),
+					_ => true
+				};
+				is_allowed && left.is_constexpr_compatible(locals, constexpr_functions) && (*id == AS_OPERATOR_ID || right.is_constexpr_compatible(locals, constexpr_functions))
+			},
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.is_constexpr_compatible(locals, constexpr_functions) &&
+				expr_2.is_constexpr_compatible(locals, constexpr_functions) &&
+				expr_3.is_constexpr_compatible(locals, constexpr_functions)
+			},
+			Expression::Expressions(exprs, _, _) => self.is_grouping() && exprs[0].is_constexpr_compatible(locals, constexpr_functions),
+			Expression::FunctionCall(func, exprs, _, _) => {
+				let is_constexpr_call = match &**func {
+					Expression::Value(name, _, _) => !locals.contains(name) && constexpr_functions.contains(name),
+					_ => false
+				};
+				is_constexpr_call && exprs.iter().all(|e| e.is_constexpr_compatible(locals, constexpr_functions))
+			},
+			_ => false
+		}
+	}
+
+	/// Checks whether the variable's value is used by this expression.
+	/// Assigning to the variable with `=` does not count as a read.
+	pub fn reads_variable(&self, name: &str) -> bool {
+		match self {
+			Expression::Value(value, _, _) => value == name,
+			Expression::Infix(left, right, 29, _, _) => {
+				let is_write = match &**left {
+					Expression::Value(value, _, _) => value == name,
+					_ => false
+				};
+				(!is_write && left.reads_variable(name)) || right.reads_variable(name)
+			},
+			Expression::Infix(left, _, id, _, _) if *id <= 5 => left.reads_variable(name),
+			Expression::Prefix(expr, _, _, _) | Expression::Suffix(expr, _, _, _) => expr.reads_variable(name),
+			Expression::Infix(left, right, _, _, _) => left.reads_variable(name) || right.reads_variable(name),
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.reads_variable(name) || expr_2.reads_variable(name) || expr_3.reads_variable(name)
+			},
+			Expression::Expressions(exprs, _, _) |
+			Expression::InitializerList(exprs, _, _) |
+			Expression::ConstructCall(_, exprs, _, _) => exprs.iter().any(|e| e.reads_variable(name)),
+			Expression::FunctionCall(expr, exprs, _, _) | Expression::ArrayAccess(expr, exprs, _, _) => {
+				expr.reads_variable(name) || exprs.iter().any(|e| e.reads_variable(name))
+			},
+			Expression::Function(scope, _, _, _, _, _) => scope.reads_variable(name),
+			Expression::Invalid => false
+		}
+	}
+
+	/// Checks whether evaluating this expression could do more than produce a value,
+	/// such as calling a function, allocating, or assigning.
+	pub fn has_side_effects(&self) -> bool {
+		match self {
+			Expression::FunctionCall(..) | Expression::ConstructCall(..) | Expression::Suffix(..) => true,
+			Expression::Prefix(expr, id, _, _) => *id <= 1 || (*id >= 9 && *id <= 14) || expr.has_side_effects(),
+			Expression::Infix(_, _, id, _, _) if *id >= 29 && *id <= 40 => true,
+			Expression::Infix(left, right, _, _, _) => left.has_side_effects() || right.has_side_effects(),
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				expr_1.has_side_effects() || expr_2.has_side_effects() || expr_3.has_side_effects()
+			},
+			Expression::Expressions(exprs, _, _) | Expression::InitializerList(exprs, _, _) => exprs.iter().any(|e| e.has_side_effects()),
+			Expression::ArrayAccess(expr, exprs, _, _) => expr.has_side_effects() || exprs.iter().any(|e| e.has_side_effects()),
+			_ => false
+		}
+	}
+
+	/// Retrieves the priority of the operator that was used to output this expression.
+	/// Returns `None` if the output is always a single unit, such as casts and member access.
+	fn get_output_priority(&self, operators: &OperatorDataStructure) -> Option<i64> {
+		match self {
+			Expression::Infix(_, _, id, _, _) if *id > 9 && *id < AS_OPERATOR_ID => Some(operators["infix"][*id].priority),
+			Expression::Ternary(..) => Some(operators["ternary"][0].priority),
+			_ => None
+		}
+	}
+
+	/// Converts an operand to a string, grouping it with parentheses if it would otherwise bind to the wrong operator.
+	/// This keeps rewritten expressions correct, since they are not grouped like parsed ones.
+	///
+	/// # Arguments
+	///
+	/// * `priority` - The priority of the operator the operand is used with.
+	/// * `group_on_tie` - Whether the operand must be grouped if its operator has the same priority.
+	fn operand_to_string(operand: &Expression, priority: i64, group_on_tie: bool, operators: &OperatorDataStructure, context: &mut Context) -> String {
+		let content = operand.to_string(operators, context);
+		match operand.get_output_priority(operators) {
+			Some(operand_priority) if operand_priority < priority || (operand_priority == priority && group_on_tie) => format!("({})", content),
+			_ => content
+		}
+	}
+
+	/// Wraps the expression with parentheses if it is an infix expression using the operator.
+	fn group_if_infix(self, operator_id: usize) -> Expression {
+		if let Expression::Infix(_, _, id, _, position) = &self {
+			if *id == operator_id {
+				let pos = position.clone();
+				return Expression::Expressions(Rc::new(vec![Rc::new(self)]), VariableType::boolean(), pos);
+			}
+		}
+		self
+	}
+
+	/// Removes pairs of ! operators and any parentheses directly around the result.
+	fn remove_double_negation(&self) -> Expression {
+		match self {
+			Expression::Prefix(expr, 4, _, _) => {
+				if let Expression::Prefix(inner_expr, 4, _, _) = &**expr {
+					return inner_expr.remove_double_negation();
+				}
+			},
+			Expression::Expressions(exprs, _, _) if exprs.len() == 1 => {
+				return exprs[0].remove_double_negation();
+			},
+			_ => ()
+		}
+		self.clone()
+	}
+
 	pub fn reverse_bool(&self) -> Expression {
 		match self {
 			Expression::Prefix(expr, operator_id, _, position) => {
 				if *operator_id == 4 {
-					return (**expr).clone();
+					return expr.remove_double_negation();
 				}
 				return Expression::Prefix(Rc::new(self.clone()), 4, VariableType::boolean(), position.clone());
 			},
@@ -401,14 +906,21 @@ impl Expression {
 						_ => 0
 					}, VariableType::boolean(), position.clone());
 				} else if *operator_id == 27 || *operator_id == 28 {
-					return Expression::Infix(Rc::new(left_expr.reverse_bool()), Rc::new(right_expr.reverse_bool()), if *operator_id == 27 { 28 } else { 27 }, VariableType::boolean(), position.clone());
+					// De Morgan's laws, operands that flipped to the other logical operator are grouped
+					let reversed_id = if *operator_id == 27 { 28 } else { 27 };
+					let left = left_expr.reverse_bool().group_if_infix(*operator_id);
+					let right = right_expr.reverse_bool().group_if_infix(*operator_id);
+					return Expression::Infix(Rc::new(left), Rc::new(right), reversed_id, VariableType::boolean(), position.clone());
 				}
 			},
+			Expression::Expressions(exprs, _, _) if exprs.len() == 1 => {
+				return exprs[0].reverse_bool();
+			},
 			_ => ()
 		}
 
 		// If all else fails, wrap with !
-		let curr_pos = self.get_position().unwrap_or(Position::new("".to_string(), Some(0), 0, None));
+		let curr_pos = self.get_position().unwrap_or(Position::new("".to_string(), Some(1), 0, None));
 		match self {
 			Expression::Expressions(..) |
 			Expression::Value(..) |
@@ -6,14 +6,21 @@
  **********************************************************/
 
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 use crate::declaration_parser::class_declaration::ClassStyle;
 
-use crate::expression::variable_type::{ VariableType, Type };
+use crate::expression::Expression;
+use crate::expression::variable_type::{ VariableType, Type, VarStyle };
 use crate::expression::function_type::FunStyle;
 
 use crate::declaration_parser::function_declaration::FunctionType;
 
+use crate::config_management::LibraryNames;
+
+use crate::context_management::context::Context;
+use crate::context_management::context_manager::ContextManager;
+
 #[derive(Clone, PartialEq)]
 pub enum NumberType {
 	Byte,
@@ -56,6 +63,41 @@ impl NumberType {
 		}
 	}
 
+	/// Returns the bit width and signedness of integer types.
+	pub fn integer_range(&self) -> Option<(usize, bool)> {
+		match self {
+			NumberType::Byte => Some((8, true)),
+			NumberType::UByte => Some((8, false)),
+			NumberType::Short => Some((16, true)),
+			NumberType::UShort => Some((16, false)),
+			NumberType::Int => Some((32, true)),
+			NumberType::UInt => Some((32, false)),
+			NumberType::Long => Some((64, true)),
+			NumberType::ULong => Some((64, false)),
+			NumberType::LongLong => Some((64, true)),
+			NumberType::ULongLong => Some((64, false)),
+			NumberType::Size => Some((64, false)),
+			NumberType::WChar => Some((32, true)),
+			_ => None
+		}
+	}
+
+	/// Checks whether converting an integer of this type to `target` could lose its value.
+	pub fn is_narrowing_to(&self, target: &NumberType) -> bool {
+		match (self.integer_range(), target.integer_range()) {
+			(Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+				if from_signed == to_signed {
+					to_bits < from_bits
+				} else if to_signed {
+					to_bits <= from_bits
+				} else {
+					true
+				}
+			},
+			_ => false
+		}
+	}
+
 	pub fn from_value_text(value: &mut String) -> NumberType {
 		let mut offset = 0;
 		let mut edit = "".to_string();
@@ -74,6 +116,10 @@ impl NumberType {
 		let mut double = false;
 		let mut dot = false;
 
+		let mut exponent = false;
+		let mut exponent_offset = 0;
+		let mut expect_sign = false;
+
 		let mut suffix = false;
 
 		let mut bits = false;
@@ -90,8 +136,19 @@ impl NumberType {
 			rindex -= 1;
 
 			if expect_num {
+				if expect_sign && (c == '+' || c == '-') {
+					expect_sign = false;
+					continue;
+				}
+				expect_sign = false;
 				if !c.is_numeric() {
-					*offset -= 1;
+					if exponent {
+						// An exponent without digits is not part of the number.
+						*offset = exponent_offset;
+						real_number = false;
+					} else {
+						*offset -= 1;
+					}
 					break;
 				} else {
 					expect_num = false;
@@ -107,8 +164,20 @@ impl NumberType {
 					continue;
 				}
 			}
+			if (c == 'e' || c == 'E') && !bits && !hex {
+				if !exponent && !suffix {
+					expect_num = true;
+					expect_sign = true;
+					exponent = true;
+					exponent_offset = *offset;
+					continue;
+				} else {
+					real_number = false;
+					break;
+				}
+			}
 			if c == '.' {
-				if !dot && !suffix {
+				if !dot && !exponent && !suffix {
 					expect_num = true;
 					dot = true;
 					continue;
@@ -146,14 +215,14 @@ impl NumberType {
 			if c == 'l' {
 				long += 1;
 				suffix = true;
-				if long > 2 || (dot && long > 1) {
+				if long > 2 || ((dot || exponent) && long > 1) {
 					real_number = false;
 					break;
 				}
 				continue;
 			}
 			if c == 'u' {
-				if unsigned || dot {
+				if unsigned || dot || exponent {
 					real_number = false;
 					break;
 				}
@@ -172,11 +241,18 @@ impl NumberType {
 			}
 		}
 
+		if expect_num && exponent {
+			*offset = exponent_offset;
+			real_number = false;
+		}
+
 		if !real_number {
 			return NumberType::UnknownNumber;
 		}
 
-		if float && !dot {
+		let floating = dot || exponent;
+
+		if float && !floating {
 			if value_mod.is_some() {
 				(*(value_mod.unwrap())) = format!("{}.0f", &value[0..value.len() - 1]);
 				(*changed_val) = true;
@@ -184,7 +260,7 @@ impl NumberType {
 		} else if double {
 			if value_mod.is_some() {
 				let true_value_mod = value_mod.unwrap();
-				(*true_value_mod) = if !dot { format!("{}.0", &value[0..value.len() - 1]) } else { value[0..value.len() - 1].to_string() };
+				(*true_value_mod) = if !floating { format!("{}.0", &value[0..value.len() - 1]) } else { value[0..value.len() - 1].to_string() };
 				(*changed_val) = true;
 			}
 		}
@@ -197,7 +273,7 @@ impl NumberType {
 					NumberType::LongLong
 				}
 			} else if long == 1 {
-				if dot {
+				if floating {
 					NumberType::LongDouble
 				} else if unsigned {
 					NumberType::ULong
@@ -207,7 +283,7 @@ impl NumberType {
 			} else {
 				if float {
 					NumberType::Float
-				} else if dot {
+				} else if floating {
 					NumberType::Double
 				} else if unsigned {
 					NumberType::UInt
@@ -227,11 +303,11 @@ pub enum StringType {
 }
 
 impl StringType {
-	pub fn to_cpp(&self) -> &'static str {
+	pub fn to_cpp(&self, library: &LibraryNames) -> String {
 		return match self {
-			StringType::ConstCharArray => "const char*",
-			StringType::MutlilineConstCharArray => "const char*",
-			StringType::StringClass => "std::string"
+			StringType::ConstCharArray => "const char*".to_string(),
+			StringType::MutlilineConstCharArray => "const char*".to_string(),
+			StringType::StringClass => library.std_name("string")
 		}
 	}
 }
@@ -244,11 +320,18 @@ pub struct ClassType {
 	pub type_params: Option<Vec<VariableType>>,
 	pub properties: Vec<Property>,
 	pub functions: Vec<Function>,
+	pub constructors: Vec<Function>,
 	pub operators: BTreeMap<usize,Vec<Function>>,
 	pub required_includes: Vec<(String,bool)>
 }
 
 impl ClassType {
+	/// Checks whether every conversion from this class is known.
+	/// Classes cannot declare conversion operators, but abstracts and classes extending unknown types may convert.
+	pub fn has_known_conversions(&self) -> bool {
+		!self.style.is_abstract() && self.extensions.iter().flatten().all(|ext| matches!(ext, Type::Class(cls) if cls.has_known_conversions()))
+	}
+
 	pub fn get_field(&self, name: &str) -> VariableType {
 		for p in &self.properties {
 			if p.name == name {
@@ -268,6 +351,86 @@ impl ClassType {
 		}
 		return VariableType::inferred();
 	}
+
+	/// Finds the result type of an overloaded infix operator applied with a right operand of `right_type`.
+	/// Overloads whose parameter matches the operand are preferred. Relational operators that are not
+	/// overloaded are rewritten in terms of `<=>`, so they result in a boolean if it is overloaded.
+	pub fn get_infix_operator_type(&self, operator_id: usize, right_type: &VariableType) -> Option<VariableType> {
+		if let Some(funcs) = self.operators.get(&(300 + operator_id)) {
+			let func = funcs.iter()
+				.find(|f| f.parameters.len() == 1 && right_type.could_convert_to(&f.parameters[0].prop_type))
+				.or(funcs.first());
+			return func.map(|f| f.return_type.clone());
+		}
+		if (18..=21).contains(&operator_id) && self.operators.contains_key(&317) {
+			return Some(VariableType::boolean());
+		}
+		None
+	}
+
+	/// Finds the constructor that can be called using the provided arguments.
+	/// If the class does not declare any constructors, the arguments are not checked.
+	/// A single argument of this class, or a class derived from it, uses the implicit copy or move constructor.
+	pub fn find_constructor(&self, args: &[Rc<Expression>], context: Option<&Context>, ctx_manager: &mut ContextManager) -> Result<Option<Function>, ConstructorMismatch> {
+		if self.constructors.is_empty() {
+			return Ok(None);
+		}
+		let arg_types = args.iter().map(|arg| arg.get_type()).collect::<Vec<VariableType>>();
+		let converts = |arg: &VariableType, param: &VariableType, ctx_manager: &mut ContextManager| {
+			arg.could_convert_to(param) || context.map(|c| arg.is_derived_from(param, c, ctx_manager)).unwrap_or(false)
+		};
+		if let [arg] = arg_types.as_slice() {
+			if converts(arg, &VariableType::copy(Type::Class(self.clone())), ctx_manager) {
+				return Ok(None);
+			}
+		}
+		let mut arity_matched = false;
+		let mut may_convert = false;
+		for constructor in &self.constructors {
+			let required = constructor.parameters.iter().filter(|p| p.default_value.is_none()).count();
+			if args.len() < required || args.len() > constructor.parameters.len() {
+				continue;
+			}
+			arity_matched = true;
+			let params = arg_types.iter().zip(&constructor.parameters);
+			if params.clone().all(|(arg, param)| converts(arg, &param.prop_type, ctx_manager)) {
+				return Ok(Some(constructor.clone()));
+			}
+			if params.clone().all(|(arg, param)| !arg.is_incompatible_with(&param.prop_type)) {
+				may_convert = true;
+			}
+		}
+		Err(if !arity_matched {
+			ConstructorMismatch::Arity
+		} else if may_convert {
+			ConstructorMismatch::Unverified
+		} else {
+			ConstructorMismatch::Incompatible
+		})
+	}
+}
+
+/// The reason the arguments of a construction do not match a constructor.
+pub enum ConstructorMismatch {
+	Arity,
+	Incompatible,
+	/// The argument types are not known to match, but may convert through constructors or conversion operators.
+	Unverified
+}
+
+impl ConstructorMismatch {
+	pub fn get_message(&self) -> &'static str {
+		match self {
+			ConstructorMismatch::Arity => "no constructor takes this number of arguments",
+			ConstructorMismatch::Incompatible => "no constructor takes arguments of these types",
+			ConstructorMismatch::Unverified => "no constructor is known to take arguments of these types, so they must convert implicitly"
+		}
+	}
+
+	/// Checks whether the construction is certainly invalid, rather than only unverified.
+	pub fn is_error(&self) -> bool {
+		!matches!(self, ConstructorMismatch::Unverified)
+	}
 }
 
 #[derive(Clone, PartialEq)]
@@ -279,12 +442,18 @@ pub struct Property {
 }
 
 impl Property {
-	pub fn to_cpp(&self, is_header: bool) -> String {
+	pub fn to_cpp(&self, is_header: bool, library: &LibraryNames) -> String {
 		let declare_text = if self.is_declare && is_header { format!("class ") } else { "".to_string() };
-		if self.default_value.is_some() && is_header {
-			format!("{}{} {} = {}", declare_text, self.prop_type.to_cpp(), self.name, self.default_value.as_ref().unwrap())
-		} else {
-			format!("{}{} {}", declare_text, self.prop_type.to_cpp(), self.name)
+		match &self.default_value {
+			Some(default_value) if is_header => format!("{}{} {} = {}", declare_text, self.prop_type.to_cpp(library), self.name, default_value),
+			_ => format!("{}{} {}", declare_text, self.prop_type.to_cpp(library), self.name)
+		}
+	}
+
+	pub fn to_cpp_parameter(&self, is_header: bool, library: &LibraryNames) -> String {
+		match &self.default_value {
+			Some(default_value) if is_header => format!("{} {} = {}", self.prop_type.to_cpp_parameter(library), self.name, default_value),
+			_ => format!("{} {}", self.prop_type.to_cpp_parameter(library), self.name)
 		}
 	}
 }
@@ -294,11 +463,20 @@ pub struct Function {
 	pub name: String,
 	pub parameters: Vec<Property>,
 	pub return_type: VariableType,
-	pub styles: Vec<FunStyle>
+	pub styles: Vec<FunStyle>,
+	pub no_discard: bool,
+	pub call_conv: Option<String>,
+	pub self_style: Option<VarStyle>,
+	pub is_const: bool
 }
 
 impl Function {
-	pub fn to_cpp(&self, use_styles: bool, header: bool, class_name: Option<&str>, func_type: &FunctionType) -> String {
+	/// Generates the C++ function type, such as `int(int, int)`.
+	pub fn to_cpp_signature(&self, library: &LibraryNames) -> String {
+		format!("{}({})", self.return_type.to_cpp(library), self.parameters.iter().map(|param| param.prop_type.to_cpp(library)).collect::<Vec<String>>().join(", "))
+	}
+
+	pub fn to_cpp(&self, use_styles: bool, header: bool, class_name: Option<&str>, func_type: &FunctionType, library: &LibraryNames) -> String {
 		let mut style_content = Vec::new();
 		let mut post_style_content = Vec::new();
 		if (func_type.is_normal() || func_type.is_destructor()) && use_styles {
@@ -320,9 +498,15 @@ impl Function {
 				}
 			}
 		}
-		format!("{}{}{}{}({}){}",
+		let self_qualifier = match (&self.self_style, class_name) {
+			(Some(style), Some(_)) => style.to_cpp_self_qualifier().map(|q| format!(" {}", q)).unwrap_or("".to_string()),
+			(None, Some(_)) if self.is_const => " const".to_string(),
+			_ => "".to_string()
+		};
+		format!("{}{}{}{}{}({}){}{}",
 			if style_content.is_empty() { "".to_string() } else { format!("{} ", style_content.join(" ")) },
-			if func_type.is_normal_or_operator() { format!("{} ", self.return_type.to_cpp()) } else { "".to_string() },
+			if func_type.is_normal_or_operator() { format!("{} ", self.return_type.to_cpp(library)) } else { "".to_string() },
+			self.call_conv.as_ref().map(|call_conv| format!("{} ", call_conv)).unwrap_or_default(),
 			if header || class_name.is_none() { "".to_string() } else { format!("{}::", class_name.unwrap()) },
 			if func_type.is_constructor() {
 				class_name.unwrap().to_string()
@@ -333,7 +517,8 @@ impl Function {
 			} else {
 				self.name.clone()
 			},
-			self.parameters.iter().map(|param| param.to_cpp(header)).collect::<Vec<String>>().join(", "),
+			self.parameters.iter().map(|param| param.to_cpp_parameter(header, library)).collect::<Vec<String>>().join(", "),
+			self_qualifier,
 			if post_style_content.is_empty() { "".to_string() } else { format!(" {}", post_style_content.join(" ")) }
 		)
 	}
@@ -14,6 +14,8 @@ use crate::context_management::context::Context;
 use crate::context_management::typing_context::ContextType;
 use crate::context_management::context_manager::ContextManager;
 
+use crate::config_management::LibraryNames;
+
 lazy_static! {
 	pub static ref STYLE_TYPES: Vec<&'static str> = vec!("copy", "ref", "borrow", "move", "ptr", "autoptr", "uniqueptr", "classptr", "let", "ptr2", "ptr3", "ptr4", "ptr5", "ptr6", "ptr7", "ptr8", "ptr9");
 	pub static ref VARIABLE_PROPS: Vec<&'static str> = vec!("const", "constexpr", "constinit", "extern", "mutable", "forever", "thread_local", "volatile", "declare");
@@ -28,7 +30,7 @@ pub struct VariableType {
 }
 
 impl VariableType {
-	pub fn to_cpp(&self) -> String {
+	pub fn to_cpp(&self, library: &LibraryNames) -> String {
 		let mut declare = false;
 		if self.var_properties.is_some() {
 			for prop in self.var_properties.as_ref().unwrap() {
@@ -37,13 +39,41 @@ impl VariableType {
 				}
 			}
 		}
-		return self.var_style.to_cpp(&self.var_type, declare);
+		let result = self.var_style.to_cpp(&self.var_type, declare, library);
+		if self.var_optional && !self.var_type.is_inferred() {
+			return format!("{}<{}>", library.std_name("optional"), result);
+		}
+		result
+	}
+
+	/// Converts the type to C++ for use as a function parameter.
+	/// Borrowed `text` parameters become `std::string_view` so any string-like argument is accepted.
+	pub fn to_cpp_parameter(&self, library: &LibraryNames) -> String {
+		if self.is_string_view_parameter() {
+			return library.std_name("string_view");
+		}
+		self.to_cpp(library)
+	}
+
+	pub fn is_const(&self) -> bool {
+		if let Some(props) = &self.var_properties {
+			return props.contains(&VarProps::Const) || props.contains(&VarProps::Constexpr);
+		}
+		false
+	}
+
+	pub fn is_string_view_parameter(&self) -> bool {
+		self.var_style == VarStyle::Borrow &&
+			matches!(self.var_type, Type::String(StringType::ConstCharArray) | Type::String(StringType::MutlilineConstCharArray))
 	}
 
 	pub fn resolve(&mut self, context: &Context, ctx_manager: &mut ContextManager) -> bool {
-		match &self.var_type {
+		match &mut self.var_type {
 			Type::Undeclared(names) => {
 				if names.len() == 1 {
+					if let Some(ContextType::TypeParameter) = context.typing.get_item(names.first().unwrap(), None, None, false) {
+						return false;
+					}
 					let context_type = context.module.get_item(names.first().unwrap(), Some(context), Some(ctx_manager), false);
 					if context_type.is_some() {
 						if let ContextType::Class(cls) = context_type.unwrap() {
@@ -51,13 +81,48 @@ impl VariableType {
 							return true;
 						}
 					}
+				} else if names.len() == 2 {
+					// Unlike modules, namespaces exist in C++, so the class keeps its qualifier.
+					if let Some(ContextType::Class(mut cls)) = context.find_namespace_item(&names[0], &names[1], ctx_manager) {
+						cls.name = format!("{}::{}", names[0], cls.name);
+						self.var_type = Type::Class(cls);
+						return true;
+					}
+					if let Some(ContextType::Class(cls)) = context.find_module_item(&names[0], &names[1], ctx_manager) {
+						self.var_type = Type::Class(cls);
+						return true;
+					}
 				}
 			},
+			// The type arguments are resolved, but the resulting type itself is still undeclared.
+			Type::UndeclaredWParams(_, type_args) | Type::Tuple(type_args) => {
+				for t in type_args {
+					t.resolve(context, ctx_manager);
+				}
+			},
+			Type::Array(element_type, _) | Type::InitializerList(element_type) => {
+				element_type.resolve(context, ctx_manager);
+			},
 			_ => ()
 		}
 		return false;
 	}
 
+	/// Retrieves the type of the elements accessed with `[]` for arrays and known standard containers.
+	pub fn get_element_type(&self) -> Option<VariableType> {
+		match &self.var_type {
+			Type::Array(element_type, _) => Some((**element_type).clone()),
+			Type::UndeclaredWParams(names, type_args) => {
+				match names.last().map(|n| n.as_str()) {
+					Some("vector") | Some("deque") | Some("array") | Some("span") | Some("valarray") => type_args.first().cloned(),
+					Some("map") | Some("unordered_map") => type_args.get(1).cloned(),
+					_ => None
+				}
+			},
+			_ => None
+		}
+	}
+
 	pub fn from_type_style(info: (VarStyle, Type, bool)) -> VariableType {
 		return VariableType {
 			var_type: info.1,
@@ -130,6 +195,18 @@ impl VariableType {
 		};
 	}
 
+	/// Replaces `This` with the type of the class it is used within.
+	pub fn replace_this(&mut self, class_type: &Type) {
+		let is_this = match &self.var_type {
+			Type::This => true,
+			Type::Undeclared(names) => names.len() == 1 && names[0] == "This",
+			_ => false
+		};
+		if is_this {
+			self.var_type = class_type.clone();
+		}
+	}
+
 	pub fn initializer_list(var_type: VariableType) -> VariableType {
 		return VariableType {
 			var_type: Type::InitializerList(Box::new(var_type)),
@@ -253,16 +330,103 @@ impl VariableType {
 		};
 	}
 
-	pub fn compare_types(&self, other: &VariableType) -> Option<VariableType> {
+	pub fn is_optional_value(&self) -> bool {
+		if self.var_optional {
+			return true;
+		}
+		if let Type::UndeclaredWParams(names, type_args) = &self.var_type {
+			return type_args.len() == 1 && names.last().map(|n| n == "optional").unwrap_or(false);
+		}
+		false
+	}
+
+	/// The type contained by an optional, or the type itself otherwise.
+	pub fn optional_inner(&self) -> VariableType {
+		if let Type::UndeclaredWParams(_, type_args) = &self.var_type {
+			if self.is_optional_value() && !self.var_optional {
+				return type_args[0].clone();
+			}
+		}
+		let mut result = self.clone();
+		result.var_optional = false;
+		result
+	}
+
+	/// The type produced by null-safe access `?.` to a member of this type.
+	/// Pointers and optionals can already be empty, anything else becomes optional.
+	pub fn to_null_safe(&self) -> VariableType {
+		if self.is_inferred() || self.is_optional_value() || self.var_style.is_ptr().unwrap_or(false) {
+			return self.clone();
+		}
+		let mut result = self.clone();
+		result.var_optional = true;
+		result
+	}
+
+	/// Finds the type both types can be used as.
+	/// If they are related classes with the same style, the closest common base class is used.
+	pub fn compare_types(&self, other: &VariableType, context: Option<&Context>, ctx_manager: &mut ContextManager) -> Option<VariableType> {
 		if self == other || (self.is_inferred() && !other.is_inferred()) {
 			return Some(self.clone());
 		} else if !self.is_inferred() && other.is_inferred() {
 			return Some(other.clone());
 		}
-		return None;
+		if context.is_none() || self.var_style != other.var_style {
+			return None;
+		}
+		let left_ancestors = self.get_class_ancestors(context.unwrap(), ctx_manager);
+		let right_ancestors = other.get_class_ancestors(context.unwrap(), ctx_manager);
+		let common = left_ancestors.into_iter().find(|left| right_ancestors.iter().any(|right| right.name == left.name))?;
+		let mut result = self.clone();
+		result.var_type = Type::Class(common);
+		Some(result)
+	}
+
+	/// Checks whether a function returning this type can override one returning `base`.
+	/// Pointers and references to a derived class are covariant with those to its base classes.
+	pub fn is_covariant_with(&self, base: &VariableType, context: &Context, ctx_manager: &mut ContextManager) -> bool {
+		if self.is_inferred() || base.is_inferred() || self.to_cpp(&context.library) == base.to_cpp(&context.library) {
+			return true;
+		}
+		let covariant_style = matches!(self.var_style, VarStyle::Ptr(1) | VarStyle::Ref | VarStyle::Borrow);
+		if !covariant_style || self.var_style != base.var_style {
+			return false;
+		}
+		self.is_derived_from(base, context, ctx_manager)
 	}
 
-	pub fn convert_between_styles(&self, other: &VariableType, content: &str) -> Option<String> {
+	/// Checks whether this type is a class that is, or derives from, the class of `base`.
+	pub fn is_derived_from(&self, base: &VariableType, context: &Context, ctx_manager: &mut ContextManager) -> bool {
+		let base_class = match base.get_class_ancestors(context, ctx_manager).into_iter().next() {
+			Some(cls) => cls,
+			None => return false
+		};
+		self.get_class_ancestors(context, ctx_manager).iter().any(|cls| cls.name == base_class.name)
+	}
+
+	/// Retrieves the class of this type followed by its base classes, ordered from closest to furthest.
+	/// Returns an empty list if this is not a class.
+	fn get_class_ancestors(&self, context: &Context, ctx_manager: &mut ContextManager) -> Vec<ClassType> {
+		let mut result: Vec<ClassType> = Vec::new();
+		let mut pending = std::collections::VecDeque::new();
+		pending.push_back(self.var_type.clone());
+		while let Some(next) = pending.pop_front() {
+			let mut var_type = VariableType::copy(next);
+			var_type.resolve(context, ctx_manager);
+			if let Some(cls_type) = var_type.var_type.get_class_type() {
+				if result.iter().any(|cls| cls.name == cls_type.name) {
+					continue;
+				}
+				if let Some(extensions) = &cls_type.extensions {
+					pending.extend(extensions.iter().cloned());
+				}
+				result.push(cls_type);
+			}
+		}
+		result
+	}
+
+	pub fn convert_between_styles(&self, other: &VariableType, content: &str, library: &LibraryNames) -> Option<String> {
 		return match self.var_style {
 			VarStyle::Copy |
 			VarStyle::Ref |
@@ -272,10 +436,11 @@ impl VariableType {
 					VarStyle::Copy |
 					VarStyle::Ref |
 					VarStyle::Borrow => Some(content.to_string()),
-					VarStyle::Move => Some(format!("std::move({})", content)),
+					VarStyle::Move => Some(format!("{}({})", library.std_name("move"), content)),
 					VarStyle::Ptr(size) => Some(format!("{}{}", String::from_utf8(vec![b'&'; size]).unwrap(), content)),
-					VarStyle::AutoPtr => Some(format!("std::make_shared<{}>({})", other.to_cpp(), content)),
-					VarStyle::UniquePtr => Some(format!("std::make_unique<{}>({})", other.to_cpp(), content)),
+					VarStyle::AutoPtr => Some(format!("{}<{}>({})", library.std_name("make_shared"), other.to_cpp(library), content)),
+					VarStyle::UniquePtr => Some(format!("{}<{}>({})", library.std_name("make_unique"), other.to_cpp(library), content)),
+					VarStyle::ClassPtr => Some(format!("{}(new {}({}))", other.to_cpp(library), other.var_type.to_cpp(false, library), content)),
 					_ => None
 				}
 			},
@@ -294,8 +459,10 @@ impl VariableType {
 							Some(content.to_string())
 						}
 					},
-					VarStyle::AutoPtr => Some(format!("std::make_shared<{}>({}{})", other.to_cpp(), stars, content)),
-					VarStyle::UniquePtr => Some(format!("std::make_unique<{}>({}{})", other.to_cpp(), stars, content)),
+					VarStyle::AutoPtr => Some(format!("{}<{}>({}{})", library.std_name("make_shared"), other.to_cpp(library), stars, content)),
+					VarStyle::UniquePtr => Some(format!("{}<{}>({}{})", library.std_name("make_unique"), other.to_cpp(library), stars, content)),
+					// The reference count is stored within the object, so a raw pointer can be shared.
+					VarStyle::ClassPtr => Some(format!("{}({}{})", other.to_cpp(library), &stars[1..], content)),
 					_ => None
 				}
 			},
@@ -304,13 +471,14 @@ impl VariableType {
 					VarStyle::Copy |
 					VarStyle::Ref |
 					VarStyle::Borrow => Some(format!("*{}", content)),
-					VarStyle::Move => Some(format!("std::move(*{})", content)),
+					VarStyle::Move => Some(format!("{}(*{})", library.std_name("move"), content)),
 					VarStyle::Ptr(size) => if size == 1 {
 						Some(format!("{}.get()", content))
 					} else {
 						Some(format!("{}{}.get()", String::from_utf8(vec![b'&'; size - 1]).unwrap(), content))
 					},
 					VarStyle::AutoPtr => Some(content.to_string()),
+					VarStyle::ClassPtr => Some(format!("{}({}.get())", other.to_cpp(library), content)),
 					_ => None
 				}
 			},
@@ -319,7 +487,7 @@ impl VariableType {
 					VarStyle::Copy |
 					VarStyle::Ref |
 					VarStyle::Borrow => Some(format!("*{}", content)),
-					VarStyle::Move => Some(format!("std::move(*{})", content)),
+					VarStyle::Move => Some(format!("{}(*{})", library.std_name("move"), content)),
 					VarStyle::Ptr(size) => if size == 1 {
 						Some(format!("{}.get()", content))
 					} else {
@@ -329,6 +497,23 @@ impl VariableType {
 					_ => None
 				}
 			},
+			VarStyle::ClassPtr => {
+				match other.var_style {
+					VarStyle::Copy |
+					VarStyle::Ref |
+					VarStyle::Borrow => Some(format!("*{}", content)),
+					VarStyle::Move => Some(format!("{}(*{})", library.std_name("move"), content)),
+					VarStyle::Ptr(size) => if size == 1 {
+						Some(format!("{}.get()", content))
+					} else {
+						Some(format!("{}{}.get()", String::from_utf8(vec![b'&'; size - 1]).unwrap(), content))
+					},
+					// The deleter keeps a reference, so the object lives as long as the shared pointer.
+					VarStyle::AutoPtr => Some(format!("{}({}.get(), [owner = {}]({}*) {{}})", other.to_cpp(library), content, content, other.var_type.to_cpp(false, library))),
+					VarStyle::ClassPtr => Some(content.to_string()),
+					_ => None
+				}
+			},
 			_ => None
 		}
 	}
@@ -363,50 +548,120 @@ impl VariableType {
 		return result;
 	}
 
+	/// Checks whether a value of this type can be passed where `other` is expected
+	/// without converting between value and pointer styles.
 	pub fn types_match(&self, other: &VariableType) -> bool {
+		self.values_match(other) && (other.is_inferred() || self.var_style.can_bind_to(&other.var_style))
+	}
+
+	/// Checks whether both types are identical, including their styles.
+	pub fn types_match_strict(&self, other: &VariableType) -> bool {
+		self.values_match(other) && (other.is_inferred() || self.var_style == other.var_style)
+	}
+
+	fn values_match(&self, other: &VariableType) -> bool {
 		if other.is_inferred() {
 			return true;
 		}
 		return self.var_type == other.var_type && self.var_optional == other.var_optional;
 	}
 
+	/// Checks whether a value of this type could be passed where `other` is expected.
+	/// Unresolved types and arithmetic conversions, including those from booleans and enums, are assumed to be valid.
+	pub fn could_convert_to(&self, other: &VariableType) -> bool {
+		if self.is_inferred() || other.is_inferred() {
+			return true;
+		}
+		if (self.is_arithmetic() || self.is_enum()) && other.is_arithmetic() {
+			return true;
+		}
+		match (&self.var_type, &other.var_type) {
+			(Type::Undeclared(_), _) | (_, Type::Undeclared(_)) |
+			(Type::UndeclaredWParams(..), _) | (_, Type::UndeclaredWParams(..)) |
+			(Type::String(_), Type::String(StringType::StringClass)) => true,
+			_ => self.var_type == other.var_type
+		}
+	}
+
+	/// Checks whether a value of this type can never be passed where `other` is expected.
+	/// Only numbers, booleans and text have conversions that are fully known, so other types are never incompatible.
+	/// Class values are known to be incompatible with them unless the class may declare its own conversions.
+	pub fn is_incompatible_with(&self, other: &VariableType) -> bool {
+		let is_known = |var_type: &VariableType| var_type.is_arithmetic() || matches!(var_type.var_type, Type::String(_));
+		let is_known_class = self.var_style.is_ptr() == Some(false) && matches!(&self.var_type, Type::Class(cls) if cls.has_known_conversions());
+		(is_known(self) || is_known_class) && is_known(other) && !self.could_convert_to(other)
+	}
+
+	fn is_arithmetic(&self) -> bool {
+		self.is_number() || self.is_boolean()
+	}
+
+	fn is_enum(&self) -> bool {
+		matches!(&self.var_type, Type::Class(cls) if cls.style.is_enum())
+	}
+
 	pub fn resolve_quantum_function(&self, params: Rc<Vec<Rc<Expression>>>) -> Result<VariableType, &'static str> {
 		if self.is_quantum_function() {
 			if let Type::QuantumFunction(funcs) = &self.var_type {
-				let mut possible_functions = funcs.clone();
+				let mut possible_functions: Vec<Function> = funcs.iter().filter(|f| {
+					params.len() <= f.parameters.len() && f.parameters[params.len()..].iter().all(|p| p.default_value.is_some())
+				}).cloned().collect();
 				let mut index = 0;
 				for p in params.iter() {
 					if possible_functions.is_empty() {
-						return Err("function does not exist");
+						return Err("function that takes these parameters doesn't exist");
 					}
 					let param_type = p.get_type();
 					let mut new_possible_functions = Vec::new();
+					let mut style_matches = Vec::new();
 					for f in possible_functions {
 						if index < f.parameters.len() {
 							let prop_type = &f.parameters[index].prop_type;
-							if param_type.types_match(prop_type) {
+							if param_type.values_match(prop_type) {
+								if param_type.types_match(prop_type) {
+									style_matches.push(f.clone());
+								}
 								new_possible_functions.push(f.clone());
 							}
 						}
 					}
-					possible_functions = new_possible_functions;
+					// Overloads that don't require a style conversion are preferred.
+					possible_functions = if style_matches.is_empty() { new_possible_functions } else { style_matches };
 					index += 1;
 				}
-				if possible_functions.len() == 1 {
+				if possible_functions.is_empty() {
+					return Err("function that takes these parameters doesn't exist");
+				} else if possible_functions.len() == 1 || Self::share_return_type(&possible_functions) {
 					return Ok(VariableType::function(possible_functions.remove(0)));
 				} else {
-					return Err("function that takes these parameters doesn't exist");
+					return Err("call to overloaded function is ambiguous");
 				}
 			}
 		}
 		return Err("not a quantum function");
 	}
 
+	/// Checks whether every overload returns the same type, in which case the
+	/// return type of a call is known even if the exact overload is not.
+	fn share_return_type(funcs: &[Function]) -> bool {
+		funcs.iter().all(|f| f.return_type.is_equal(&funcs[0].return_type))
+	}
+
 	pub fn get_function_call_return(&self) -> Option<VariableType> {
 		return match &self.var_type {
 			Type::Function(func_type) => {
 				Some(func_type.return_type.clone())
 			},
+			Type::QuantumFunction(funcs) => {
+				if !funcs.is_empty() && Self::share_return_type(funcs) {
+					Some(funcs[0].return_type.clone())
+				} else {
+					None
+				}
+			},
+			Type::Class(cls_type) => {
+				cls_type.operators.get(&400).and_then(|funcs| funcs.first()).map(|func| func.return_type.clone())
+			},
 			_ => None
 		}
 	}
@@ -420,6 +675,10 @@ impl VariableType {
 		return false;
 	}
 
+	pub fn is_boolean(&self) -> bool {
+		matches!(self.var_type, Type::Boolean)
+	}
+
 	pub fn is_void(&self) -> bool {
 		if let Type::Void = &self.var_type {
 			return true;
@@ -458,9 +717,9 @@ impl VariableType {
 		return true;
 	}
 
-	pub fn to_string(&self) -> String {
+	pub fn to_string(&self, library: &LibraryNames) -> String {
 		return format!("---\nCPP: {}\nSTYLE: {}\nPROPS: {}\nOPTIONAL: {}\n",
-			self.to_cpp(), self.var_style.get_name(), if self.var_properties.is_some() {
+			self.to_cpp(library), self.var_style.get_name(), if self.var_properties.is_some() {
 				self.var_properties.as_ref().unwrap().len()
 			} else {
 				0
@@ -479,6 +738,7 @@ pub enum Type {
 	Function(Box<Function>),
 	QuantumFunction(Vec<Function>),
 	InitializerList(Box<VariableType>),
+	Array(Box<VariableType>, usize),
 	Tuple(Vec<VariableType>),
 	Inferred,
 	Undeclared(Vec<String>),
@@ -488,16 +748,16 @@ pub enum Type {
 }
 
 impl Type {
-	pub fn to_cpp(&self, declare: bool) -> String {
+	pub fn to_cpp(&self, declare: bool, library: &LibraryNames) -> String {
 		return match self {
 			Type::Unknown(name) => name.clone(),
 			Type::Void => "void".to_string(),
 			Type::Boolean => "bool".to_string(),
 			Type::Number(num_type) => num_type.to_cpp().to_string(),
-			Type::String(string_type) => string_type.to_cpp().to_string(),
+			Type::String(string_type) => string_type.to_cpp(library),
 			Type::Class(class_type) => {
 				if class_type.style.is_abstract() {
-					class_type.extensions.as_ref().unwrap().first().as_ref().unwrap().to_cpp(declare)
+					class_type.extensions.as_ref().unwrap().first().as_ref().unwrap().to_cpp(declare, library)
 				} else if declare {
 					format!("class {}", class_type.name.clone())
 				} else {
@@ -508,22 +768,25 @@ impl Type {
 				let params = &func.parameters;
 				let mut params_output = "".to_string();
 				for i in 0..params.len() {
-					params_output += &params[i].prop_type.to_cpp();
+					params_output += &params[i].prop_type.to_cpp(library);
 					if i < params.len() - 1 {
 						params_output += ", ";
 					}
 				}
-				format!("std::function<{}({})>", func.return_type.to_cpp(), func.parameters.iter().map(|param| param.prop_type.to_cpp()).collect::<Vec<String>>().join(", "))
+				format!("{}<{}>", library.std_name("function"), func.to_cpp_signature(library))
 			},
 			Type::QuantumFunction(funcs) => {
 				if !funcs.is_empty() {
-					Type::Function(Box::new(funcs.first().unwrap().clone())).to_cpp(false)
+					Type::Function(Box::new(funcs.first().unwrap().clone())).to_cpp(false, library)
 				} else {
 					"".to_string()
 				}
 			},
 			Type::InitializerList(init_type) => {
-				format!("std::initializer_list<{}>", init_type.to_cpp())
+				format!("{}<{}>", library.std_name("initializer_list"), init_type.to_cpp(library))
+			},
+			Type::Array(element_type, size) => {
+				format!("{}<{}, {}>", library.std_name("array"), element_type.to_cpp(library), size)
 			}
 			Type::Tuple(types) => {
 				let mut is_inferred = false;
@@ -536,7 +799,7 @@ impl Type {
 				if is_inferred {
 					"auto".to_string()
 				} else {
-					format!("std::tuple<{}>", types.iter().map(|t| t.to_cpp()).collect::<Vec<String>>().join(", "))
+					format!("{}<{}>", library.std_name("tuple"), types.iter().map(|t| t.to_cpp(library)).collect::<Vec<String>>().join(", "))
 				}
 			}
 			Type::Inferred => "auto".to_string(),
@@ -562,7 +825,7 @@ impl Type {
 				let mut i = 0;
 				loop {
 					if i < type_args.len() {
-						result += type_args[i].to_cpp().as_str();
+						result += type_args[i].to_cpp(library).as_str();
 						i += 1;
 						if i < type_args.len() {
 							result += ", ";
@@ -604,6 +867,7 @@ impl Type {
 			Type::Function(_) => Some("nullptr"),
 			Type::QuantumFunction(_) => Some("nullptr"),
 			Type::InitializerList(_) => Some("{}"),
+			Type::Array(_, _) => Some("{}"),
 			Type::Tuple(_) => None,
 			Type::Inferred => None,
 			Type::Undeclared(_) => None,
@@ -695,33 +959,64 @@ impl VarStyle {
 		return self;
 	}
 
-	pub fn to_cpp(&self, var_type: &Type, declare: bool) -> String {
+	pub fn to_cpp(&self, var_type: &Type, declare: bool, library: &LibraryNames) -> String {
 		if var_type.is_inferred() {
 			return "auto".to_string();
 		}
 		return match self {
-			VarStyle::Copy => var_type.to_cpp(declare),
-			VarStyle::Ref => format!("{}&", var_type.to_cpp(declare)),
+			VarStyle::Copy => var_type.to_cpp(declare, library),
+			VarStyle::Ref => format!("{}&", var_type.to_cpp(declare, library)),
 			VarStyle::Borrow => {
 				if let Type::String(str_type) = var_type {
 					if let StringType::ConstCharArray = str_type {
-						format!("{}&", var_type.to_cpp(declare))
+						format!("{}&", var_type.to_cpp(declare, library))
 					} else {
-						format!("const {}&", var_type.to_cpp(declare))
+						format!("const {}&", var_type.to_cpp(declare, library))
 					}
 				} else {
-					format!("const {}&", var_type.to_cpp(declare))
+					format!("const {}&", var_type.to_cpp(declare, library))
 				}
 			},
-			VarStyle::Move => format!("{}&&", var_type.to_cpp(declare)),
+			VarStyle::Move => format!("{}&&", var_type.to_cpp(declare, library)),
 			VarStyle::Ptr(amount) => {
 				let stars = if *amount < 1 { 1 } else if *amount > 9 { 9 } else { *amount };
-				format!("{}{}", var_type.to_cpp(declare), String::from_utf8(vec![b'*'; stars]).unwrap_or("*".to_string()))
+				if let Type::Function(func) = var_type {
+					// The declarator syntax for function pointers surrounds the name, so the pointer type is spelled with add_pointer_t.
+					let extra_stars = String::from_utf8(vec![b'*'; stars - 1]).unwrap_or("".to_string());
+					format!("{}<{}>{}", library.std_name("add_pointer_t"), func.to_cpp_signature(library), extra_stars)
+				} else {
+					format!("{}{}", var_type.to_cpp(declare, library), String::from_utf8(vec![b'*'; stars]).unwrap_or("*".to_string()))
+				}
+			},
+			VarStyle::AutoPtr => format!("{}<{}>", library.std_name("shared_ptr"), var_type.to_cpp(declare, library)),
+			VarStyle::UniquePtr => format!("{}<{}>", library.std_name("unique_ptr"), var_type.to_cpp(declare, library)),
+			VarStyle::ClassPtr => format!("{}<{}>", library.class_ptr_type, var_type.to_cpp(declare, library)),
+			_ => var_type.to_cpp(declare, library)
+		}
+	}
+
+	/// Checks whether a value with this style can bind to a parameter of the `other`
+	/// style without being converted, such as a `copy` passed to a `borrow`.
+	pub fn can_bind_to(&self, other: &VarStyle) -> bool {
+		match (self, other) {
+			(VarStyle::Unknown, _) | (_, VarStyle::Unknown) |
+			(VarStyle::Infer, _) | (_, VarStyle::Infer) => true,
+			(VarStyle::Copy, _) | (VarStyle::Ref, _) | (VarStyle::Borrow, _) | (VarStyle::Move, _) => {
+				matches!(other, VarStyle::Copy | VarStyle::Ref | VarStyle::Borrow | VarStyle::Move)
 			},
-			VarStyle::AutoPtr => format!("std::shared_ptr<{}>", var_type.to_cpp(declare)),
-			VarStyle::UniquePtr => format!("std::unique_ptr<{}>", var_type.to_cpp(declare)),
-			VarStyle::ClassPtr => format!("{}*", var_type.to_cpp(declare)),
-			_ => var_type.to_cpp(declare)
+			_ => self == other
+		}
+	}
+
+	/// Gets the ref-qualifier for a method declared with `where self: <style>`.
+	/// Methods called through a pointer always have an lvalue receiver.
+	pub fn to_cpp_self_qualifier(&self) -> Option<&'static str> {
+		match self {
+			VarStyle::Ref => Some("&"),
+			VarStyle::Borrow => Some("const&"),
+			VarStyle::Move => Some("&&"),
+			VarStyle::Ptr(1) => Some("&"),
+			_ => None
 		}
 	}
 
@@ -6,6 +6,7 @@
  **********************************************************/
 
 use std::path::Path;
+use std::collections::BTreeMap;
 
 use path_slash::PathExt;
 
@@ -53,3 +54,59 @@ pub fn get_all_files(dir: &str, file_extension: &str) -> Option<Vec<String>> {
 pub fn get_all_tasty_files(dir: &str) -> Option<Vec<String>> {
 	return get_all_files(dir, "tasty");
 }
+
+/// Hashes the contents of a file for the build cache.
+/// FNV-1a is used so hashes remain stable between compiler builds.
+///
+/// # Arguments
+///
+/// * `content` - The contents to hash.
+///
+/// # Return
+///
+/// The 64-bit hash of the contents.
+pub fn hash_content(content: &str) -> u64 {
+	let mut hash: u64 = 0xcbf29ce484222325;
+	for byte in content.bytes() {
+		hash ^= byte as u64;
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+	hash
+}
+
+/// Reads the build cache that maps source files to the hash of their contents.
+/// Each line of the cache contains a hash followed by the path it belongs to.
+///
+/// # Arguments
+///
+/// * `path` - The path of the cache file.
+///
+/// # Return
+///
+/// The hashes stored in the cache. If the cache doesn't exist, the map is empty.
+pub fn read_build_cache(path: &Path) -> BTreeMap<String,u64> {
+	let mut result = BTreeMap::new();
+	if let Ok(content) = std::fs::read_to_string(path) {
+		for line in content.lines() {
+			if let Some((hash, file)) = line.split_once(' ') {
+				if let Ok(hash) = u64::from_str_radix(hash, 16) {
+					result.insert(file.to_string(), hash);
+				}
+			}
+		}
+	}
+	result
+}
+
+/// Writes the build cache that maps source files to the hash of their contents.
+///
+/// # Arguments
+///
+/// * `path` - The path of the cache file.
+/// * `hashes` - The hashes to store.
+pub fn write_build_cache(path: &Path, hashes: &BTreeMap<String,u64>) {
+	let content = hashes.iter().map(|(file, hash)| format!("{:016x} {}\n", hash, file)).collect::<String>();
+	if let Err(error) = std::fs::write(path, content) {
+		println!("Could not write build cache: {}\n{}", path.to_string_lossy(), error);
+	}
+}
@@ -30,6 +30,399 @@
  *      --out:out
  *      --out:"My Output"
  *
+ * ----------
+ *
+ * [ config-dir ]
+ *   Determines the directory the configuration files,
+ *   such as "operators.json", are read from.
+ *   By default, the "config" directory next to the
+ *   executable or in the working directory is used.
+ *
+ *   [ examples ]
+ *      --config-dir:config
+ *      --config-dir:"/usr/share/tasty_fresh"
+ *
+ * ----------
+ *
+ * [ source-ext / header-ext ]
+ *   Determines the file extensions used for the
+ *   generated source and header files.
+ *   By default, "cpp" and "hpp" are used.
+ *
+ *   [ examples ]
+ *      --source-ext:cc
+ *      --header-ext:hh
+ *
+ * ----------
+ *
+ * [ std-namespace ]
+ *   Determines the namespace used for generated
+ *   standard library references such as "std::tuple".
+ *   By default, "std" is used.
+ *
+ *   [ examples ]
+ *      --std-namespace:eastl
+ *
+ * ----------
+ *
+ * [ include-prefix ]
+ *   Prepends a path to the includes of generated headers,
+ *   meaning the self-include of each source file and the
+ *   includes of imported modules. Used when the headers
+ *   are found through an include root.
+ *
+ *   [ examples ]
+ *      --include-prefix:myproj/
+ *
+ * ----------
+ *
+ * [ emit-test-main ]
+ *   Generates "tasty_test_main" in each output directory,
+ *   a source file with a main function that runs every
+ *   function marked with the @Test attribute.
+ *
+ *   [ examples ]
+ *      --emit-test-main
+ *
+ * ----------
+ *
+ * [ emit-bench-main ]
+ *   Generates "tasty_bench_main" in each output directory,
+ *   a source file with a main function that times every
+ *   function marked with the @Bench attribute.
+ *
+ *   [ examples ]
+ *      --emit-bench-main
+ *
+ * ----------
+ *
+ * [ emit-compile-commands ]
+ *   Generates "compile_commands.json" in each output
+ *   directory, a compilation database with an entry for
+ *   every generated source file. The compiler command
+ *   is based on the "target" option and includes the
+ *   output directories.
+ *
+ *   [ examples ]
+ *      --emit-compile-commands
+ *
+ * ----------
+ *
+ * [ dump-includes ]
+ *   Generates "tasty_includes.json" in each output
+ *   directory, a graph of the modules and the imports
+ *   between them. Each edge is marked "header" if the
+ *   import is included by the module's header, or
+ *   "source" otherwise.
+ *
+ *   [ examples ]
+ *      --dump-includes
+ *
+ * ----------
+ *
+ * [ checked-casts ]
+ *   Makes the "as" operator check narrowing integer
+ *   conversions at runtime, throwing std::out_of_range
+ *   when the value does not fit the target type.
+ *
+ *   [ examples ]
+ *      --checked-casts
+ *
+ * ----------
+ *
+ * [ allow-reinterpret ]
+ *   Allows the "#*" reinterpret cast operator everywhere.
+ *   Otherwise, it may only be used in functions with the
+ *   @Unsafe attribute.
+ *
+ *   [ examples ]
+ *      --allow-reinterpret
+ *
+ * ----------
+ *
+ * [ immutable-let ]
+ *   Makes local "let" variables const unless they are
+ *   declared with "let mut". Modifying a const variable
+ *   is reported as an error.
+ *
+ *   [ examples ]
+ *      --immutable-let
+ *
+ * ----------
+ *
+ * [ include-order ]
+ *   Determines the order of the include groups in
+ *   generated headers: "system-first" (default),
+ *   "local-first", or "alphabetical", which sorts the
+ *   includes within each group. A module can override
+ *   this with %IncludeSystemFirst%, %IncludeLocalFirst%,
+ *   or %IncludeAlphabetical%.
+ *
+ *   [ examples ]
+ *      --include-order:local-first
+ *
+ * ----------
+ *
+ * [ target ]
+ *   Sets the compiler the output is written for: "gcc"
+ *   (default), "clang", or "msvc". This determines the
+ *   spelling of compiler-specific output such as @Packed.
+ *   @ThreadSafe and @GuardedBy are only output for "clang".
+ *
+ *   [ examples ]
+ *      --target:msvc
+ *
+ * ----------
+ *
+ * [ line-ending ]
+ *   Sets the line ending used in generated files: "lf"
+ *   (default) or "crlf".
+ *
+ *   [ examples ]
+ *      --line-ending:crlf
+ *
+ * ----------
+ *
+ * [ default-visibility ]
+ *   Sets the symbol visibility of functions and classes
+ *   without @Export or @Hidden: "default" (default) or
+ *   "hidden". Hidden symbols are not exported from
+ *   shared libraries.
+ *
+ *   [ examples ]
+ *      --default-visibility:hidden
+ *
+ * ----------
+ *
+ * [ brace-style ]
+ *   Sets where opening braces are placed in generated
+ *   code: "k&r" (default) keeps them at the end of the
+ *   line, and "allman" places them on their own line.
+ *   Allman braces are not used with aligned lines.
+ *
+ *   [ examples ]
+ *      --brace-style:allman
+ *
+ * ----------
+ *
+ * [ align-assignments ]
+ *   Aligns the "=" of consecutive single-line variable
+ *   declarations and assignments in generated source
+ *   files that share the same indentation.
+ *
+ *   [ examples ]
+ *      --align-assignments
+ *
+ * ----------
+ *
+ * [ strict ]
+ *   Reports warnings for code that is valid but unsafe,
+ *   such as variables left uninitialized with "uninit"
+ *   and @NoReturn functions that may return.
+ *
+ *   [ examples ]
+ *      --strict
+ *
+ * ----------
+ *
+ * [ constraint-style ]
+ *   Determines how "where" constraints on generic
+ *   functions are written: "requires" (default) uses a
+ *   C++20 requires clause, while "enable-if" uses
+ *   std::enable_if_t with "::value" for older standards.
+ *
+ *   [ examples ]
+ *      --constraint-style:enable-if
+ *
+ * ----------
+ *
+ * [ max-errors ]
+ *   Sets the number of errors a single file may report
+ *   before it is aborted (default 20). Further errors in
+ *   the file are not reported.
+ *
+ *   [ examples ]
+ *      --max-errors:5
+ *
+ * ----------
+ *
+ * [ incremental ]
+ *   Skips transpiling files that are unchanged since the
+ *   last incremental run, along with the files they
+ *   import. The hashes of the sources are stored in
+ *   ".tasty-cache" in the first output directory. Every
+ *   file is transpiled when "emit-test-main" is used.
+ *
+ *   [ examples ]
+ *      --incremental
+ *
+ * ----------
+ *
+ * [ verbose ]
+ *   Prints how long each file took to parse and
+ *   transpile, along with the number of declarations,
+ *   classes, and functions it contains.
+ *
+ *   [ examples ]
+ *      --verbose
+ *
+ * ----------
+ *
+ * [ preserve-member-order ]
+ *   Declares the members of each class section in the
+ *   order they were written instead of grouping the
+ *   functions and variables separately.
+ *
+ *   [ examples ]
+ *      --preserve-member-order
+ *
+ * ----------
+ *
+ * [ fold-constants ]
+ *   Replaces "+", "-", "*", and "/" operations on
+ *   numeric literals with their result, such as
+ *   "2 + 3 * 4" with "14". Divisions by zero and results
+ *   that don't fit the expression's type are not folded.
+ *
+ *   [ examples ]
+ *      --fold-constants
+ *
+ * ----------
+ *
+ * [ module-init ]
+ *   Moves the initializers of module variables into a
+ *   generated "__init_module_" function for each module.
+ *   "tasty_module_init" is generated in each output
+ *   directory with "tasty_init_modules", which calls them
+ *   with imported modules first. Call it at the start of
+ *   main to avoid static initialization order problems.
+ *
+ *   [ examples ]
+ *      --module-init
+ *
+ * ----------
+ *
+ * [ emit-mocks ]
+ *   Generates a "<Name>Mock" class for each abstract
+ *   with @Mock. Each of its functions records its name
+ *   in "calls" and returns a default value.
+ *
+ *   [ examples ]
+ *      --emit-mocks
+ *
+ * ----------
+ *
+ * [ warn-unused ]
+ *   Prints a warning for each local variable that is
+ *   never read after it is declared. Variables whose
+ *   initializers may have side effects, such as function
+ *   calls, are not reported.
+ *
+ *   [ examples ]
+ *      --warn-unused
+ *
+ * ----------
+ *
+ * [ keep-comments ]
+ *   Outputs the comments written above each module-level
+ *   declaration as C++ comments above the generated code
+ *   for that declaration.
+ *
+ *   [ examples ]
+ *      --keep-comments
+ *
+ * ----------
+ *
+ * [ infer-const-methods ]
+ *   Makes class methods const if their bodies cannot
+ *   modify the object.
+ *   Methods are only changed if every member access can
+ *   be checked, and virtual methods are never changed.
+ *
+ *   [ examples ]
+ *      --infer-const-methods
+ *
+ * ----------
+ *
+ * [ auto-constexpr ]
+ *   Makes module functions constexpr, defining them in
+ *   the header, if they only take and return numbers or
+ *   booleans and their bodies only use their own values
+ *   and call other constexpr functions.
+ *
+ *   [ examples ]
+ *      --auto-constexpr
+ *
+ * ----------
+ *
+ * [ json-type ]
+ *   Sets the JSON type used by the functions generated
+ *   for classes with @Serialize (default "nlohmann::json").
+ *
+ *   [ examples ]
+ *      --json-type:nlohmann::ordered_json
+ *
+ * ----------
+ *
+ * [ json-include ]
+ *   Sets the header included for the JSON type used by
+ *   @Serialize (default "nlohmann/json.hpp").
+ *
+ *   [ examples ]
+ *      --json-include:json.hpp
+ *
+ * ----------
+ *
+ * [ class-ptr-type ]
+ *   Sets the intrusive smart pointer template used for
+ *   the "classptr" style (default "boost::intrusive_ptr").
+ *   Classes used with it must provide their own reference
+ *   count, such as by extending
+ *   "boost::intrusive_ref_counter".
+ *
+ *   [ examples ]
+ *      --class-ptr-type:my::ref_ptr
+ *
+ * ----------
+ *
+ * [ class-ptr-include ]
+ *   Sets the header included for the "classptr" style
+ *   (default "boost/intrusive_ptr.hpp").
+ *
+ *   [ examples ]
+ *      --class-ptr-include:ref_ptr.hpp
+ *
+ * ----------
+ *
+ * [ stdin ]
+ *   Reads a single source file from stdin instead of
+ *   the source directories. The optional value names
+ *   the module (default "main.tasty"). Errors are
+ *   printed to stderr.
+ *
+ *   [ examples ]
+ *      --stdin
+ *      --stdin:util.tasty
+ *
+ * ----------
+ *
+ * [ stdout ]
+ *   Used with "stdin" to write the generated header and
+ *   source to stdout instead of the output directories.
+ *   The two are separated by a "// --- source ---" line.
+ *
+ *   [ examples ]
+ *      --stdout
+ *
+ * ----------
+ *
+ * [ single-header ]
+ *   Used with "stdout" to write a single header that
+ *   contains the source after its declarations.
+ *
+ *   [ examples ]
+ *      --single-header
+ *
  **********************************************************/
 
 #![allow(dead_code)]
@@ -41,6 +434,7 @@ mod expression;
 mod scope_parser;
 
 mod file_system;
+mod string_util;
 mod transpiler;
 
 #[macro_use]
@@ -53,14 +447,18 @@ use context_management::static_extension::StaticExtension;
 use declaration_parser::parser::Parser;
 use declaration_parser::module_declaration::{ ModuleDeclaration, DeclarationType };
 use declaration_parser::attributes::Attributes;
+use declaration_parser::include_declaration::IncludeDeclaration;
+use declaration_parser::cpp_transpiler::CPPTranspiler;
 
 use expression::variable_type::{ VariableType, Type };
 
-use config_management::ConfigData;
+use config_management::{ ConfigData, IncludeOrder, CompilerTarget, LineEnding, ConstraintStyle, Visibility, BraceStyle };
 
 use file_system::get_all_tasty_files;
 
-use transpiler::Transpiler;
+use string_util::{ escape_cpp_string, parse_string_parameter };
+
+use transpiler::{ Transpiler, VarFuncDeclarations };
 
 use context_management::context::Context;
 
@@ -71,12 +469,22 @@ use std::collections::BTreeMap;
 use std::path::Path;
 use std::ffi::OsStr;
 
+use std::time::{ Duration, Instant };
+
+use std::io::Read;
+
 use regex::Regex;
 
 use colored::*;
 
 use path_slash::PathExt;
 
+/// The build cache entry storing the hash of the arguments, as output depends on them as well.
+const CACHE_ARGUMENTS_KEY: &str = "--arguments";
+
+/// The line separating the header from the source when both are written to stdout.
+const STDOUT_SOURCE_MARKER: &str = "// --- source ---";
+
 /// Parses arguments with `--KEY` or `--KEY:VALUE` format.
 ///
 /// # Arguments
@@ -88,7 +496,7 @@ use path_slash::PathExt;
 /// An instance of BTreeMap containing the key/value pairs
 /// passed to the compiler.
 fn parse_arguments(args: Args) -> BTreeMap<String,Vec<String>> {
-	let arg_regexp = Regex::new(r"^--(\w[\w\d]*):(.*)$").unwrap();
+	let arg_regexp = Regex::new(r"^--(\w[\w\d\-]*):(.*)$").unwrap();
 	let mut result = BTreeMap::new();
 	let mut index = 0;
 	for arg in args {
@@ -208,6 +616,205 @@ fn get_output_dirs(arguments: &BTreeMap<String,Vec<String>>) -> Option<Vec<Strin
 	return Some(output_dirs);
 }
 
+/// Retrieves a file extension provided as an argument, such as `--source-ext:cc`.
+/// The extension may be provided with or without a leading dot.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+/// * `key` - The name of the argument.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(ext))` if it is valid, or `Err(())` if it is invalid.
+fn get_file_extension(arguments: &BTreeMap<String,Vec<String>>, key: &str) -> Result<Option<String>,()> {
+	match arguments.get(key) {
+		Some(values) => {
+			let value = match values.last() {
+				Some(v) => v.trim_start_matches('.').to_string(),
+				None => "".to_string()
+			};
+			if value.is_empty() {
+				println!("{}{}{}{}", "An extension must be provided using ".bright_red(), format!("--{}:", key).yellow(), "EXT".green(), ".".bright_red());
+				return Err(());
+			} else if value.contains('/') || value.contains('\\') {
+				println!("{}{}{}", "Extension ".bright_red(), value.yellow(), " cannot contain path separators!".bright_red());
+				return Err(());
+			}
+			Ok(Some(value))
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the include ordering provided as an argument, such as `--include-order:local-first`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(order))` if it is valid, or `Err(())` if it is invalid.
+fn get_include_order(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<IncludeOrder>,()> {
+	match arguments.get("include-order").and_then(|values| values.last()) {
+		Some(value) => {
+			match IncludeOrder::new(value) {
+				Some(order) => Ok(Some(order)),
+				None => {
+					println!("{}{}{}{}", "Unknown include order ".bright_red(), value.yellow(), ", expected ".bright_red(), "system-first, local-first, or alphabetical".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the compiler target provided as an argument, such as `--target:msvc`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(target))` if it is valid, or `Err(())` if it is invalid.
+fn get_target(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<CompilerTarget>,()> {
+	match arguments.get("target").and_then(|values| values.last()) {
+		Some(value) => {
+			match CompilerTarget::new(value) {
+				Some(target) => Ok(Some(target)),
+				None => {
+					println!("{}{}{}{}", "Unknown target ".bright_red(), value.yellow(), ", expected ".bright_red(), "gcc, clang, or msvc".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the line ending provided as an argument, such as `--line-ending:crlf`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(ending))` if it is valid, or `Err(())` if it is invalid.
+fn get_line_ending(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<LineEnding>,()> {
+	match arguments.get("line-ending").and_then(|values| values.last()) {
+		Some(value) => {
+			match LineEnding::new(value) {
+				Some(ending) => Ok(Some(ending)),
+				None => {
+					println!("{}{}{}{}", "Unknown line ending ".bright_red(), value.yellow(), ", expected ".bright_red(), "lf or crlf".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the default visibility provided as an argument, such as `--default-visibility:hidden`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(visibility))` if it is valid, or `Err(())` if it is invalid.
+fn get_default_visibility(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<Visibility>,()> {
+	match arguments.get("default-visibility").and_then(|values| values.last()) {
+		Some(value) => {
+			match Visibility::new(value) {
+				Some(visibility) => Ok(Some(visibility)),
+				None => {
+					println!("{}{}{}{}", "Unknown visibility ".bright_red(), value.yellow(), ", expected ".bright_red(), "default or hidden".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the brace style provided as an argument, such as `--brace-style:allman`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(style))` if it is valid, or `Err(())` if it is invalid.
+fn get_brace_style(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<BraceStyle>,()> {
+	match arguments.get("brace-style").and_then(|values| values.last()) {
+		Some(value) => {
+			match BraceStyle::new(value) {
+				Some(style) => Ok(Some(style)),
+				None => {
+					println!("{}{}{}{}", "Unknown brace style ".bright_red(), value.yellow(), ", expected ".bright_red(), "k&r or allman".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the constraint style provided as an argument, such as `--constraint-style:enable-if`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(style))` if it is valid, or `Err(())` if it is invalid.
+fn get_constraint_style(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<ConstraintStyle>,()> {
+	match arguments.get("constraint-style").and_then(|values| values.last()) {
+		Some(value) => {
+			match ConstraintStyle::new(value) {
+				Some(style) => Ok(Some(style)),
+				None => {
+					println!("{}{}{}{}", "Unknown constraint style ".bright_red(), value.yellow(), ", expected ".bright_red(), "requires or enable-if".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
+/// Retrieves the error limit provided as an argument, such as `--max-errors:5`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+///
+/// # Return
+///
+/// `Ok(None)` if the argument was not provided, `Ok(Some(limit))` if it is valid, or `Err(())` if it is invalid.
+fn get_max_errors(arguments: &BTreeMap<String,Vec<String>>) -> Result<Option<usize>,()> {
+	match arguments.get("max-errors").and_then(|values| values.last()) {
+		Some(value) => {
+			match value.parse::<usize>() {
+				Ok(limit) if limit > 0 => Ok(Some(limit)),
+				_ => {
+					println!("{}{}{}{}", "Invalid error limit ".bright_red(), value.yellow(), ", expected ".bright_red(), "a positive number".green());
+					Err(())
+				}
+			}
+		},
+		None => Ok(None)
+	}
+}
+
 /// Parses the input source file into its declaration data.
 ///
 /// # Arguments
@@ -220,21 +827,50 @@ fn get_output_dirs(arguments: &BTreeMap<String,Vec<String>>) -> Option<Vec<Strin
 /// The `ModuleDeclaration` for the file is returned.
 fn parse_source_file(file: &str, source_location: &str, config_data: &ConfigData, module_contexts: &mut ContextManager, parser: &mut Parser, global_context: &mut GlobalContext) -> ModuleDeclaration {
 	let content = std::fs::read_to_string(file).expect("Could not read source file.");
+	parse_source_content(file, content, source_location, config_data, module_contexts, parser, global_context)
+}
+
+/// Parses Tasty Fresh source code that has already been read, such as from stdin.
+/// The `file` is only used to name the module and its messages.
+fn parse_source_content(file: &str, content: String, source_location: &str, config_data: &ConfigData, module_contexts: &mut ContextManager, parser: &mut Parser, global_context: &mut GlobalContext) -> ModuleDeclaration {
 	if !file.ends_with(".tasty") { panic!("File is not a .tasty. You should be ashamed."); }
+	context_management::begin_file_errors(0);
 	*parser = Parser::new(content);
 	let mut curr_index = 0;
 	let mut context = Context::new();
-	let mut module_declaration = ModuleDeclaration::new(parser, file, &config_data.operators);
+	context.checked_casts = config_data.checked_casts;
+	context.allow_reinterpret = config_data.allow_reinterpret;
+	context.immutable_let = config_data.immutable_let;
+	context.brace_style = config_data.brace_style;
+	context.library = config_data.library.clone();
+	let mut module_declaration = ModuleDeclaration::new(parser, file, &config_data.operators, &config_data.library);
 	let mut attribute_class_indexes = Vec::new();
+	let mut imports = Vec::new();
+	let mut header_imports = Vec::new();
 	for declaration in &mut module_declaration.declarations {
 		match declaration {
-			DeclarationType::Function(d, _) => {
-				d.declaration_id = context.module.add_function(d.name.clone(), d.to_function(&parser.content), Some(module_contexts));
+			DeclarationType::Function(d, attributes) => {
+				d.declaration_id = context.module.add_function(d.name.clone(), d.to_typing_function(&parser.content, attributes), Some(module_contexts));
+				if d.is_meta() {
+					context.meta_functions.insert(d.name.clone(), d.to_meta_function(parser, file, config_data, module_contexts));
+				}
 				for p in &d.parameters {
-					context.register_type(&p.0);
+					context.register_parameter_type(&p.0);
 				}
 				context.register_type(&d.return_type);
 			},
+			DeclarationType::Import(d, _) => {
+				if d.is_pub {
+					context.reexport_module(d.path.clone());
+				}
+				if d.is_header || d.is_pub {
+					header_imports.push(d.path.clone());
+				}
+				imports.push(d.path.clone());
+			},
+			DeclarationType::Namespace(d, _) => {
+				context.namespaces.push((d.name.clone(), d.is_inline));
+			},
 			DeclarationType::Variable(d, _) => {
 				d.declaration_id = context.module.add_variable(d.name.clone(), d.var_type.clone(), Some(module_contexts));
 				context.register_type(&d.var_type);
@@ -279,7 +915,7 @@ fn parse_source_file(file: &str, source_location: &str, config_data: &ConfigData
 					if let DeclarationType::Function(d2, _) = extend {
 						context.static_extends.insert(d2.name.clone(),
 							StaticExtension::new(
-								format!("{}_{}", d.make_name(), d2.name),
+								format!("{}_{}", d.make_name(&config_data.library), d2.name),
 								d2.to_function(&parser.content),
 								VariableType::copy(d.refurbish_type.clone())
 							)
@@ -295,12 +931,9 @@ fn parse_source_file(file: &str, source_location: &str, config_data: &ConfigData
 		}
 		curr_index += 1;
 	}
-	let access_file_path = if file.starts_with(&source_location) {
-		&file[source_location.len() + 1..file.len() - 6]
-	} else {
-		&file[..file.len() - 6]
-	};
+	let access_file_path = get_access_file_path(file, source_location);
 	module_contexts.add_context(access_file_path.to_string(), context);
+	module_contexts.set_imports(access_file_path.to_string(), imports, header_imports);
 
 	let mut attribute_classes_processed = 0;
 	for attribute_index in attribute_class_indexes {
@@ -310,27 +943,61 @@ fn parse_source_file(file: &str, source_location: &str, config_data: &ConfigData
 		}
 		attribute_classes_processed += 1;
 	}
-
-	return module_declaration;
+
+	module_declaration.error_count = context_management::file_error_count();
+	return module_declaration;
+}
+
+/// Retrieves the module path of a source file, which is its path relative to the source directory without the extension.
+///
+/// # Arguments
+///
+/// * `file` - The relative or absolute path to the source file.
+/// * `source_location` - The source directory the file was found in.
+///
+/// # Return
+///
+/// The module path, such as `a/util`.
+fn get_access_file_path<'a>(file: &'a str, source_location: &str) -> &'a str {
+	if file.starts_with(source_location) {
+		&file[source_location.len() + 1..file.len() - 6]
+	} else {
+		&file[..file.len() - 6]
+	}
 }
 
-/// Transpiles the input source file into C++ and outputs it to the provided `output_dirs`.
+/// Checks whether the output files of a source file exist in every output directory.
 ///
 /// # Arguments
 ///
 /// * `file` - The relative or absolute path to the source file.
-/// * `output_dirs` - The list of output directories to write the C++ files to.
+/// * `output_dirs` - The list of output directories the C++ files are written to.
 /// * `config_data` - The configuration data for the transpiler.
+fn outputs_exist(file: &str, output_dirs: &[String], config_data: &ConfigData) -> bool {
+	output_dirs.iter().all(|dir| {
+		let path = Path::new(dir).join(file);
+		path.with_extension(&config_data.source_ext).exists() && path.with_extension(&config_data.header_ext).exists()
+	})
+}
+
+/// The lines generated for a module by `generate_output_lines`.
+struct OutputLines {
+	header_lines: Vec<String>,
+	source_lines: Vec<String>,
+	header_include_line: Option<usize>,
+	declarations_are_empty: bool,
+	header_is_empty: bool
+}
+
+/// Transpiles the parsed module into the lines of its header and source files.
+/// The line the source file should include its header at is returned alongside them,
+/// as the include path depends on where the files are written.
 ///
 /// # Return
 ///
-/// If successful, `true` is returned; otherwise `false`.
-fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<String>, config_data: &ConfigData, module_contexts: &mut ContextManager, module_declaration: &mut ModuleDeclaration, parser: &mut Parser, global_context: &mut GlobalContext) -> bool {
-	let access_file_path = if file.starts_with(&source_location) {
-		&file[source_location.len() + 1..file.len() - 6]
-	} else {
-		&file[..file.len() - 6]
-	};
+/// The generated lines. If there were errors, `None` is returned.
+fn generate_output_lines(file: &str, source_location: &str, config_data: &ConfigData, module_contexts: &mut ContextManager, module_declaration: &mut ModuleDeclaration, parser: &mut Parser, global_context: &mut GlobalContext) -> Option<OutputLines> {
+	let access_file_path = get_access_file_path(file, source_location);
 	{
 		/*let context = module_contexts.get_context(access_file_path);
 		let typing = &mut context.typing;
@@ -338,11 +1005,28 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 		*/
 	}
 
+	context_management::begin_file_errors(module_declaration.error_count);
 	let mut transpile_context = Transpiler::new(file, access_file_path, config_data, module_contexts, parser);
 	transpile_context.parse_declarations(&mut module_declaration.declarations, global_context, None, None);
+	if config_data.module_init {
+		transpile_context.add_module_init(global_context);
+	}
+	transpile_context.close_namespaces();
+	transpile_context.add_lazy_includes();
+	let has_errors = module_declaration.error_count > 0 || transpile_context.has_errors();
+	if config_data.align_assignments {
+		transpile_context.align_assignments();
+	}
 
-	if !transpile_context.output_lines.is_empty() {
-		if transpile_context.header_include_line.is_none() {
+	let declarations_are_empty = transpile_context.class_declarations.is_empty() && transpile_context.declarations.is_empty();
+	let header_is_empty = declarations_are_empty &&
+		transpile_context.header_system_includes.is_empty() &&
+		transpile_context.header_local_includes.is_empty() &&
+		transpile_context.module_contexts.get_context_immut(access_file_path).headers.headers.is_empty();
+	if header_is_empty {
+		transpile_context.header_include_line = None;
+	} else if !transpile_context.output_lines.is_empty()
+		&& transpile_context.header_include_line.is_none() {
 			if !transpile_context.output_lines[0].is_empty() {
 				transpile_context.output_lines.insert(0, "".to_string());
 			}
@@ -351,13 +1035,10 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 			}
 			transpile_context.header_include_line = Some(0);
 		}
-	}
 
-	let declarations_are_empty = transpile_context.class_declarations.is_empty() && transpile_context.declarations.is_empty();
 	let mut header_lines: Vec<String> = Vec::new();
 	{
-		let file_path = Path::new(file);
-		let marco_name = file_path.file_stem().unwrap().to_str().unwrap().to_uppercase() + "_TASTYFILE";
+		let marco_name = get_include_guard_name(access_file_path);
 		if config_data.pragma_guard {
 			header_lines.push("#pragma once".to_string());
 		} else {
@@ -365,26 +1046,37 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 			header_lines.push("#define ".to_string() + &marco_name);
 		}
 		header_lines.push("".to_string());
-		let context_headers = &transpile_context.module_contexts.get_context(access_file_path).headers;
-		if !context_headers.is_empty() || !transpile_context.header_system_includes.is_empty() {
-			for head in &context_headers.headers {
-				header_lines.push(format!("#include <{}>", head.path));
-			}
-			for head_path in &transpile_context.header_system_includes {
-				header_lines.push(format!("#include <{}>", head_path));
-			}
-			header_lines.push("".to_string());
+		let context = transpile_context.module_contexts.get_context(access_file_path);
+		let include_order = context.include_order.unwrap_or(config_data.include_order);
+		let mut system_includes = Vec::new();
+		let mut local_includes = Vec::new();
+		for head in &context.headers.headers {
+			system_includes.push(format!("#include <{}>", head.path));
+		}
+		for head_path in &transpile_context.header_system_includes {
+			system_includes.push(IncludeDeclaration::wrap_with_condition(format!("#include <{}>", head_path), transpile_context.header_include_conditions.get(head_path)));
 		}
-		if !transpile_context.header_local_includes.is_empty() {
-			for head_path in &transpile_context.header_local_includes {
-				header_lines.push(format!("#include \"{}\"", head_path));
+		for head_path in &transpile_context.header_local_includes {
+			local_includes.push(IncludeDeclaration::wrap_with_condition(format!("#include \"{}\"", head_path), transpile_context.header_include_conditions.get(head_path)));
+		}
+		push_include_groups(&mut header_lines, system_includes, local_includes, include_order);
+		if !transpile_context.namespaces.is_empty() {
+			for namespace in &transpile_context.namespaces {
+				header_lines.push(namespace.to_cpp());
 			}
 			header_lines.push("".to_string());
 		}
 		transpile_context.declarations.export_to_lines(&mut header_lines, 0, true);
-		for cls in transpile_context.class_declarations {
+		for mut cls in transpile_context.class_declarations {
 			header_lines.push(cls.0);
-			if !cls.1.is_empty() || !cls.2.is_empty() {
+			if config_data.preserve_member_order {
+				cls.1.append(cls.2);
+				if !cls.1.is_empty() {
+					header_lines.push("public:".to_string());
+					cls.1.export_in_source_order(&mut header_lines, 1);
+					header_lines.pop();
+				}
+			} else if !cls.1.is_empty() || !cls.2.is_empty() {
 				header_lines.push("public:".to_string());
 				if !cls.1.is_empty() {
 					cls.1.export_to_lines(&mut header_lines, 1, false);
@@ -396,10 +1088,23 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 			}
 			if !cls.3.is_empty() {
 				header_lines.push("private:".to_string());
-				cls.3.export_to_lines(&mut header_lines, 1, false);
+				if config_data.preserve_member_order {
+					cls.3.export_in_source_order(&mut header_lines, 1);
+				} else {
+					cls.3.export_to_lines(&mut header_lines, 1, false);
+				}
 				header_lines.pop();
 			}
 			header_lines.push("};".to_string());
+			if let Some(class_suffix) = cls.4 {
+				header_lines.push(class_suffix);
+			}
+			header_lines.push("".to_string());
+		}
+		if !transpile_context.namespaces.is_empty() {
+			for _ in &transpile_context.namespaces {
+				header_lines.push("}".to_string());
+			}
 			header_lines.push("".to_string());
 		}
 		if !config_data.pragma_guard {
@@ -407,29 +1112,64 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 		}
 	}
 
+	if has_errors {
+		return None;
+	}
+
+	Some(OutputLines {
+		header_lines,
+		source_lines: std::mem::take(&mut transpile_context.output_lines),
+		header_include_line: transpile_context.header_include_line,
+		declarations_are_empty,
+		header_is_empty
+	})
+}
+
+/// Outputs the lines generated for the source file to the provided `output_dirs`.
+///
+/// # Arguments
+///
+/// * `file` - The relative or absolute path to the source file.
+/// * `output_dirs` - The list of output directories to write the C++ files to.
+/// * `config_data` - The configuration data for the transpiler.
+/// * `output` - The lines returned by `generate_output_lines`.
+///
+/// # Return
+///
+/// If successful, `true` is returned; otherwise `false`.
+fn write_output_files(file: &str, source_location: &str, output_dirs: &Vec<String>, config_data: &ConfigData, output: Option<OutputLines>) -> bool {
+	let OutputLines { header_lines, source_lines: mut output_lines, header_include_line, declarations_are_empty, header_is_empty } = match output {
+		Some(output) => output,
+		None => {
+			println!("{}{}", "Skipped writing output due to errors: ".bright_red(), file.yellow());
+			return false;
+		}
+	};
+
 	for dir in output_dirs {
 		let path = Path::new(dir).join(file);
 		let path_str = path.to_slash();
 		if path_str.is_some() {
 			let path_str_unwrap = path_str.unwrap();
 			let path_base = path_str_unwrap[..(path_str_unwrap.len() - path.extension().and_then(OsStr::to_str).unwrap_or("").len())].to_string();
-			let header_path = path_base.clone() + (if config_data.hpp_headers { "hpp" } else { "h" });
-			if transpile_context.header_include_line.is_some() {
-				insert_output_line(&mut transpile_context.output_lines, format!("#include \"{}\"",
+			let header_path = path_base.clone() + &config_data.header_ext;
+			if let Some(include_line) = header_include_line {
+				insert_output_line(&mut output_lines, format!("#include \"{}{}\"",
+				config_data.include_prefix,
 				if header_path.starts_with(format!("./{}/", source_location).as_str()) {
 					&header_path[source_location.len() + 3..]
 				} else if header_path.starts_with(format!("{}/", source_location).as_str()) {
 					&header_path[source_location.len() + 1..]
 				} else {
 					&header_path
-				}).as_str(), transpile_context.header_include_line.unwrap(), 1);
+				}).as_str(), include_line, 1);
 			}
-			let full_source_path = path_base + "cpp";
+			let full_source_path = path_base + &config_data.source_ext;
 			let full_header_path = header_path;
 
 			let full_source_path_obj = Path::new(&full_source_path);
 			let full_header_path_obj = Path::new(&full_header_path);
-			if transpile_context.output_lines.is_empty() &&
+			if output_lines.is_empty() &&
 				declarations_are_empty &&
 				!full_source_path_obj.exists() &&
 				!full_header_path_obj.exists() {
@@ -453,7 +1193,7 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 			}
 
 			let source_exists = Path::new(&full_source_path).exists();
-			let content_to_write_source = transpile_context.output_lines.join("\n");
+			let content_to_write_source = config_data.line_ending.join(&output_lines);
 			let original_source_content = if source_exists { std::fs::read_to_string(&full_source_path) } else { Ok("".to_string()) };
 			if !source_exists || original_source_content.is_ok() {
 				if !source_exists || original_source_content.unwrap() != content_to_write_source {
@@ -464,14 +1204,14 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 				}
 			}
 
-			let header_exists = Path::new(&full_header_path).exists();
-			let content_to_write_header = header_lines.join("\n");
-			let original_header_content = if header_exists { std::fs::read_to_string(&full_header_path) } else { Ok("".to_string()) };
-			if !header_exists || original_header_content.is_ok() {
-				if !header_exists || original_header_content.unwrap() != content_to_write_header {
-					let header_write = std::fs::write(&full_header_path, content_to_write_header);
-					if !header_write.is_ok() {
-						println!("Could not write to file: {}\n{}", full_header_path, header_write.err().unwrap());
+			// Modules without declarations or header includes have nothing for other files to include.
+			if !header_is_empty {
+				let header_exists = Path::new(&full_header_path).exists();
+				let content_to_write_header = config_data.line_ending.join(&header_lines);
+				let is_changed = !header_exists || std::fs::read_to_string(&full_header_path).is_ok_and(|content| content != content_to_write_header);
+				if is_changed {
+					if let Err(e) = std::fs::write(&full_header_path, content_to_write_header) {
+						println!("Could not write to file: {}\n{}", full_header_path, e);
 					}
 				}
 			}
@@ -483,11 +1223,350 @@ fn transpile_source_file(file: &str, source_location: &str, output_dirs: &Vec<St
 	return true;
 }
 
+/// Transpiles a single source file read from stdin.
+/// The output is written to stdout if `--stdout` is provided; otherwise it is written to the `output_dirs`.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments map returned by `parse_arguments`.
+/// * `output_dirs` - The list of output directories to write the C++ files to.
+/// * `config_data` - The configuration data for the transpiler.
+///
+/// # Return
+///
+/// If successful, `true` is returned; otherwise `false`.
+fn transpile_stdin(arguments: &BTreeMap<String,Vec<String>>, output_dirs: &Vec<String>, config_data: &ConfigData) -> bool {
+	let file = arguments.get("stdin").and_then(|values| values.last()).map(|name| name.as_str()).unwrap_or("main.tasty");
+	if !file.ends_with(".tasty") {
+		eprintln!("{}{}{}", "The name provided to ".bright_red(), "--stdin:".yellow(), "must end with \".tasty\".".bright_red());
+		return false;
+	}
+
+	let mut content = String::new();
+	if let Err(e) = std::io::stdin().read_to_string(&mut content) {
+		eprintln!("{}{}", "Could not read from stdin: ".bright_red(), e);
+		return false;
+	}
+
+	let source_location = ".";
+	let mut module_contexts = ContextManager::new();
+	let mut global_context = GlobalContext::new();
+	let mut parser = Parser::new("".to_string());
+	let mut module_declaration = parse_source_content(file, content, source_location, config_data, &mut module_contexts, &mut parser, &mut global_context);
+
+	if !arguments.contains_key("stdout") {
+		let output = generate_output_lines(file, source_location, config_data, &mut module_contexts, &mut module_declaration, &mut parser, &mut global_context);
+		return write_output_files(file, source_location, output_dirs, config_data, output);
+	}
+
+	let OutputLines { mut header_lines, source_lines: mut output_lines, header_include_line, .. } = match generate_output_lines(file, source_location, config_data, &mut module_contexts, &mut module_declaration, &mut parser, &mut global_context) {
+		Some(output) => output,
+		None => {
+			eprintln!("{}{}", "Skipped writing output due to errors: ".bright_red(), file.yellow());
+			return false;
+		}
+	};
+
+	let lines = if arguments.contains_key("single-header") {
+		let insert_index = if config_data.pragma_guard { header_lines.len() } else { header_lines.len() - 1 };
+		let source_lines = output_lines.into_iter().skip_while(|line| line.is_empty()).collect::<Vec<String>>();
+		if !source_lines.is_empty() {
+			header_lines.splice(insert_index..insert_index, source_lines.into_iter().chain(std::iter::once("".to_string())));
+		}
+		header_lines
+	} else {
+		if let Some(include_line) = header_include_line {
+			let header_path = get_access_file_path(file, source_location).to_string() + "." + &config_data.header_ext;
+			insert_output_line(&mut output_lines, format!("#include \"{}{}\"", config_data.include_prefix, header_path).as_str(), include_line, 1);
+		}
+		header_lines.push(STDOUT_SOURCE_MARKER.to_string());
+		header_lines.append(&mut output_lines);
+		header_lines
+	};
+	println!("{}", config_data.line_ending.join(&lines));
+	true
+}
+
+/// Formats a duration in milliseconds for the verbose output.
+fn format_duration(duration: Duration) -> String {
+	format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Prints the timings and declaration counts of a source file for `--verbose`.
+///
+/// # Arguments
+///
+/// * `file` - The relative or absolute path to the source file.
+/// * `module_declaration` - The parsed declarations of the file.
+/// * `parse_time` - How long the file took to parse.
+/// * `transpile_time` - How long the file took to transpile, or `None` if it was unchanged.
+fn print_file_stats(file: &str, module_declaration: &ModuleDeclaration, parse_time: Duration, transpile_time: Option<Duration>) {
+	let counts = module_declaration.count_declarations();
+	println!("{}{}", file.yellow(), ":".bright_blue());
+	println!("{}{}", "   parse time:     ".bright_blue(), format_duration(parse_time).green());
+	println!("{}{}", "   transpile time: ".bright_blue(), match transpile_time {
+		Some(time) => format_duration(time).green(),
+		None => "skipped (unchanged)".green()
+	});
+	println!("{}{}{}{}{}{}", "   declarations:   ".bright_blue(), counts.declarations.to_string().green(),
+		", classes: ".bright_blue(), counts.classes.to_string().green(),
+		", functions: ".bright_blue(), counts.functions.to_string().green());
+}
+
+/// Generates the include guard macro for a source file from its path relative to the source directory.
+/// Using the whole path prevents collisions between files that share a name in different directories.
+///
+/// # Arguments
+///
+/// * `access_file_path` - The relative path of the file without its extension, such as `a/util`.
+///
+/// # Return
+///
+/// The macro name, such as `A_UTIL_TASTYFILE`.
+fn get_include_guard_name(access_file_path: &str) -> String {
+	let sanitized: String = access_file_path.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' }).collect();
+	sanitized + "_TASTYFILE"
+}
+
+/// Generates a source file containing a `main` function that runs every function marked with `@Test`.
+/// Each test passes unless it throws, and the process returns the number of failed tests.
+///
+/// # Arguments
+///
+/// * `output_dirs` - The list of output directories to write the runner to.
+/// * `config_data` - The configuration data for the transpiler.
+/// * `global_context` - The global context containing the registered tests.
+fn emit_test_main(output_dirs: &Vec<String>, config_data: &ConfigData, global_context: &GlobalContext) {
+	let mut lines = Vec::new();
+	lines.push("#include <cstdio>".to_string());
+	lines.push("#include <exception>".to_string());
+	lines.push("".to_string());
+	let mut included = Vec::new();
+	for test in &global_context.tests {
+		if !included.contains(&test.2) {
+			lines.push(format!("#include \"{}\"", test.2));
+			included.push(test.2.clone());
+		}
+	}
+	lines.push("".to_string());
+	lines.push("int main() {".to_string());
+	lines.push("\tint failed = 0;".to_string());
+	for test in &global_context.tests {
+		lines.push("\ttry {".to_string());
+		lines.push(format!("\t\t{}();", test.1));
+		lines.push(format!("\t\tstd::printf(\"[PASS] %s\\n\", \"{}\");", escape_cpp_string(&test.0)));
+		lines.push("\t} catch(...) {".to_string());
+		lines.push("\t\tfailed++;".to_string());
+		lines.push(format!("\t\tstd::printf(\"[FAIL] %s\\n\", \"{}\");", escape_cpp_string(&test.0)));
+		lines.push("\t}".to_string());
+	}
+	lines.push(format!("\tstd::printf(\"%d of {} tests passed\\n\", {} - failed);", global_context.tests.len(), global_context.tests.len()));
+	lines.push("\treturn failed;".to_string());
+	lines.push("}".to_string());
+
+	let content = config_data.line_ending.join(&lines);
+	for dir in output_dirs {
+		let path = Path::new(dir).join(format!("tasty_test_main.{}", config_data.source_ext));
+		let original_content = std::fs::read_to_string(&path);
+		if original_content.is_err() || original_content.unwrap() != content {
+			let result = std::fs::write(&path, &content);
+			if result.is_err() {
+				println!("Could not write to file: {}\n{}", path.to_string_lossy(), result.err().unwrap());
+			}
+		}
+	}
+}
+
+/// Generates a source file containing a `main` function that times every function marked with `@Bench`.
+/// Each benchmark is run once and its duration is printed in microseconds.
+///
+/// # Arguments
+///
+/// * `output_dirs` - The list of output directories to write the runner to.
+/// * `config_data` - The configuration data for the transpiler.
+/// * `global_context` - The global context containing the registered benchmarks.
+fn emit_bench_main(output_dirs: &Vec<String>, config_data: &ConfigData, global_context: &GlobalContext) {
+	let mut lines = Vec::new();
+	lines.push("#include <chrono>".to_string());
+	lines.push("#include <cstdio>".to_string());
+	lines.push("".to_string());
+	let mut included = Vec::new();
+	for bench in &global_context.benches {
+		if !included.contains(&bench.2) {
+			lines.push(format!("#include \"{}\"", bench.2));
+			included.push(bench.2.clone());
+		}
+	}
+	lines.push("".to_string());
+	lines.push("int main() {".to_string());
+	for bench in &global_context.benches {
+		lines.push("\t{".to_string());
+		lines.push("\t\tauto start = std::chrono::steady_clock::now();".to_string());
+		lines.push(format!("\t\t{}();", bench.1));
+		lines.push("\t\tauto elapsed = std::chrono::duration_cast<std::chrono::microseconds>(std::chrono::steady_clock::now() - start);".to_string());
+		lines.push(format!("\t\tstd::printf(\"[BENCH] %s: %lld us\\n\", \"{}\", static_cast<long long>(elapsed.count()));", escape_cpp_string(&bench.0)));
+		lines.push("\t}".to_string());
+	}
+	lines.push("\treturn 0;".to_string());
+	lines.push("}".to_string());
+
+	let content = config_data.line_ending.join(&lines);
+	for dir in output_dirs {
+		let path = Path::new(dir).join(format!("tasty_bench_main.{}", config_data.source_ext));
+		let original_content = std::fs::read_to_string(&path);
+		if original_content.is_err() || original_content.unwrap() != content {
+			let result = std::fs::write(&path, &content);
+			if result.is_err() {
+				println!("Could not write to file: {}\n{}", path.to_string_lossy(), result.err().unwrap());
+			}
+		}
+	}
+}
+
+/// Generates a source file containing `tasty_init_modules`, which calls every module's init function.
+/// Modules are initialized after the modules they import.
+///
+/// # Arguments
+///
+/// * `output_dirs` - The list of output directories to write the registry to.
+/// * `config_data` - The configuration data for the transpiler.
+/// * `global_context` - The global context containing the registered init functions.
+/// * `module_contexts` - The context manager used to find the imports of each module.
+fn emit_module_init(output_dirs: &Vec<String>, config_data: &ConfigData, global_context: &GlobalContext, module_contexts: &ContextManager) {
+	let inits = &global_context.module_inits;
+	let mut order = Vec::new();
+	let mut visited = vec![false; inits.len()];
+	for i in 0..inits.len() {
+		order_module_init(i, inits, module_contexts, &mut visited, &mut order);
+	}
+
+	let mut lines = Vec::new();
+	for i in &order {
+		lines.push(format!("#include \"{}\"", inits[*i].1));
+	}
+	lines.push("".to_string());
+	lines.push("void tasty_init_modules() {".to_string());
+	for i in &order {
+		lines.push(format!("\t{}();", inits[*i].0));
+	}
+	lines.push("}".to_string());
+
+	let content = config_data.line_ending.join(&lines);
+	for dir in output_dirs {
+		let path = Path::new(dir).join(format!("tasty_module_init.{}", config_data.source_ext));
+		let original_content = std::fs::read_to_string(&path);
+		if original_content.is_err() || original_content.unwrap() != content {
+			let result = std::fs::write(&path, &content);
+			if result.is_err() {
+				println!("Could not write to file: {}\n{}", path.to_string_lossy(), result.err().unwrap());
+			}
+		}
+	}
+}
+
+/// Generates a JSON file containing the graph of imports between modules.
+/// Edges are directed from the importing module to the imported module.
+///
+/// # Arguments
+///
+/// * `output_dirs` - The list of output directories to write the graph to.
+/// * `module_contexts` - The context manager containing the imports of each module.
+fn dump_includes(output_dirs: &Vec<String>, module_contexts: &ContextManager) {
+	let nodes = module_contexts.contexts.keys().collect::<Vec<&String>>();
+	let mut edges = Vec::new();
+	for (module, imports) in &module_contexts.imports {
+		let header_imports = module_contexts.header_imports.get(module);
+		for import in imports {
+			let is_header = header_imports.map(|h| h.contains(import)).unwrap_or(false);
+			edges.push(serde_json::json!({
+				"from": module,
+				"to": import,
+				"kind": if is_header { "header" } else { "source" }
+			}));
+		}
+	}
+
+	let content = serde_json::to_string_pretty(&serde_json::json!({
+		"nodes": nodes,
+		"edges": edges
+	})).unwrap_or("{}".to_string());
+	for dir in output_dirs {
+		let path = Path::new(dir).join("tasty_includes.json");
+		let original_content = std::fs::read_to_string(&path);
+		if original_content.is_err() || original_content.unwrap() != content {
+			let result = std::fs::write(&path, &content);
+			if result.is_err() {
+				println!("Could not write to file: {}\n{}", path.to_string_lossy(), result.err().unwrap());
+			}
+		}
+	}
+}
+
+/// Generates a `compile_commands.json` compilation database for clang tooling.
+/// Every generated source file in an output directory receives an entry.
+///
+/// # Arguments
+///
+/// * `output_dirs` - The list of output directories to write the database to.
+/// * `files` - The transpiled source files, along with the source directory of each.
+/// * `config_data` - The configuration data for the transpiler.
+fn emit_compile_commands(output_dirs: &Vec<String>, files: &Vec<(String, String)>, config_data: &ConfigData) {
+	let working_dir = env::current_dir().unwrap_or_default();
+	for dir in output_dirs {
+		let mut entries = Vec::new();
+		for (file, source_location) in files {
+			let source_path = Path::new(dir).join(file).with_extension(&config_data.source_ext);
+			if !source_path.exists() {
+				continue;
+			}
+			// Headers are included relative to the source directory, or the working directory if they are output elsewhere.
+			let mut include_dirs = vec![
+				dir.clone(),
+				Path::new(dir).join(source_location).to_slash_lossy().to_string()
+			];
+			if dir != "." {
+				include_dirs.push(".".to_string());
+			}
+			let source_path_str = source_path.to_slash_lossy().to_string();
+			entries.push(serde_json::json!({
+				"directory": working_dir.to_string_lossy(),
+				"file": source_path_str,
+				"command": config_data.target.compile_command(&source_path_str, &include_dirs)
+			}));
+		}
+
+		let content = serde_json::to_string_pretty(&entries).unwrap_or("[]".to_string());
+		let path = Path::new(dir).join("compile_commands.json");
+		let original_content = std::fs::read_to_string(&path);
+		if original_content.is_err() || original_content.unwrap() != content {
+			let result = std::fs::write(&path, &content);
+			if result.is_err() {
+				println!("Could not write to file: {}\n{}", path.to_string_lossy(), result.err().unwrap());
+			}
+		}
+	}
+}
+
+fn order_module_init(index: usize, inits: &Vec<(String, String, String)>, module_contexts: &ContextManager, visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+	if visited[index] {
+		return;
+	}
+	visited[index] = true;
+	let dependencies = module_contexts.get_dependencies(&inits[index].2);
+	for i in 0..inits.len() {
+		if dependencies.contains(&inits[i].2) {
+			order_module_init(i, inits, module_contexts, visited, order);
+		}
+	}
+	order.push(index);
+}
+
 fn get_configure_declaration_with_attributes(isolated: &mut bool, declaration: &str, attributes: &Attributes, content: &str, semicolon: bool) -> String {
 	let prepend = attributes.get_attribute_parameters("DeclarePrepend", content);
 	let append = attributes.get_attribute_parameters("DeclareAppend", content);
 	*isolated = attributes.has_attribute("Isolated");
-	let result = format!("{}{}{}{}", 
+	let result = format!("{}{}{}{}{}", 
+		get_doc_comment(attributes, content),
 		if prepend.is_empty() { "".to_string() } else { format!("{}\n", prepend.join("\n")) }, 
 		declaration,
 		if semicolon { ";" } else { "" },
@@ -496,14 +1575,37 @@ fn get_configure_declaration_with_attributes(isolated: &mut bool, declaration: &
 	return result;
 }
 
-fn configure_declaration_with_attributes(delcarations: &mut Vec<String>, declarations_isolated: &mut Vec<String>, declaration: &str, attributes: &Attributes, content: &str, semicolon: bool) {
+/// Generates a Doxygen comment from the `@Doc` attributes.
+/// Each `@Doc` and embedded newline becomes its own line.
+fn get_doc_comment(attributes: &Attributes, content: &str) -> String {
+	let mut lines = Vec::new();
+	for doc in attributes.get_all_attribute_parameters("Doc", content) {
+		for line in parse_string_parameter(&doc).split('\n') {
+			lines.push(line.trim_end().replace("*/", "* /"));
+		}
+	}
+	if lines.is_empty() {
+		return "".to_string();
+	}
+	if lines.len() == 1 {
+		return format!("/** {} */\n", lines[0]);
+	}
+	let mut result = "/**\n".to_string();
+	for line in lines {
+		if line.is_empty() {
+			result += " *\n";
+		} else {
+			result += &format!(" * {}\n", line);
+		}
+	}
+	result += " */\n";
+	result
+}
+
+fn configure_declaration_with_attributes(declarations: &mut VarFuncDeclarations, source_index: usize, declaration: &str, attributes: &Attributes, content: &str, semicolon: bool) {
 	let mut isolated = false;
 	let result = get_configure_declaration_with_attributes(&mut isolated, declaration, attributes, content, semicolon);
-	if isolated {
-		declarations_isolated.push(result);
-	} else {
-		delcarations.push(result);
-	}
+	declarations.add_variable(result, isolated, source_index);
 }
 
 // clear
@@ -527,13 +1629,51 @@ fn insert_output_line(output_lines: &mut Vec<String>, line: &str, line_number: u
 	}
 }
 
+/// Adds the system and local include groups to the header lines in the provided order.
+/// Each group is followed by an empty line.
+///
+/// # Arguments
+///
+/// * `header_lines` - The lines of the header file being generated.
+/// * `system_includes` - The `#include <...>` lines.
+/// * `local_includes` - The `#include "..."` lines.
+/// * `order` - The order to output the groups in.
+fn push_include_groups(header_lines: &mut Vec<String>, mut system_includes: Vec<String>, mut local_includes: Vec<String>, order: IncludeOrder) {
+	if order == IncludeOrder::Alphabetical {
+		// Conditional includes are sorted by their include line rather than their condition.
+		let include_line = |line: &String| line.lines().find(|l| l.starts_with("#include")).unwrap_or(line).to_string();
+		system_includes.sort_by_key(include_line);
+		local_includes.sort_by_key(include_line);
+	}
+	let groups = if order == IncludeOrder::LocalFirst {
+		[local_includes, system_includes]
+	} else {
+		[system_includes, local_includes]
+	};
+	for group in groups.iter() {
+		if !group.is_empty() {
+			header_lines.extend(group.iter().cloned());
+			header_lines.push("".to_string());
+		}
+	}
+}
+
 /// The main function of Tasty Fresh.
 fn main() {
 	let arguments = parse_arguments(env::args());
 
-	let source_files = match get_source_files(&arguments) {
-		Some(files) => files,
-		None => return
+	let stdin_mode = arguments.contains_key("stdin");
+	if stdin_mode {
+		context_management::set_messages_to_stderr(true);
+	}
+
+	let source_files = if stdin_mode {
+		BTreeMap::new()
+	} else {
+		match get_source_files(&arguments) {
+			Some(files) => files,
+			None => return
+		}
 	};
 
 	let output_dirs = match get_output_dirs(&arguments) {
@@ -541,10 +1681,120 @@ fn main() {
 		None => return
 	};
 
-	let mut data = config_management::read_config_files();
+	let config_dir = arguments.get("config-dir").and_then(|values| values.last());
+	let mut data = match config_management::read_config_files(config_dir.map(|dir| dir.as_str())) {
+		Ok(data) => data,
+		Err(message) => {
+			println!("{}", message.bright_red());
+			return;
+		}
+	};
 
 	data.pragma_guard = arguments.contains_key("pragma-guard");
 	data.hpp_headers = !arguments.contains_key("h-headers");
+	data.checked_casts = arguments.contains_key("checked-casts");
+	data.allow_reinterpret = arguments.contains_key("allow-reinterpret");
+	data.immutable_let = arguments.contains_key("immutable-let");
+	data.align_assignments = arguments.contains_key("align-assignments");
+	data.strict = arguments.contains_key("strict");
+	data.preserve_member_order = arguments.contains_key("preserve-member-order");
+	data.fold_constants = arguments.contains_key("fold-constants");
+	data.module_init = arguments.contains_key("module-init");
+	data.emit_mocks = arguments.contains_key("emit-mocks");
+	data.warn_unused = arguments.contains_key("warn-unused");
+	data.keep_comments = arguments.contains_key("keep-comments");
+	data.infer_const_methods = arguments.contains_key("infer-const-methods");
+	data.auto_constexpr = arguments.contains_key("auto-constexpr");
+	if !data.hpp_headers {
+		data.header_ext = "h".to_string();
+	}
+
+	if let Some(namespace) = arguments.get("std-namespace").and_then(|values| values.last()) {
+		data.library.std_namespace = namespace.clone();
+	}
+
+	if let Some(prefix) = arguments.get("include-prefix").and_then(|values| values.last()) {
+		let prefix = prefix.replace('\\', "/");
+		data.include_prefix = if prefix.is_empty() || prefix.ends_with('/') { prefix } else { prefix + "/" };
+	}
+
+	if let Some(json_type) = arguments.get("json-type").and_then(|values| values.last()) {
+		data.json_type = json_type.clone();
+	}
+
+	if let Some(json_include) = arguments.get("json-include").and_then(|values| values.last()) {
+		data.json_include = json_include.clone();
+	}
+
+	if let Some(class_ptr_type) = arguments.get("class-ptr-type").and_then(|values| values.last()) {
+		data.library.class_ptr_type = class_ptr_type.clone();
+	}
+
+	if let Some(class_ptr_include) = arguments.get("class-ptr-include").and_then(|values| values.last()) {
+		data.library.class_ptr_include = class_ptr_include.clone();
+	}
+
+	match get_include_order(&arguments) {
+		Ok(Some(order)) => data.include_order = order,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_target(&arguments) {
+		Ok(Some(target)) => data.target = target,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_line_ending(&arguments) {
+		Ok(Some(ending)) => data.line_ending = ending,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_default_visibility(&arguments) {
+		Ok(Some(visibility)) => data.default_visibility = visibility,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_brace_style(&arguments) {
+		Ok(Some(style)) => data.brace_style = style,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_constraint_style(&arguments) {
+		Ok(Some(style)) => data.constraint_style = style,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_max_errors(&arguments) {
+		Ok(Some(limit)) => data.max_errors = limit,
+		Ok(None) => (),
+		Err(_) => return
+	}
+	context_management::set_error_limit(data.max_errors);
+
+	match get_file_extension(&arguments, "source-ext") {
+		Ok(Some(ext)) => data.source_ext = ext,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	match get_file_extension(&arguments, "header-ext") {
+		Ok(Some(ext)) => data.header_ext = ext,
+		Ok(None) => (),
+		Err(_) => return
+	}
+
+	if stdin_mode {
+		if !transpile_stdin(&arguments, &output_dirs, &data) {
+			std::process::exit(1);
+		}
+		return;
+	}
 
 	let mut file_contexts = ContextManager::new();//BTreeMap::new();
 	let mut file_declarations = BTreeMap::new();
@@ -552,17 +1802,83 @@ fn main() {
 
 	let mut global_context = GlobalContext::new();
 
+	let verbose = arguments.contains_key("verbose");
+	let mut parse_times = BTreeMap::new();
+
 	for files in &source_files {
 		for f in files.1 {
 			let mut parser: Parser = Parser::new("".to_string());
+			let parse_start = Instant::now();
 			file_declarations.insert(f.clone(), parse_source_file(&f, &files.0, &data, &mut file_contexts, &mut parser, &mut global_context));
+			parse_times.insert(f.clone(), parse_start.elapsed());
 			file_parsers.insert(f, parser);
 		}
 	}
 
+	// Tests are registered while transpiling, so every file is transpiled when the test main is emitted.
+	let incremental = arguments.contains_key("incremental") && !arguments.contains_key("emit-test-main");
+	let cache_path = Path::new(&output_dirs[0]).join(".tasty-cache");
+	let arguments_hash = file_system::hash_content(&format!("{:?}", arguments));
+	let mut previous_hashes = if incremental { file_system::read_build_cache(&cache_path) } else { BTreeMap::new() };
+	if previous_hashes.get(CACHE_ARGUMENTS_KEY) != Some(&arguments_hash) {
+		previous_hashes.clear();
+	}
+
+	let mut file_hashes = BTreeMap::new();
+	let mut changed_modules = std::collections::BTreeSet::new();
+	for files in &source_files {
+		for f in files.1 {
+			let hash = file_system::hash_content(&file_parsers.get(f).unwrap().content);
+			if previous_hashes.get(f) != Some(&hash) {
+				changed_modules.insert(get_access_file_path(f, files.0).to_string());
+			}
+			file_hashes.insert(f.clone(), hash);
+		}
+	}
+
+	let mut new_hashes = BTreeMap::new();
+	new_hashes.insert(CACHE_ARGUMENTS_KEY.to_string(), arguments_hash);
+	let mut transpiled_files = Vec::new();
 	for files in &source_files {
 		for f in files.1 {
-			transpile_source_file(&f, &files.0, &output_dirs, &data, &mut file_contexts, file_declarations.get_mut(f).unwrap(), file_parsers.get_mut(f).unwrap(), &mut global_context);
+			let access_file_path = get_access_file_path(f, files.0);
+			let unchanged = incremental &&
+				!changed_modules.contains(access_file_path) &&
+				file_contexts.get_dependencies(access_file_path).is_disjoint(&changed_modules) &&
+				outputs_exist(f, &output_dirs, &data);
+			let transpile_start = Instant::now();
+			if unchanged || write_output_files(f, files.0, &output_dirs, &data, generate_output_lines(f, files.0, &data, &mut file_contexts, file_declarations.get_mut(f).unwrap(), file_parsers.get_mut(f).unwrap(), &mut global_context)) {
+				new_hashes.insert(f.clone(), *file_hashes.get(f).unwrap());
+				transpiled_files.push((f.clone(), files.0.clone()));
+			}
+			if verbose {
+				let transpile_time = if unchanged { None } else { Some(transpile_start.elapsed()) };
+				print_file_stats(f, file_declarations.get(f).unwrap(), *parse_times.get(f).unwrap(), transpile_time);
+			}
 		}
 	}
+
+	if incremental {
+		file_system::write_build_cache(&cache_path, &new_hashes);
+	}
+
+	if arguments.contains_key("emit-test-main") {
+		emit_test_main(&output_dirs, &data, &global_context);
+	}
+
+	if arguments.contains_key("emit-bench-main") {
+		emit_bench_main(&output_dirs, &data, &global_context);
+	}
+
+	if data.module_init {
+		emit_module_init(&output_dirs, &data, &global_context, &file_contexts);
+	}
+
+	if arguments.contains_key("dump-includes") {
+		dump_includes(&output_dirs, &file_contexts);
+	}
+
+	if arguments.contains_key("emit-compile-commands") {
+		emit_compile_commands(&output_dirs, &transpiled_files, &data);
+	}
 }
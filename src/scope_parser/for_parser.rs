@@ -15,7 +15,9 @@ use crate::config_management::ConfigData;
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionEndReason;
-use crate::expression::variable_type::VariableType;
+use crate::expression::variable_type::{ VariableType, Type };
+use crate::expression::value_type::NumberType;
+use crate::expression::const_eval::{ evaluate, ConstValue };
 
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
@@ -210,8 +212,23 @@ impl ForParser {
 			parse_unneccessary_ascii!(by_keyword, parser);
 			let mut by_expression: Option<Rc<Expression>> = None;
 			if by_keyword == "by" {
+				declare_parse_whitespace!(parser);
+				let by_start = parser.index;
 				let mut by_reason = ExpressionEndReason::Unknown;
 				by_expression = Some(parser.parse_expression(file_name.clone(), config_data, Some(context), context_manager, &mut by_reason, Some(VariableType::boolean())));
+				let step_is_positive = match evaluate(by_expression.as_ref().unwrap(), &std::collections::BTreeMap::new()) {
+					Some(ConstValue::Int(step)) => Some(step > 0),
+					Some(ConstValue::Float(step)) => Some(step > 0.0),
+					_ => None
+				};
+				if step_is_positive == Some(false) {
+					let by_end = parser.content[..parser.index].trim_end().len().max(by_start + 1);
+					return ForParserResult::Err("Invalid Step", if for_type.is_increment() || for_type.is_incrementto() {
+						"the step of an increment loop must be positive"
+					} else {
+						"the step of a decrement loop must be positive"
+					}, by_start, by_end);
+				}
 
 				match by_reason {
 					ExpressionEndReason::Unknown => return ForParserResult::Err("Unknown Error", "unknown expression parsing error", parser.index - 1, parser.index),
@@ -253,6 +270,35 @@ impl ForParser {
 		});
 	}
 
+	/// Determines the type of an increment or decrement loop's counter.
+	/// If any of the start, end, or step values are floating point, the widest of them is used
+	/// so the counter is not truncated; otherwise the type of the start value is used.
+	pub fn get_counter_type(start: &Expression, end: &Expression, by: &Option<Rc<Expression>>) -> VariableType {
+		let mut result = start.get_type();
+		let mut result_rank = Self::floating_rank(&result);
+		let mut others = vec![end.get_type()];
+		if let Some(by_expr) = by {
+			others.push(by_expr.get_type());
+		}
+		for other in others {
+			let rank = Self::floating_rank(&other);
+			if rank > result_rank {
+				result = VariableType::copy(other.var_type);
+				result_rank = rank;
+			}
+		}
+		result
+	}
+
+	fn floating_rank(var_type: &VariableType) -> usize {
+		match &var_type.var_type {
+			Type::Number(NumberType::Float) => 1,
+			Type::Number(NumberType::Double) => 2,
+			Type::Number(NumberType::LongDouble) => 3,
+			_ => 0
+		}
+	}
+
 	pub fn is_declaration(parser: &Parser) -> bool {
 		return Self::is_for_declaration(&parser.content, parser.index);
 	}
@@ -0,0 +1,110 @@
+/**********************************************************
+ * --- Guard Parser ---
+ *
+ * Parses a guard statement and its diverging else block.
+ **********************************************************/
+
+use crate::{
+	declare_parse_whitespace,
+	declare_parse_ascii
+};
+
+use crate::config_management::ConfigData;
+
+use crate::expression::Expression;
+use crate::expression::expression_parser::ExpressionEndReason;
+use crate::expression::variable_type::VariableType;
+
+use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
+use crate::declaration_parser::parser::Parser;
+
+use crate::context_management::context::Context;
+use crate::context_management::context_manager::ContextManager;
+
+use crate::scope_parser::ScopeExpression;
+
+use std::rc::Rc;
+
+use regex::Regex;
+
+lazy_static! {
+	pub static ref GUARD_REGEX: Regex = Regex::new(r"^\b(?:guard)\b").unwrap();
+}
+
+type GuardParserResult = DeclarationResult<GuardParser>;
+
+pub struct GuardParser {
+	pub expression: Rc<Expression>,
+	pub scope: Box<ScopeExpression>,
+	pub line: usize,
+	pub end_line: usize
+}
+
+impl Declaration<GuardParser> for GuardParser {
+	fn out_of_space_error_msg() -> &'static str {
+		"unexpected end of guard statement"
+	}
+}
+
+impl GuardParser {
+	pub fn new(parser: &mut Parser, file_name: String, config_data: &ConfigData, context: &mut Context, context_manager: &mut ContextManager, expected_return_type: Option<VariableType>) -> GuardParserResult {
+		let initial_line = parser.line;
+
+		let mut guard_keyword = "".to_string();
+		declare_parse_ascii!(guard_keyword, parser);
+		if guard_keyword != "guard" {
+			return GuardParserResult::Err("Unexpected Keyword", "\"guard\" keyword expected", parser.index - guard_keyword.len(), parser.index);
+		}
+
+		declare_parse_whitespace!(parser);
+
+		let mut reason = ExpressionEndReason::Unknown;
+		let expression = parser.parse_expression(file_name.clone(), config_data, Some(context), context_manager, &mut reason, Some(VariableType::boolean()));
+
+		match reason {
+			ExpressionEndReason::Unknown => return GuardParserResult::Err("Unknown Error", "unknown expression parsing error", parser.index - 1, parser.index),
+			ExpressionEndReason::EndOfContent =>  return GuardParserResult::Err("Unexpected End of Expression", "unexpected end of expression", parser.index - 1, parser.index),
+			ExpressionEndReason::NoValueError => return GuardParserResult::Err("Value Expected", "expression value expected here", parser.index - 1, parser.index),
+			_ => ()
+		}
+
+		declare_parse_whitespace!(parser);
+
+		let else_start = parser.index;
+		let mut else_keyword = "".to_string();
+		declare_parse_ascii!(else_keyword, parser);
+		if else_keyword != "else" {
+			return GuardParserResult::Err("Else Expected", "guard statement requires an else block", else_start, parser.index.max(else_start + 1));
+		}
+
+		declare_parse_whitespace!(parser);
+
+		if parser.get_curr() != '{' {
+			return GuardParserResult::Err("Unexpected Symbol", "expected '{' operator", parser.index, parser.index + 1);
+		}
+		let scope = ScopeExpression::new(parser, None, parser.index + 1, parser.line, &file_name, config_data, context, context_manager, expected_return_type);
+		if parser.get_curr() == '}' {
+			parser.increment();
+		}
+
+		if !scope.is_diverging() {
+			return GuardParserResult::Err("Guard Must Exit", "guard else block must end with return, break, or continue", else_start, else_start + 4);
+		}
+
+		GuardParserResult::Ok(GuardParser {
+			expression,
+			scope: Box::new(scope),
+			line: initial_line,
+			end_line: parser.line
+		})
+	}
+
+	pub fn is_declaration(parser: &Parser) -> bool {
+		Self::is_guard_declaration(&parser.content, parser.index)
+	}
+
+	pub fn is_guard_declaration(content: &str, index: usize) -> bool {
+		let declare = &content[index..];
+		GUARD_REGEX.is_match(declare)
+	}
+}
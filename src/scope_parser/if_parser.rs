@@ -11,16 +11,20 @@ use crate::{
 };
 
 use crate::config_management::ConfigData;
+use crate::config_management::operator_data::AS_OPERATOR_ID;
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionEndReason;
-use crate::expression::variable_type::VariableType;
+use crate::expression::variable_type::{ VariableType, VarProps };
 
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
 
+use crate::context_management::print_code_warning;
 use crate::context_management::context::Context;
 use crate::context_management::context_manager::ContextManager;
+use crate::context_management::typing_context::ContextType;
+use crate::context_management::position::Position;
 
 use crate::scope_parser::ScopeExpression;
 
@@ -36,6 +40,7 @@ type IfParserResult = DeclarationResult<IfParser>;
 
 pub struct IfParser {
 	pub if_type: IfType,
+	pub is_const: bool,
 	pub expression: Option<Rc<Expression>>,
 	pub scope: Box<ScopeExpression>,
 	pub line: usize,
@@ -94,7 +99,7 @@ impl Declaration<IfParser> for IfParser {
 }
 
 impl IfParser {
-	pub fn new(parser: &mut Parser, file_name: String, config_data: &ConfigData, context: &mut Context, context_manager: &mut ContextManager) -> IfParserResult {
+	pub fn new(parser: &mut Parser, file_name: String, config_data: &ConfigData, context: &mut Context, context_manager: &mut ContextManager, follows_const_if: bool) -> IfParserResult {
 		let initial_line = parser.line;
 
 		let mut if_keyword = "".to_string();
@@ -128,8 +133,24 @@ impl IfParser {
 			}
 		}
 
+		let mut is_const = follows_const_if && !if_type.is_if() && !if_type.is_unless();
+		if obtain_condition {
+			let curr_index = parser.index;
+			let curr_line = parser.line;
+
+			let mut const_keyword = "".to_string();
+			parse_unneccessary_ascii!(const_keyword, parser);
+			if const_keyword == "const" {
+				declare_parse_whitespace!(parser);
+				is_const = true;
+			} else {
+				parser.reset(curr_index, curr_line);
+			}
+		}
+
 		let mut expression: Option<Rc<Expression>> = None;
 		if obtain_condition {
+			let condition_start = parser.index;
 			let mut reason = ExpressionEndReason::Unknown;
 			expression = Some(parser.parse_expression(file_name.clone(), config_data, Some(context), context_manager, &mut reason, Some(VariableType::boolean())));
 
@@ -140,6 +161,13 @@ impl IfParser {
 				_ => ()
 			}
 
+			if is_const {
+				if let Some(name) = Self::find_runtime_variable(expression.as_ref().unwrap(), context, context_manager) {
+					let position = Position::new(file_name.clone(), None, condition_start, Some(parser.index));
+					print_code_warning("Non-Constant Condition", &format!("\"{}\" is not constexpr", name), &position, &parser.content);
+				}
+			}
+
 			declare_parse_whitespace!(parser);
 		}
 
@@ -155,6 +183,7 @@ impl IfParser {
 
 		return IfParserResult::Ok(IfParser {
 			if_type: if_type,
+			is_const,
 			expression: expression,
 			scope: Box::new(scope.unwrap()),
 			line: initial_line,
@@ -162,6 +191,43 @@ impl IfParser {
 		});
 	}
 
+	/// Finds the name of the first variable in a compile-time condition that is not `constexpr`.
+	fn find_runtime_variable(expr: &Expression, context: &Context, context_manager: &ContextManager) -> Option<String> {
+		match expr {
+			Expression::Value(name, _, _) => {
+				let mut item = context.typing.get_item(name, None, None, false);
+				if item.is_none() {
+					item = context.module.get_item(name, Some(context), Some(context_manager), false);
+				}
+				if let Some(ContextType::Variable(var_type)) = item {
+					let is_constexpr = var_type.var_properties.as_ref().map(|props| props.contains(&VarProps::Constexpr)).unwrap_or(false);
+					if !is_constexpr {
+						return Some(name.clone());
+					}
+				}
+				None
+			},
+			Expression::Prefix(expr, _, _, _) | Expression::Suffix(expr, _, _, _) => Self::find_runtime_variable(expr, context, context_manager),
+			Expression::Infix(left, right, id, _, _) => {
+				let result = Self::find_runtime_variable(left, context, context_manager);
+				if result.is_some() || *id == 0 || (*id >= 2 && *id <= 9) || *id == AS_OPERATOR_ID {
+					result
+				} else {
+					Self::find_runtime_variable(right, context, context_manager)
+				}
+			},
+			Expression::Ternary(expr_1, expr_2, expr_3, _, _) => {
+				Self::find_runtime_variable(expr_1, context, context_manager)
+					.or_else(|| Self::find_runtime_variable(expr_2, context, context_manager))
+					.or_else(|| Self::find_runtime_variable(expr_3, context, context_manager))
+			},
+			Expression::Expressions(exprs, _, _) | Expression::FunctionCall(_, exprs, _, _) => {
+				exprs.iter().find_map(|e| Self::find_runtime_variable(e, context, context_manager))
+			},
+			_ => None
+		}
+	}
+
 	pub fn is_declaration(parser: &Parser) -> bool {
 		return Self::is_if_declaration(&parser.content, parser.index);
 	}
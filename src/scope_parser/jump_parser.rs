@@ -0,0 +1,116 @@
+/**********************************************************
+ * --- Jump Parser ---
+ *
+ * Parses a break or continue statement.
+ * Within a value loop, break may provide the loop's value.
+ **********************************************************/
+
+use crate::{
+	declare_parse_whitespace,
+	declare_parse_ascii,
+	parse_unneccessary_ascii
+};
+
+use crate::config_management::ConfigData;
+
+use crate::expression::Expression;
+use crate::expression::expression_parser::ExpressionEndReason;
+
+use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
+use crate::declaration_parser::parser::Parser;
+
+use crate::context_management::context::Context;
+use crate::context_management::context_manager::ContextManager;
+
+use std::rc::Rc;
+
+use regex::Regex;
+
+lazy_static! {
+	pub static ref JUMP_REGEX: Regex = Regex::new(r"^\b(?:break|continue)\b").unwrap();
+}
+
+type JumpParserResult = DeclarationResult<JumpParser>;
+
+pub struct JumpParser {
+	pub is_break: bool,
+	pub label: Option<String>,
+	pub value: Option<Rc<Expression>>,
+	pub line: usize
+}
+
+impl Declaration<JumpParser> for JumpParser {
+	fn out_of_space_error_msg() -> &'static str {
+		"unexpected end of jump statement"
+	}
+}
+
+impl JumpParser {
+	pub fn new(parser: &mut Parser, file_name: String, config_data: &ConfigData, context: &mut Context, context_manager: &mut ContextManager) -> JumpParserResult {
+		let initial_line = parser.line;
+
+		let mut jump_keyword = "".to_string();
+		declare_parse_ascii!(jump_keyword, parser);
+		if jump_keyword != "break" && jump_keyword != "continue" {
+			return JumpParserResult::Err("Unexpected Keyword", "\"break\" or \"continue\" keyword expected", parser.index - jump_keyword.len(), parser.index);
+		}
+
+		declare_parse_whitespace!(parser);
+
+		let value_start = parser.index;
+		let value_line = parser.line;
+		let mut label = "".to_string();
+		parse_unneccessary_ascii!(label, parser);
+
+		let mut value = None;
+		let loop_label = context.find_loop_label(&label);
+		let is_value_break = jump_keyword == "break" && context.loop_value_type.is_some() && loop_label.is_none();
+		if is_value_break && label.is_empty() && parser.get_curr() == ';' {
+			return JumpParserResult::Err("Break Value Expected", "break must provide the value of the loop", parser.index, parser.index + 1);
+		} else if is_value_break {
+			label = "".to_string();
+			parser.reset(value_start, value_line);
+			let expected_type = context.loop_value_type.clone();
+			let mut reason = ExpressionEndReason::Unknown;
+			let expr = parser.parse_expression(file_name, config_data, Some(context), context_manager, &mut reason, expected_type);
+			match reason {
+				ExpressionEndReason::Unknown => return JumpParserResult::Err("Unknown Error", "unknown expression parsing error", parser.index - 1, parser.index),
+				ExpressionEndReason::EndOfContent =>  return JumpParserResult::Err("Unexpected End of Expression", "unexpected end of expression", parser.index - 1, parser.index),
+				ExpressionEndReason::NoValueError => return JumpParserResult::Err("Value Expected", "expression value expected here", parser.index - 1, parser.index),
+				_ => ()
+			}
+			let value_type = expr.get_type();
+			let loop_type = context.loop_value_type.as_ref().unwrap();
+			if loop_type.is_inferred() {
+				context.loop_value_type = Some(value_type);
+			} else if !value_type.could_convert_to(loop_type) {
+				return JumpParserResult::Err("Mismatched Break Value", "break values of a loop must share the same type", value_start, parser.index);
+			}
+			value = Some(expr);
+		} else if !label.is_empty() && loop_label.is_none() {
+			return JumpParserResult::Err("Unknown Label", "no enclosing loop has this label", parser.index - label.len(), parser.index);
+		}
+
+		declare_parse_whitespace!(parser);
+
+		if parser.get_curr() != ';' {
+			return JumpParserResult::Err("Semicolon Needed", "there should be a ; here", parser.index, parser.index + 1);
+		}
+
+		JumpParserResult::Ok(JumpParser {
+			is_break: jump_keyword == "break",
+			label: if label.is_empty() { None } else { loop_label },
+			value,
+			line: initial_line
+		})
+	}
+
+	pub fn is_declaration(parser: &Parser) -> bool {
+		Self::is_jump_declaration(&parser.content, parser.index)
+	}
+
+	pub fn is_jump_declaration(content: &str, index: usize) -> bool {
+		let declare = &content[index..];
+		JUMP_REGEX.is_match(declare)
+	}
+}
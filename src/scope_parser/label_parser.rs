@@ -0,0 +1,63 @@
+/**********************************************************
+ * --- Label Parser ---
+ *
+ * Parses a loop label.
+ **********************************************************/
+
+use crate::{
+	declare_parse_whitespace,
+	declare_parse_ascii,
+	declare_parse_required_next_char
+};
+
+use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
+use crate::declaration_parser::parser::Parser;
+
+use regex::Regex;
+
+lazy_static! {
+	pub static ref LABEL_REGEX: Regex = Regex::new(r"^[A-Za-z_]\w*\s*:\s*\b(?:for|inc|dec|while|until|loop|do)\b").unwrap();
+}
+
+type LabelParserResult = DeclarationResult<LabelParser>;
+
+pub struct LabelParser {
+	pub name: String,
+	pub line: usize
+}
+
+impl Declaration<LabelParser> for LabelParser {
+	fn out_of_space_error_msg() -> &'static str {
+		"unexpected end of loop label"
+	}
+}
+
+impl LabelParser {
+	pub fn new(parser: &mut Parser) -> LabelParserResult {
+		let initial_line = parser.line;
+
+		let mut name = "".to_string();
+		declare_parse_ascii!(name, parser);
+
+		declare_parse_whitespace!(parser);
+
+		let mut next_char = ' ';
+		declare_parse_required_next_char!(':', next_char, parser);
+
+		declare_parse_whitespace!(parser);
+
+		LabelParserResult::Ok(LabelParser {
+			name,
+			line: initial_line
+		})
+	}
+
+	pub fn is_declaration(parser: &Parser) -> bool {
+		Self::is_label_declaration(&parser.content, parser.index)
+	}
+
+	pub fn is_label_declaration(content: &str, index: usize) -> bool {
+		let declare = &content[index..];
+		LABEL_REGEX.is_match(declare)
+	}
+}
@@ -9,32 +9,46 @@
 pub mod expression_scope_parser;
 pub mod return_parser;
 pub mod if_parser;
+pub mod guard_parser;
 pub mod while_parser;
 pub mod loop_parser;
 pub mod dowhile_parser;
 pub mod for_parser;
 pub mod inject_parser;
+pub mod label_parser;
+pub mod jump_parser;
+pub mod try_parser;
 
 use crate::declaration_parser::parser::Parser;
 use crate::declaration_parser::variable_declaration::{ VariableDeclaration, VariableExportType };
+use crate::declaration_parser::attribute_declaration::AttributeDeclaration;
+use crate::declaration_parser::attributes::Attributes;
+use crate::declaration_parser::class_declaration::ClassDeclaration;
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionEndReason;
-use crate::expression::variable_type::VariableType;
+use crate::expression::variable_type::{ VariableType, VarStyle, VarProps, Type };
 
 use crate::scope_parser::return_parser::ReturnParser;
 use crate::scope_parser::if_parser::{ IfParser, IfType };
+use crate::scope_parser::guard_parser::GuardParser;
 use crate::scope_parser::while_parser::{ WhileParser, WhileType };
 use crate::scope_parser::loop_parser::LoopParser;
 use crate::scope_parser::dowhile_parser::DoWhileParser;
 use crate::scope_parser::for_parser::ForParser;
 use crate::scope_parser::inject_parser::InjectParser;
+use crate::scope_parser::label_parser::LabelParser;
+use crate::scope_parser::jump_parser::JumpParser;
+use crate::scope_parser::try_parser::TryParser;
 
 use crate::config_management::ConfigData;
 use crate::config_management::operator_data::OperatorDataStructure;
 
 use crate::context_management::context::Context;
 use crate::context_management::context_manager::ContextManager;
+use crate::context_management::{ print_code_error, print_code_warning, error_count, error_limit_reached };
+use crate::context_management::position::Position;
+use crate::context_management::class_members::ClassMembers;
 
 use std::rc::Rc;
 
@@ -45,15 +59,23 @@ pub enum ScopeExpression {
 	Scope(Vec<ScopeExpression>),
 	SubScope(Box<ScopeExpression>, usize, usize),
 	VariableDeclaration(VariableDeclaration, Option<Rc<Expression>>),
-	Return(Option<Rc<Expression>>, usize),
-	If(IfType, Option<Rc<Expression>>, Box<ScopeExpression>, usize, usize),
+	ValueLoop(VariableDeclaration, Box<ScopeExpression>, usize, usize),
+	Return(Option<Rc<Expression>>, bool, Option<Vec<VariableType>>, usize),
+	If(IfType, bool, Option<Rc<Expression>>, Box<ScopeExpression>, usize, usize),
+	Guard(Rc<Expression>, Box<ScopeExpression>, usize, usize),
 	While(WhileType, Rc<Expression>, Box<ScopeExpression>, usize, usize),
 	Loop(Box<ScopeExpression>, usize, usize),
 	DoWhile(WhileType, Rc<Expression>, Box<ScopeExpression>, usize, usize, usize),
 	For(String, Rc<Expression>, Box<ScopeExpression>, usize, usize),
 	Increment(String, Rc<Expression>, Rc<Expression>, Option<Rc<Expression>>, Box<ScopeExpression>, bool, usize, usize),
 	Decrement(String, Rc<Expression>, Rc<Expression>, Option<Rc<Expression>>, Box<ScopeExpression>, bool, usize, usize),
-	Injection(String, usize, usize)
+	Injection(String, usize, usize),
+	Labeled(String, Box<ScopeExpression>, usize),
+	Label(String, usize),
+	Break(Option<String>, usize),
+	BreakValue(Rc<Expression>, usize),
+	Continue(Option<String>, usize),
+	Try(Box<ScopeExpression>, Vec<(VariableType, String, Box<ScopeExpression>, usize, usize)>, usize, usize)
 }
 
 impl ScopeExpression {
@@ -61,6 +83,7 @@ impl ScopeExpression {
 		parser.reset(start_index, line);
 
 		let mut scope_exprs = Vec::new();
+		let mut attributes = Vec::new();
 
 		loop {
 			if limit.is_some() {
@@ -68,8 +91,77 @@ impl ScopeExpression {
 					break;
 				}
 			}
+			if error_limit_reached() {
+				break;
+			}
 			parser.parse_whitespace();
-			if ReturnParser::is_declaration(parser) {
+			if AttributeDeclaration::is_declaration(parser) {
+				let attribute_start = parser.index;
+				let result = AttributeDeclaration::new(parser, false);
+				if result.is_error() {
+					result.print_error(file.to_string(), &parser.content);
+					break;
+				}
+				attributes.push((result.unwrap_and_move(), attribute_start));
+				continue;
+			}
+			let alignment = Self::get_local_alignment(std::mem::take(&mut attributes), VariableDeclaration::is_declaration(parser), file, &parser.content);
+			// Values can only be provided to the innermost loop, so nested loops cannot break with them.
+			let is_nested_loop = WhileParser::is_declaration(parser) || LoopParser::is_declaration(parser) ||
+				DoWhileParser::is_declaration(parser) || ForParser::is_declaration(parser);
+			let loop_value_type = if is_nested_loop { context.loop_value_type.take() } else { None };
+			if LabelParser::is_declaration(parser) {
+				let result = LabelParser::new(parser);
+				if result.is_error() {
+					result.print_error(file.to_string(), &parser.content);
+					break;
+				} else {
+					let label_declare = result.unwrap_and_move();
+					let label_name = context.push_loop_label(&label_declare.name);
+					let labeled_scope = ScopeExpression::new(parser, Some(1), parser.index, parser.line, file, config_data, context, context_manager, expected_return_type.clone());
+					context.pop_loop_label();
+					if let ScopeExpression::Scope(mut labeled_exprs) = labeled_scope {
+						if labeled_exprs.is_empty() {
+							break;
+						}
+						let mut loop_expr = labeled_exprs.remove(0);
+						let line = loop_expr.get_line().unwrap_or(label_declare.line);
+						let end_line = loop_expr.get_end_line().unwrap_or(label_declare.line);
+						// Labels are only output if they are jumped to, since unused labels cause warnings.
+						if loop_expr.jumps_to_label(&label_name, false) {
+							// The body is nested in its own block so jumping to the continue label cannot skip initializations in scope.
+							if let Some(body) = loop_expr.get_loop_scope_mut() {
+								let inner = std::mem::replace(body, ScopeExpression::Scope(Vec::new()));
+								*body = ScopeExpression::Scope(vec![
+									ScopeExpression::SubScope(Box::new(inner), line, end_line),
+									ScopeExpression::Label(format!("{}_continue", label_name), end_line)
+								]);
+							}
+						}
+						if loop_expr.jumps_to_label(&label_name, true) {
+							scope_exprs.push(ScopeExpression::Labeled(label_name, Box::new(loop_expr), label_declare.line));
+						} else {
+							scope_exprs.push(loop_expr);
+						}
+					}
+				}
+			} else if JumpParser::is_declaration(parser) {
+				let result = JumpParser::new(parser, file.to_string(), config_data, context, context_manager);
+				if result.is_error() {
+					result.print_error(file.to_string(), &parser.content);
+					break;
+				} else {
+					parser.increment();
+					let jump_declare = result.unwrap_and_move();
+					if let Some(value) = jump_declare.value {
+						scope_exprs.push(ScopeExpression::BreakValue(value, jump_declare.line));
+					} else if jump_declare.is_break {
+						scope_exprs.push(ScopeExpression::Break(jump_declare.label, jump_declare.line));
+					} else {
+						scope_exprs.push(ScopeExpression::Continue(jump_declare.label, jump_declare.line));
+					}
+				}
+			} else if ReturnParser::is_declaration(parser) {
 				let result = ReturnParser::new(parser, file.to_string(), config_data, context, context_manager, expected_return_type.clone());
 				if result.is_error() {
 					result.print_error(file.to_string(), &parser.content);
@@ -79,18 +171,30 @@ impl ScopeExpression {
 					if parser.get_curr() == ';' {
 						parser.increment();
 						let return_declare = result.unwrap_and_move();
-						scope_exprs.push(ScopeExpression::Return(return_declare.expression, return_declare.line));
+						scope_exprs.push(ScopeExpression::Return(return_declare.expression, return_declare.use_move, return_declare.tuple_types, return_declare.line));
 					}
 				}
 			} else if IfParser::is_declaration(parser) {
-				let result = IfParser::new(parser, file.to_string(), config_data, context, context_manager);
+				let follows_const_if = match scope_exprs.last() {
+					Some(ScopeExpression::If(_, is_const, _, _, _, _)) => *is_const,
+					_ => false
+				};
+				let result = IfParser::new(parser, file.to_string(), config_data, context, context_manager, follows_const_if);
 				if result.is_error() {
 					result.print_error(file.to_string(), &parser.content);
 					break;
 				} else {
-					parser.parse_whitespace();
 					let if_declare = result.unwrap_and_move();
-					scope_exprs.push(ScopeExpression::If(if_declare.if_type, if_declare.expression, if_declare.scope, if_declare.line, if_declare.end_line));
+					scope_exprs.push(ScopeExpression::If(if_declare.if_type, if_declare.is_const, if_declare.expression, if_declare.scope, if_declare.line, if_declare.end_line));
+				}
+			} else if GuardParser::is_declaration(parser) {
+				let result = GuardParser::new(parser, file.to_string(), config_data, context, context_manager, expected_return_type.clone());
+				if result.is_error() {
+					result.print_error(file.to_string(), &parser.content);
+					break;
+				} else {
+					let guard_declare = result.unwrap_and_move();
+					scope_exprs.push(ScopeExpression::Guard(guard_declare.expression, guard_declare.scope, guard_declare.line, guard_declare.end_line));
 				}
 			} else if WhileParser::is_declaration(parser) {
 				let result = WhileParser::new(parser, file.to_string(), config_data, context, context_manager);
@@ -98,7 +202,6 @@ impl ScopeExpression {
 					result.print_error(file.to_string(), &parser.content);
 					break;
 				} else {
-					parser.parse_whitespace();
 					let while_declare = result.unwrap_and_move();
 					scope_exprs.push(ScopeExpression::While(while_declare.while_type, while_declare.expression, while_declare.scope, while_declare.line, while_declare.end_line));
 				}
@@ -108,7 +211,6 @@ impl ScopeExpression {
 					result.print_error(file.to_string(), &parser.content);
 					break;
 				} else {
-					parser.parse_whitespace();
 					let loop_declare = result.unwrap_and_move();
 					scope_exprs.push(ScopeExpression::Loop(loop_declare.scope, loop_declare.line, loop_declare.end_line));
 				}
@@ -118,10 +220,18 @@ impl ScopeExpression {
 					result.print_error(file.to_string(), &parser.content);
 					break;
 				} else {
-					parser.parse_whitespace();
 					let do_while_declare = result.unwrap_and_move();
 					scope_exprs.push(ScopeExpression::DoWhile(do_while_declare.while_type, do_while_declare.expression, do_while_declare.scope, do_while_declare.line, do_while_declare.end_line, do_while_declare.while_offset));
 				}
+			} else if TryParser::is_declaration(parser) {
+				let result = TryParser::new(parser, file.to_string(), config_data, context, context_manager);
+				if result.is_error() {
+					result.print_error(file.to_string(), &parser.content);
+					break;
+				} else {
+					let try_declare = result.unwrap_and_move();
+					scope_exprs.push(ScopeExpression::Try(try_declare.scope, try_declare.catches, try_declare.line, try_declare.end_line));
+				}
 			} else if InjectParser::is_declaration(parser) {
 				let result = InjectParser::new(parser);
 				if result.is_error() {
@@ -138,7 +248,6 @@ impl ScopeExpression {
 					result.print_error(file.to_string(), &parser.content);
 					break;
 				} else {
-					parser.parse_whitespace();
 					let for_declare = result.unwrap_and_move();
 					if for_declare.for_type.is_for() {
 						scope_exprs.push(ScopeExpression::For(
@@ -181,27 +290,107 @@ impl ScopeExpression {
 					break;
 				} else {
 					let mut var_declare = result.unwrap_and_move();
-					if var_declare.value.is_some() {
+					if let Some((align, attribute_start)) = alignment {
+						if var_declare.bindings.is_some() {
+							let pos = Position::new(file.to_string(), None, attribute_start, Some(attribute_start + 6));
+							print_code_error("Invalid Alignment", "@Align cannot be used on structured bindings", &pos, &parser.content);
+						} else {
+							var_declare.alignment = Some(align);
+						}
+					}
+					let is_let = var_declare.var_type.var_style.is_inferred();
+					if var_declare.value.is_some() && var_declare.bindings.is_none() && Self::is_value_loop(&parser.content, var_declare.value.as_ref().unwrap().0) {
+						parser.reset(var_declare.value.as_ref().unwrap().0, var_declare.line);
+						parser.parse_whitespace();
+						let loop_start = parser.index;
+						let outer_loop_value_type = context.loop_value_type.replace(var_declare.var_type.clone());
+						let result = LoopParser::new(parser, file.to_string(), config_data, context, context_manager);
+						let loop_value_type = std::mem::replace(&mut context.loop_value_type, outer_loop_value_type).unwrap_or(VariableType::inferred());
+						if result.is_error() {
+							result.print_error(file.to_string(), &parser.content);
+							break;
+						}
+						let loop_declare = result.unwrap_and_move();
+						parser.parse_whitespace();
+						if parser.get_curr() != ';' {
+							let pos = Position::new(file.to_string(), None, parser.index, Some(parser.index + 1));
+							print_code_error("Semicolon Needed", "there should be a ; here", &pos, &parser.content);
+							break;
+						}
+						parser.increment();
+						if var_declare.var_type.is_inferred() {
+							if loop_value_type.is_inferred() {
+								let pos = Position::new(file.to_string(), None, loop_start, Some(loop_start + 4));
+								print_code_error("Break Value Expected", "loop must break with a value to initialize a variable", &pos, &parser.content);
+							}
+							var_declare.var_type.var_type = loop_value_type.var_type;
+							var_declare.var_type.var_optional = loop_value_type.var_optional;
+						}
+						if var_declare.var_type.var_style.is_inferred() {
+							var_declare.var_type.var_style = VarStyle::Copy;
+						}
+						if is_let && context.immutable_let {
+							var_declare.apply_immutable_let();
+						}
+						var_declare.var_type.resolve(context, context_manager);
+						context.register_type(&var_declare.var_type);
+						context.typing.add_variable(var_declare.name.clone(), var_declare.var_type.clone(), None);
+						scope_exprs.push(ScopeExpression::ValueLoop(var_declare, loop_declare.scope, loop_declare.line, loop_declare.end_line));
+					} else if var_declare.value.is_some() {
 						parser.reset(var_declare.value.as_ref().unwrap().0, var_declare.line);
 						let mut reason = ExpressionEndReason::Unknown;
+						let errors_before = error_count();
 						let expr = parser.parse_expression(file.to_string(), config_data, Some(context), context_manager, &mut reason, Some(var_declare.var_type.clone()));
-						if reason == ExpressionEndReason::EndOfExpression {
+						if reason != ExpressionEndReason::EndOfExpression {
+							Self::skip_invalid_statement(parser, file, errors_before);
+						} else {
 							parser.parse_whitespace();
-							if parser.get_curr() == ';' {
+							if parser.get_curr() != ';' {
+								Self::skip_invalid_statement(parser, file, errors_before);
+							} else {
 								parser.increment();
 								if var_declare.var_type.is_inferred() {
-									var_declare.var_type.var_type = expr.get_type().var_type;
+									let expr_type = expr.get_type();
+									var_declare.var_type.var_type = expr_type.var_type;
+									var_declare.var_type.var_optional = expr_type.var_optional;
 								}
 								if var_declare.var_type.var_style.is_inferred() {
 									var_declare.var_type.var_style = var_declare.var_type.var_style.attempt_inference(&expr.get_type());
 								}
+								if is_let && context.immutable_let {
+									var_declare.apply_immutable_let();
+								}
 								var_declare.var_type.resolve(context, context_manager);
 								context.register_type(&var_declare.var_type);
-								context.typing.add_variable(var_declare.name.clone(), var_declare.var_type.clone(), None);
+								if let Some(names) = &var_declare.bindings {
+									let value_range = var_declare.value.unwrap();
+									let element_types = match &var_declare.var_type.var_type {
+										Type::Tuple(types) => Some(types.clone()),
+										_ => None
+									};
+									if let Some(types) = element_types.as_ref().filter(|types| types.len() != names.len()) {
+										let pos = Position::new(file.to_string(), None, value_range.0, Some(value_range.1));
+										let msg = format!("{} names cannot bind a tuple of {} elements", names.len(), types.len());
+										print_code_error("Binding Count Mismatch", &msg, &pos, &parser.content);
+									}
+									for (i, name) in names.iter().enumerate() {
+										let binding_type = match &element_types {
+											Some(types) if i < types.len() => types[i].clone(),
+											_ => VariableType::inferred()
+										};
+										context.typing.add_variable(name.clone(), binding_type, None);
+									}
+								} else {
+									context.typing.add_variable(var_declare.name.clone(), var_declare.var_type.clone(), None);
+								}
 								scope_exprs.push(ScopeExpression::VariableDeclaration(var_declare, Some(expr)));
 							}
 						}
 					} else {
+						if let Some(uninit_range) = var_declare.uninit.filter(|_| config_data.strict) {
+							let pos = Position::new(file.to_string(), None, uninit_range.0, Some(uninit_range.1));
+							print_code_warning("Uninitialized Variable", &format!("\"{}\" is intentionally left uninitialized", var_declare.name), &pos, &parser.content);
+						}
 						var_declare.var_type.resolve(context, context_manager);
 						context.register_type(&var_declare.var_type);
 						context.typing.add_variable(var_declare.name.clone(), var_declare.var_type.clone(), None);
@@ -227,22 +416,91 @@ impl ScopeExpression {
 					break;
 				}
 				let mut reason = ExpressionEndReason::Unknown;
+				let expr_start = parser.index;
+				let errors_before = error_count();
 				let expr = parser.parse_expression(file.to_string(), config_data, Some(context), context_manager, &mut reason, None);
 				if reason != ExpressionEndReason::EndOfExpression {
 					break;
 				} else {
+					if config_data.strict && Self::is_no_discard_call(&expr) {
+						let pos = Position::new(file.to_string(), None, expr_start, Some(parser.index));
+						print_code_warning("Discarded Result", "the result of this [[nodiscard]] function is ignored", &pos, &parser.content);
+					}
 					parser.parse_whitespace();
 					if parser.get_curr() == ';' {
 						parser.increment();
 						scope_exprs.push(ScopeExpression::Expression(expr));
+					} else {
+						Self::skip_invalid_statement(parser, file, errors_before);
 					}
 				}
 			}
+			if is_nested_loop {
+				context.loop_value_type = loop_value_type;
+			}
 		}
 
 		return ScopeExpression::Scope(scope_exprs);
 	}
 
+	/// Reports content that ends a statement early, unless its expression already reported an error.
+	/// Parsing resumes after the statement's `;`, or at the `}` that ends the scope.
+	fn skip_invalid_statement(parser: &mut Parser, file: &str, errors_before: usize) {
+		if error_count() == errors_before {
+			let pos = Position::new(file.to_string(), None, parser.index, Some(parser.index + 1));
+			print_code_error("Expected Semicolon", "expected ';' to end the statement here", &pos, &parser.content);
+		}
+		while !parser.out_of_space && parser.get_curr() != '}' {
+			if parser.get_curr() == ';' {
+				parser.increment();
+				break;
+			}
+			if !parser.parse_string() && parser.get_curr() == '\n' {
+				parser.line += 1;
+			}
+			parser.increment();
+		}
+	}
+
+	/// Retrieves the alignment from the attributes preceding a local variable, along with where `@Align` starts.
+	/// `@Align` is the only attribute that can be used within a scope.
+	fn get_local_alignment(attributes: Vec<(AttributeDeclaration, usize)>, is_variable: bool, file: &str, content: &str) -> Option<(usize, usize)> {
+		let mut result = None;
+		for (attribute, start) in attributes {
+			let pos = Position::new(file.to_string(), None, start, Some(start + attribute.name.len() + 1));
+			if !is_variable {
+				print_code_error("Invalid Attribute", "attributes within a scope can only be used on variables", &pos, content);
+			} else if attribute.name != "Align" {
+				print_code_error("Invalid Attribute", "only @Align can be used on local variables", &pos, content);
+			} else {
+				match ClassDeclaration::get_alignment(&Attributes::new(Some(vec![attribute])), content) {
+					Some(alignment) => result = Some((alignment, start)),
+					None => print_code_error("Invalid Alignment", "@Align requires a single power-of-two integer", &pos, content)
+				}
+			}
+		}
+		result
+	}
+
+	/// Checks whether a variable's value is a loop that provides its value through break.
+	fn is_value_loop(content: &str, value_start: usize) -> bool {
+		let value = &content[value_start..];
+		LoopParser::is_loop_declaration(value, value.len() - value.trim_start().len())
+	}
+
+	/// Checks whether the expression is a call to a function marked with `@NoDiscard`.
+	fn is_no_discard_call(expr: &Expression) -> bool {
+		match expr {
+			Expression::FunctionCall(callee, _, _, _) => match &callee.get_type().var_type {
+				Type::Function(func) => func.no_discard,
+				Type::QuantumFunction(funcs) => !funcs.is_empty() && funcs.iter().all(|f| f.no_discard),
+				_ => false
+			},
+			Expression::Expressions(exprs, _, _) if exprs.len() == 1 => Self::is_no_discard_call(&exprs[0]),
+			_ => false
+		}
+	}
+
 	pub fn to_string(&self, operators: &OperatorDataStructure, line_offset: usize, tab_offset: usize, context: &mut Context) -> String {
 		return match self {
 			ScopeExpression::Scope(exprs) => {
@@ -255,7 +513,7 @@ impl ScopeExpression {
 					let line_number = if context.align_lines {
 						real_line_number
 					} else {
-						if e.is_extend() {
+						if e.is_extend() && !context.uses_allman_braces() {
 							last_line_offset
 						} else if real_line_number - real_last_line_offset > 1 {
 							last_line_offset + 2
@@ -376,18 +634,72 @@ impl ScopeExpression {
 			ScopeExpression::VariableDeclaration(declaration, expr) => {
 				declaration.to_cpp(expr, operators, context, VariableExportType::Scoped)
 			},
-			ScopeExpression::Return(expr, _) => {
+			ScopeExpression::ValueLoop(declaration, scope, line, end_line) => {
+				let scope_str = scope.to_string(operators, *line, tab_offset, context);
+				let loop_str = format!("while(true){}", self.format_scope_contents(&scope_str, context, line, end_line));
+				let lambda = format!("[&](){}{{\n{}\n}}()", if context.uses_allman_braces() { "\n" } else { " " }, loop_str.split("\n").map(|l| format!("\t{}", l)).collect::<Vec<String>>().join("\n"));
+				let position = Position::new("".to_string(), Some(*line + 1), 0, None);
+				let expr = Rc::new(Expression::Value(lambda, declaration.var_type.clone(), position));
+				declaration.to_cpp(&Some(expr), operators, context, VariableExportType::Scoped)
+			},
+			ScopeExpression::Labeled(name, expr, _) => {
+				format!("{}{}{}_break:;", expr.to_string(operators, line_offset, tab_offset, context), if context.align_lines { " " } else { "\n" }, name)
+			},
+			ScopeExpression::Label(name, _) => {
+				format!("{}:;", name)
+			},
+			ScopeExpression::Break(label, _) => {
+				if label.is_none() {
+					"break;".to_string()
+				} else {
+					format!("goto {}_break;", label.as_ref().unwrap())
+				}
+			},
+			ScopeExpression::BreakValue(expr, _) => {
+				format!("return {};", expr.to_string(operators, context))
+			},
+			ScopeExpression::Continue(label, _) => {
+				if label.is_none() {
+					"continue;".to_string()
+				} else {
+					format!("goto {}_continue;", label.as_ref().unwrap())
+				}
+			},
+			ScopeExpression::Try(scope, catches, line, end_line) => {
+				let try_end_line = catches.first().map(|c| c.3).unwrap_or(*end_line);
+				let scope_str = scope.to_string(operators, *line, tab_offset, context);
+				let mut result = format!("try{}", self.format_scope_contents(&scope_str, context, line, &try_end_line));
+				for (catch_type, catch_name, catch_scope, catch_line, catch_end_line) in catches {
+					let catch_str = catch_scope.to_string(operators, *catch_line, tab_offset, context);
+					result += format!("{}catch({}){}", if context.uses_allman_braces() { "\n" } else { " " }, if catch_name.is_empty() {
+						"...".to_string()
+					} else {
+						format!("{} {}", catch_type.to_cpp(&context.library), catch_name)
+					}, self.format_scope_contents(&catch_str, context, catch_line, catch_end_line)).as_str();
+				}
+				result
+			},
+			ScopeExpression::Return(expr, use_move, tuple_types, _) => {
 				if expr.is_none() {
 					"return;".to_string()
+				} else if let (Some(types), Expression::Expressions(exprs, _, _)) = (tuple_types, &**expr.as_ref().unwrap()) {
+					context.add_header("tuple", true);
+					let elements = exprs.iter().zip(types.iter()).map(|(e, t)| {
+						let content = e.to_string(operators, context);
+						e.get_type().convert_between_styles(t, &content, &context.library).unwrap_or(content)
+					}).collect::<Vec<String>>();
+					format!("return {}({});", context.library.std_name("make_tuple"), elements.join(", "))
+				} else if *use_move {
+					format!("return {}({});", context.library.std_name("move"), expr.as_ref().unwrap().to_string(operators, context))
 				} else {
 					format!("return {};", expr.as_ref().unwrap().to_string(operators, context))
 				}
 			},
 			ScopeExpression::SubScope(scope, line, end_line) => {
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("{}", self.format_scope_contents(&scope_str, context, line, end_line))
+				self.format_scope_contents(&scope_str, context, line, end_line).trim_start().to_string()
 			},
-			ScopeExpression::If(if_type, expr, scope, line, end_line) => {
+			ScopeExpression::If(if_type, is_const, expr, scope, line, end_line) => {
 				let expr_str = if expr.is_none() {
 					"".to_string()
 				} else if if_type.is_unless() || if_type.is_elseunless() {
@@ -396,14 +708,14 @@ impl ScopeExpression {
 					expr.as_ref().unwrap().to_string(operators, context)
 				};
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("{} {}", if if_type.is_else() {
+				format!("{}{}", if if_type.is_else() {
 						"else".to_string()
 					} else {
-						format!("{}if({})", if if_type.is_elseif() || if_type.is_elseunless() {
+						format!("{}if{}({})", if if_type.is_elseif() || if_type.is_elseunless() {
 								"else "
 							} else {
 								""
-							}, if context.align_lines {
+							}, if *is_const { " constexpr" } else { "" }, if context.align_lines {
 								&expr_str
 							} else {
 								expr_str.trim()
@@ -411,6 +723,15 @@ impl ScopeExpression {
 						)
 					}, self.format_scope_contents(&scope_str, context, line, end_line))
 			},
+			ScopeExpression::Guard(expr, scope, line, end_line) => {
+				let expr_str = expr.reverse_bool().to_string(operators, context);
+				let scope_str = scope.to_string(operators, *line, tab_offset, context);
+				format!("if({}){}", if context.align_lines {
+					&expr_str
+				} else {
+					expr_str.trim()
+				}, self.format_scope_contents(&scope_str, context, line, end_line))
+			},
 			ScopeExpression::While(while_type, expr, scope, line, end_line) => {
 				let expr_str = if while_type.is_until() {
 					expr.reverse_bool().to_string(operators, context)
@@ -418,7 +739,7 @@ impl ScopeExpression {
 					expr.to_string(operators, context)
 				};
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("while({}) {}", if context.align_lines {
+				format!("while({}){}", if context.align_lines {
 					&expr_str
 				} else {
 					expr_str.trim()
@@ -426,7 +747,7 @@ impl ScopeExpression {
 			},
 			ScopeExpression::Loop(scope, line, end_line) => {
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("while(true) {}", self.format_scope_contents(&scope_str, context, line, end_line))
+				format!("while(true){}", self.format_scope_contents(&scope_str, context, line, end_line))
 			},
 			ScopeExpression::DoWhile(while_type, expr, scope, line, end_line, while_offset) => {
 				let expr_str = if while_type.is_until() {
@@ -435,11 +756,11 @@ impl ScopeExpression {
 					expr.to_string(operators, context)
 				};
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("do {}{}while({});",
+				format!("do{}{}while({});",
 					self.format_scope_contents(&scope_str, context, line, end_line),
 					if context.align_lines {
 						let tabs = String::from_utf8(vec![b'\t'; tab_offset]).unwrap_or("".to_string());
-						let mut result = "".to_string();
+						let mut result = if *while_offset == 0 { " ".to_string() } else { "".to_string() };
 						for _ in 0..*while_offset {
 							result += format!("{}\n", tabs).as_str();
 						}
@@ -455,7 +776,7 @@ impl ScopeExpression {
 			ScopeExpression::For(name, expr, scope, line, end_line) => {
 				let expr_str = expr.to_string(operators, context);
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("for(auto& {} : {}) {}", name, if context.align_lines {
+				format!("for(auto& {} : {}){}", name, if context.align_lines {
 					&expr_str
 				} else {
 					expr_str.trim()
@@ -466,7 +787,7 @@ impl ScopeExpression {
 				let end_str = end_expr.to_string(operators, context);
 				let by_str = if by_expr.is_none() { None } else { Some(by_expr.as_ref().unwrap().to_string(operators, context)) };
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("for({} {} = {}; {} {} {}; {}) {}", start_expr.get_type().to_cpp(), name, if context.align_lines {
+				format!("for({} {} = {}; {} {} {}; {}){}", ForParser::get_counter_type(start_expr, end_expr, by_expr).to_cpp(&context.library), name, if context.align_lines {
 					&start_str
 				} else {
 					start_str.trim()
@@ -479,9 +800,9 @@ impl ScopeExpression {
 					end_str.trim()
 				},
 				if by_str.is_none() {
-					"i++".to_string()
+					format!("{}++", name)
 				} else {
-					format!("i += {}", if context.align_lines {
+					format!("{} += {}", name, if context.align_lines {
 						&by_str.as_ref().unwrap()
 					} else {
 						by_str.as_ref().unwrap().trim()
@@ -493,7 +814,7 @@ impl ScopeExpression {
 				let end_str = end_expr.to_string(operators, context);
 				let by_str = if by_expr.is_none() { None } else { Some(by_expr.as_ref().unwrap().to_string(operators, context)) };
 				let scope_str = scope.to_string(operators, *line, tab_offset, context);
-				format!("for({} {} = {}; {} {} {}; {}) {}", start_expr.get_type().to_cpp(), name, if context.align_lines {
+				format!("for({} {} = {}; {} {} {}; {}){}", ForParser::get_counter_type(start_expr, end_expr, by_expr).to_cpp(&context.library), name, if context.align_lines {
 					&start_str
 				} else {
 					start_str.trim()
@@ -506,9 +827,9 @@ impl ScopeExpression {
 					end_str.trim()
 				},
 				if by_str.is_none() {
-					"i--".to_string()
+					format!("{}--", name)
 				} else {
-					format!("i -= {}", if context.align_lines {
+					format!("{} -= {}", name, if context.align_lines {
 						&by_str.as_ref().unwrap()
 					} else {
 						by_str.as_ref().unwrap().trim()
@@ -525,9 +846,9 @@ impl ScopeExpression {
 			for _ in re.split(&scope_str) {
 				final_line += 1;
 			}
-			return format!("{{{}{}}}", scope_str, if final_line == *end_line { " " } else { "\n" });
+			return format!(" {{{}{}}}", scope_str, if final_line == *end_line { " " } else { "\n" });
 		}
-		return format!("{{\n\t{}\n}}", scope_str.trim());
+		format!("{}{{\n\t{}\n}}", if context.uses_allman_braces() { "\n" } else { " " }, scope_str.trim())
 	}
 
 	pub fn get_expression(&self) -> Option<Rc<Expression>> {
@@ -540,7 +861,7 @@ impl ScopeExpression {
 					None
 				}
 			},
-			ScopeExpression::Return(expr, _) => if expr.is_some() {
+			ScopeExpression::Return(expr, _, _, _) => if expr.is_some() {
 				Some(Rc::clone(&expr.as_ref().unwrap()))
 			} else {
 				None
@@ -554,8 +875,10 @@ impl ScopeExpression {
 			ScopeExpression::Expression(expr) => expr.get_line_number(),
 			ScopeExpression::SubScope(_, line, _) => Some(*line),
 			ScopeExpression::VariableDeclaration(declare, _) => Some(declare.line),
-			ScopeExpression::Return(_, line) => Some(*line),
-			ScopeExpression::If(_, _, _, line, _) => Some(*line),
+			ScopeExpression::ValueLoop(declare, _, _, _) => Some(declare.line),
+			ScopeExpression::Return(_, _, _, line) => Some(*line),
+			ScopeExpression::If(_, _, _, _, line, _) => Some(*line),
+			ScopeExpression::Guard(_, _, line, _) => Some(*line),
 			ScopeExpression::While(_, _, _, line, _) => Some(*line),
 			ScopeExpression::Loop(_, line, _) => Some(*line),
 			ScopeExpression::DoWhile(_, _, _, line, _, _) => Some(*line),
@@ -563,6 +886,12 @@ impl ScopeExpression {
 			ScopeExpression::Increment(_, _, _, _, _, _, line, _) => Some(*line),
 			ScopeExpression::Decrement(_, _, _, _, _, _, line, _) => Some(*line),
 			ScopeExpression::Injection(_, line, _) => Some(*line),
+			ScopeExpression::Labeled(_, _, line) => Some(*line),
+			ScopeExpression::Label(_, line) => Some(*line),
+			ScopeExpression::Break(_, line) => Some(*line),
+			ScopeExpression::BreakValue(_, line) => Some(*line),
+			ScopeExpression::Continue(_, line) => Some(*line),
+			ScopeExpression::Try(_, _, line, _) => Some(*line),
 			_ => None
 		};
 	}
@@ -571,7 +900,8 @@ impl ScopeExpression {
 		return match self {
 			ScopeExpression::Expression(expr) => expr.get_line_number(),
 			ScopeExpression::SubScope(_, _, end_line) => Some(*end_line),
-			ScopeExpression::If(_, _, _, _, end_line) => Some(*end_line),
+			ScopeExpression::If(_, _, _, _, _, end_line) => Some(*end_line),
+			ScopeExpression::Guard(_, _, _, end_line) => Some(*end_line),
 			ScopeExpression::While(_, _, _, _, end_line) => Some(*end_line),
 			ScopeExpression::Loop(_, _, end_line) => Some(*end_line),
 			ScopeExpression::DoWhile(_, _, _, _, end_line, while_line) => Some(*while_line + *end_line),
@@ -579,13 +909,306 @@ impl ScopeExpression {
 			ScopeExpression::Increment(_, _, _, _, _, _, _, end_line) => Some(*end_line),
 			ScopeExpression::Decrement(_, _, _, _, _, _, _, end_line) => Some(*end_line),
 			ScopeExpression::Injection(_, _, end_line) => Some(*end_line),
+			ScopeExpression::Labeled(_, expr, _) => expr.get_end_line(),
+			ScopeExpression::Label(_, line) => Some(*line),
+			ScopeExpression::Break(_, line) => Some(*line),
+			ScopeExpression::BreakValue(_, line) => Some(*line),
+			ScopeExpression::Continue(_, line) => Some(*line),
+			ScopeExpression::Try(_, _, _, end_line) => Some(*end_line),
+			ScopeExpression::ValueLoop(_, _, _, end_line) => Some(*end_line),
 			_ => None
 		};
 	}
 
+	pub fn get_loop_scope_mut(&mut self) -> Option<&mut ScopeExpression> {
+		match self {
+			ScopeExpression::While(_, _, scope, _, _) => Some(&mut **scope),
+			ScopeExpression::Loop(scope, _, _) => Some(&mut **scope),
+			ScopeExpression::DoWhile(_, _, scope, _, _, _) => Some(&mut **scope),
+			ScopeExpression::For(_, _, scope, _, _) => Some(&mut **scope),
+			ScopeExpression::Increment(_, _, _, _, scope, _, _, _) => Some(&mut **scope),
+			ScopeExpression::Decrement(_, _, _, _, scope, _, _, _) => Some(&mut **scope),
+			_ => None
+		}
+	}
+
+	/// Checks whether a `break` or `continue` within this expression jumps to the label.
+	fn jumps_to_label(&self, label: &str, is_break: bool) -> bool {
+		match self {
+			ScopeExpression::Break(Some(name), _) => is_break && name == label,
+			ScopeExpression::Continue(Some(name), _) => !is_break && name == label,
+			_ => self.get_child_scopes().iter().any(|s| s.jumps_to_label(label, is_break))
+		}
+	}
+
+	/// Retrieves the scopes nested directly within this expression.
+	fn get_child_scopes(&self) -> Vec<&ScopeExpression> {
+		match self {
+			ScopeExpression::Scope(exprs) => exprs.iter().collect(),
+			ScopeExpression::SubScope(scope, _, _) |
+			ScopeExpression::ValueLoop(_, scope, _, _) |
+			ScopeExpression::If(_, _, _, scope, _, _) |
+			ScopeExpression::Guard(_, scope, _, _) |
+			ScopeExpression::While(_, _, scope, _, _) |
+			ScopeExpression::Loop(scope, _, _) |
+			ScopeExpression::DoWhile(_, _, scope, _, _, _) |
+			ScopeExpression::For(_, _, scope, _, _) |
+			ScopeExpression::Increment(_, _, _, _, scope, _, _, _) |
+			ScopeExpression::Decrement(_, _, _, _, scope, _, _, _) |
+			ScopeExpression::Labeled(_, scope, _) => vec![&**scope],
+			ScopeExpression::Try(scope, catches, _, _) => {
+				let mut result = vec![&**scope];
+				result.extend(catches.iter().map(|c| &*c.2));
+				result
+			},
+			_ => Vec::new()
+		}
+	}
+
+	/// Retrieves the expressions evaluated by this expression, excluding those within its nested scopes.
+	fn get_own_expressions(&self) -> Vec<&Rc<Expression>> {
+		match self {
+			ScopeExpression::Expression(expr) |
+			ScopeExpression::VariableDeclaration(_, Some(expr)) |
+			ScopeExpression::Return(Some(expr), _, _, _) |
+			ScopeExpression::If(_, _, Some(expr), _, _, _) |
+			ScopeExpression::Guard(expr, _, _, _) |
+			ScopeExpression::While(_, expr, _, _, _) |
+			ScopeExpression::DoWhile(_, expr, _, _, _, _) |
+			ScopeExpression::For(_, expr, _, _, _) |
+			ScopeExpression::BreakValue(expr, _) => vec![expr],
+			ScopeExpression::Increment(_, start, end, by, _, _, _, _) |
+			ScopeExpression::Decrement(_, start, end, by, _, _, _, _) => {
+				let mut result = vec![start, end];
+				result.extend(by.iter());
+				result
+			},
+			_ => Vec::new()
+		}
+	}
+
+	/// Checks whether the scope may modify the object of the class it is used within, for `--infer-const-methods`.
+	/// `locals` holds the parameters and variables that hide the members of the same name.
+	pub fn may_modify_members(&self, members: &ClassMembers, locals: &Vec<String>) -> bool {
+		let mut inner_locals = locals.clone();
+		match self {
+			ScopeExpression::Scope(exprs) => {
+				for expr in exprs {
+					if expr.may_modify_members(members, &inner_locals) {
+						return true;
+					}
+					if let ScopeExpression::VariableDeclaration(var_declare, _) | ScopeExpression::ValueLoop(var_declare, _, _, _) = expr {
+						inner_locals.push(var_declare.name.clone());
+						inner_locals.extend(var_declare.bindings.iter().flatten().cloned());
+					}
+				}
+				return false;
+			},
+			ScopeExpression::Injection(..) => return true,
+			ScopeExpression::VariableDeclaration(var_declare, Some(value)) => {
+				// A reference to a member could be used to modify it later.
+				let is_copy = matches!(var_declare.var_type.var_style, VarStyle::Copy | VarStyle::Borrow | VarStyle::Infer);
+				if !is_copy && value.is_member_access(members, locals) {
+					return true;
+				}
+			},
+			ScopeExpression::For(name, expr, _, _, _) => {
+				if expr.is_member_access(members, locals) {
+					return true;
+				}
+				inner_locals.push(name.clone());
+			},
+			ScopeExpression::Increment(name, _, _, _, _, _, _, _) | ScopeExpression::Decrement(name, _, _, _, _, _, _, _) => {
+				inner_locals.push(name.clone());
+			},
+			_ => ()
+		}
+		self.get_own_expressions().iter().any(|e| e.may_modify_members(members, locals)) ||
+			self.get_child_scopes().iter().any(|s| s.may_modify_members(members, &inner_locals))
+	}
+
+	/// Checks whether the scope could be the body of a `constexpr` function for `--auto-constexpr`.
+	/// Anything that cannot be checked, such as injected code, disqualifies the scope.
+	pub fn is_constexpr_compatible(&self, locals: &Vec<String>, constexpr_functions: &Vec<String>) -> bool {
+		let mut inner_locals = locals.clone();
+		match self {
+			ScopeExpression::Scope(exprs) => {
+				for expr in exprs {
+					if !expr.is_constexpr_compatible(&inner_locals, constexpr_functions) {
+						return false;
+					}
+					if let ScopeExpression::VariableDeclaration(var_declare, _) = expr {
+						inner_locals.push(var_declare.name.clone());
+					}
+				}
+				return true;
+			},
+			ScopeExpression::VariableDeclaration(var_declare, Some(value)) => {
+				let var_type = if var_declare.var_type.is_inferred() { value.get_type() } else { var_declare.var_type.clone() };
+				let is_local_value = matches!(var_declare.var_type.var_style, VarStyle::Copy | VarStyle::Infer);
+				let is_automatic = var_declare.var_type.var_properties.as_ref()
+					.map(|props| props.iter().all(|p| *p == VarProps::Const || *p == VarProps::Constexpr))
+					.unwrap_or(true);
+				if var_declare.bindings.is_some() || !is_local_value || !is_automatic || !(var_type.is_number() || var_type.is_boolean()) {
+					return false;
+				}
+			},
+			ScopeExpression::Increment(name, _, _, _, _, _, _, _) | ScopeExpression::Decrement(name, _, _, _, _, _, _, _) => {
+				inner_locals.push(name.clone());
+			},
+			ScopeExpression::Return(_, _, Some(_), _) |
+			ScopeExpression::VariableDeclaration(_, None) |
+			ScopeExpression::ValueLoop(..) |
+			ScopeExpression::BreakValue(..) |
+			ScopeExpression::For(..) |
+			ScopeExpression::Injection(..) |
+			ScopeExpression::Labeled(..) |
+			ScopeExpression::Label(..) |
+			ScopeExpression::Try(..) => return false,
+			_ => ()
+		}
+		self.get_own_expressions().iter().all(|e| e.is_constexpr_compatible(locals, constexpr_functions)) &&
+			self.get_child_scopes().iter().all(|s| s.is_constexpr_compatible(&inner_locals, constexpr_functions))
+	}
+
+	/// Checks whether the variable's value is used within this expression.
+	/// Injected code is assumed to read it if the name appears within it.
+	pub fn reads_variable(&self, name: &str) -> bool {
+		if let ScopeExpression::Injection(content, _, _) = self {
+			return Regex::new(&format!(r"\b{}\b", regex::escape(name))).map(|re| re.is_match(content)).unwrap_or(true);
+		}
+		self.get_own_expressions().iter().any(|e| e.reads_variable(name)) ||
+			self.get_child_scopes().iter().any(|s| s.reads_variable(name))
+	}
+
+	/// Prints a warning for each local variable that is never read after it is declared.
+	/// Variables with side-effecting initializers are skipped, since the declaration may exist for those effects.
+	pub fn warn_unused_variables(&self, file: &str, content: &str) {
+		if let ScopeExpression::Scope(exprs) = self {
+			for (i, expr) in exprs.iter().enumerate() {
+				if let ScopeExpression::VariableDeclaration(var_declare, value) = expr {
+					let has_side_effects = value.as_ref().map(|v| v.has_side_effects()).unwrap_or(false);
+					if var_declare.bindings.is_none() && !has_side_effects && !exprs[i + 1..].iter().any(|e| e.reads_variable(&var_declare.name)) {
+						let pos = Self::get_name_position(&var_declare.name, var_declare.line, file, content);
+						print_code_warning("Unused Variable", &format!("\"{}\" is never read", var_declare.name), &pos, content);
+					}
+				}
+			}
+		}
+		for scope in self.get_child_scopes() {
+			scope.warn_unused_variables(file, content);
+		}
+	}
+
+	/// Finds the position of the name on the line it is declared on.
+	fn get_name_position(name: &str, line: usize, file: &str, content: &str) -> Position {
+		let line_content = content.split('\n').nth(line).unwrap_or("");
+		let start = Regex::new(&format!(r"\b{}\b", regex::escape(name))).ok()
+			.and_then(|re| re.find(line_content))
+			.map(|m| line_content[..m.start()].chars().count())
+			.unwrap_or(0);
+		Position::new(file.to_string(), Some(line + 1), start, Some(start + name.chars().count()))
+	}
+
+	/// Checks whether control can never continue past the end of this expression.
+	/// Injected code is trusted since its contents cannot be checked.
+	pub fn is_diverging(&self) -> bool {
+		match self {
+			ScopeExpression::Scope(exprs) => exprs.last().map(|e| e.is_diverging()).unwrap_or(false),
+			ScopeExpression::SubScope(scope, _, _) => scope.is_diverging(),
+			ScopeExpression::Return(..) | ScopeExpression::Break(..) | ScopeExpression::BreakValue(..) | ScopeExpression::Continue(..) | ScopeExpression::Injection(..) => true,
+			_ => false
+		}
+	}
+
+	/// Checks whether control can never leave this expression, as required by `@NoReturn`.
+	/// Throwing, calling a function that exits the program, and looping forever never leave.
+	/// Injected code is trusted since its contents cannot be checked.
+	pub fn never_returns(&self) -> bool {
+		!self.contains_return() && self.never_completes()
+	}
+
+	fn contains_return(&self) -> bool {
+		if let ScopeExpression::Return(..) = self {
+			return true;
+		}
+		self.get_child_scopes().iter().any(|s| s.contains_return())
+	}
+
+	fn never_completes(&self) -> bool {
+		match self {
+			ScopeExpression::Scope(exprs) => {
+				for (i, expr) in exprs.iter().enumerate() {
+					if expr.never_completes() {
+						return true;
+					}
+					// An if chain never completes if it has an else and none of its branches complete.
+					if let ScopeExpression::If(if_type, _, _, _, _, _) = expr {
+						if if_type.is_if() || if_type.is_unless() {
+							let chain_end = exprs[i + 1..].iter().position(|e| !e.is_extend()).map(|p| i + 1 + p).unwrap_or(exprs.len());
+							let chain = &exprs[i..chain_end];
+							let has_else = chain.iter().any(|e| match e {
+								ScopeExpression::If(t, _, _, _, _, _) => t.is_else(),
+								_ => false
+							});
+							if has_else && chain.iter().all(|e| e.get_child_scopes().iter().all(|s| s.never_completes())) {
+								return true;
+							}
+						}
+					}
+				}
+				false
+			},
+			ScopeExpression::Expression(expr) => Self::is_exiting_expression(expr),
+			ScopeExpression::SubScope(scope, _, _) | ScopeExpression::Labeled(_, scope, _) => scope.never_completes(),
+			ScopeExpression::Loop(scope, _, _) => !scope.breaks_loop(false),
+			ScopeExpression::Try(scope, catches, _, _) => scope.never_completes() && catches.iter().all(|c| c.2.never_completes()),
+			ScopeExpression::Injection(..) => true,
+			_ => false
+		}
+	}
+
+	/// Checks whether a `break` within this expression could leave the enclosing loop.
+	/// Within nested loops, only labeled breaks are assumed to leave it.
+	fn breaks_loop(&self, nested: bool) -> bool {
+		match self {
+			ScopeExpression::Break(label, _) => label.is_some() || !nested,
+			ScopeExpression::BreakValue(..) => !nested,
+			ScopeExpression::ValueLoop(..) |
+			ScopeExpression::While(..) |
+			ScopeExpression::Loop(..) |
+			ScopeExpression::DoWhile(..) |
+			ScopeExpression::For(..) |
+			ScopeExpression::Increment(..) |
+			ScopeExpression::Decrement(..) => self.get_child_scopes().iter().any(|s| s.breaks_loop(true)),
+			_ => self.get_child_scopes().iter().any(|s| s.breaks_loop(nested))
+		}
+	}
+
+	/// Checks whether the expression throws or calls a function that exits the program.
+	fn is_exiting_expression(expr: &Expression) -> bool {
+		match expr {
+			Expression::Prefix(_, 11, _, _) => true,
+			Expression::Expressions(exprs, _, _) => expr.is_grouping() && Self::is_exiting_expression(&exprs[0]),
+			Expression::FunctionCall(func, _, _, _) => {
+				let name = match &**func {
+					Expression::Value(name, _, _) => name.clone(),
+					Expression::Infix(namespace, name, 0, _, _) => match (&**namespace, &**name) {
+						(Expression::Value(namespace, _, _), Expression::Value(name, _, _)) => format!("{}::{}", namespace, name),
+						_ => return false
+					},
+					_ => return false
+				};
+				let name = name.trim_start_matches("std::");
+				name == "abort" || name == "exit" || name == "quick_exit" || name == "_Exit" || name == "terminate"
+			},
+			_ => false
+		}
+	}
+
 	pub fn is_extend(&self) -> bool {
 		return match self {
-			ScopeExpression::If(if_type, _, _, _, _) => if_type.is_elseif() || if_type.is_else(),
+			ScopeExpression::If(if_type, _, _, _, _, _) => if_type.is_elseif() || if_type.is_else(),
 			_ => false
 		};
 	}
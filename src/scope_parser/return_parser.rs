@@ -13,7 +13,7 @@ use crate::config_management::ConfigData;
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionEndReason;
-use crate::expression::variable_type::VariableType;
+use crate::expression::variable_type::{ VariableType, VarStyle, Type };
 
 use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
 use crate::declaration_parser::parser::Parser;
@@ -34,6 +34,8 @@ type ReturnParserResult = DeclarationResult<ReturnParser>;
 
 pub struct ReturnParser {
 	pub expression: Option<Rc<Expression>>,
+	pub use_move: bool,
+	pub tuple_types: Option<Vec<VariableType>>,
 	pub line: usize
 }
 
@@ -61,6 +63,7 @@ impl ReturnParser {
 
 		declare_parse_whitespace!(parser);
 
+		let expr_start = parser.index;
 		let mut expression: Option<Rc<Expression>> = None;
 		if parser.get_curr() != ';' {
 			let mut reason = ExpressionEndReason::Unknown;
@@ -71,22 +74,120 @@ impl ReturnParser {
 				ExpressionEndReason::EndOfContent =>  return ReturnParserResult::Err("Unexpected End of Expression", "unexpected end of expression", parser.index - 1, parser.index),
 				ExpressionEndReason::NoValueError => return ReturnParserResult::Err("Value Expected", "expression value expected here", parser.index - 1, parser.index),
 				ExpressionEndReason::EndOfExpression => {
-					let old_index = parser.index;
 					declare_parse_whitespace!(parser);
 					if parser.get_curr() != ';' {
-						return ReturnParserResult::Err("Semicolon Needed", "there should be a ; here", old_index - 1, old_index);
+						return ReturnParserResult::Err("Semicolon Needed", "there should be a ; here", parser.index, parser.index + 1);
 					}
 				},
 				_ => ()
 			}
 		}
 
+		// Values separated by commas are bundled into the tuple the function returns.
+		if let (Some(expr), Some(ret)) = (&expression, &context.function_return_type) {
+			if let (Expression::Infix(_, _, 41, _, position), Type::Tuple(_)) = (&**expr, &ret.var_type) {
+				let mut elements = Vec::new();
+				Self::flatten_comma_expression(expr, &mut elements);
+				expression = Some(Rc::new(Expression::Expressions(Rc::new(elements), ret.clone(), position.clone())));
+			}
+		}
+
+		let mut tuple_types = None;
+		if let (Some(expr), Some(ret)) = (&expression, &context.function_return_type) {
+			if let (Expression::Expressions(exprs, _, _), Type::Tuple(types)) = (&**expr, &ret.var_type) {
+				if exprs.len() > 1 {
+					if exprs.len() != types.len() {
+						return ReturnParserResult::Err("Tuple Size Mismatch", "number of returned values does not match the declared tuple type", expr_start, parser.index);
+					}
+					for (e, t) in exprs.iter().zip(types.iter()) {
+						if !e.get_type().could_convert_to(t) {
+							let (start, end) = Self::get_element_range(e, expr_start, parser.index);
+							return ReturnParserResult::Err("Tuple Type Mismatch", "returned value does not match the declared tuple element type", start, end);
+						}
+					}
+					tuple_types = Some(types.clone());
+				}
+			}
+		}
+
+		// Methods returning their class by value or reference return the object "this" points to.
+		if let (Some(expr), Some(ret)) = (&expression, &context.function_return_type) {
+			if let Expression::Value(name, var_type, position) = &**expr {
+				if name == "this" && !ret.is_void() && ret.var_type.get_class_type().is_some() && ret.var_style.is_ptr() == Some(false) {
+					let mut deref_type = var_type.clone();
+					deref_type.var_style = VarStyle::Ref;
+					expression = Some(Rc::new(Expression::Prefix(Rc::clone(expr), 6, deref_type, position.clone())));
+				}
+			}
+		}
+
+		let use_move = expression.is_some() && Self::should_move(expression.as_ref().unwrap(), &context.function_return_type);
+		if use_move {
+			context.add_header("utility", true);
+		}
+
 		return ReturnParserResult::Ok(ReturnParser {
 			expression: expression,
+			use_move,
+			tuple_types,
 			line: initial_line
 		});
 	}
 
+	/// Collects the operands of the comma operators in the expression, in order.
+	fn flatten_comma_expression(expr: &Rc<Expression>, elements: &mut Vec<Rc<Expression>>) {
+		if let Expression::Infix(left, right, 41, _, _) = &**expr {
+			Self::flatten_comma_expression(left, elements);
+			Self::flatten_comma_expression(right, elements);
+		} else {
+			elements.push(Rc::clone(expr));
+		}
+	}
+
+	/// Determines whether the returned expression should be wrapped with `std::move`.
+	/// Named locals are left alone so RVO can apply, while `move`/`ref` variables and
+	/// subobjects of locals are moved since they cannot be implicitly moved from.
+	pub fn should_move(expr: &Expression, return_type: &Option<VariableType>) -> bool {
+		let ret = match return_type {
+			Some(ret) => ret,
+			None => return false
+		};
+		if ret.is_void() || ret.var_style != VarStyle::Copy {
+			return false;
+		}
+		match expr {
+			Expression::Value(name, var_type, _) => {
+				name != "this" && matches!(var_type.var_style, VarStyle::Move | VarStyle::Ref)
+			},
+			Expression::Infix(left, _, 2, var_type, _) => {
+				if var_type.is_number() || var_type.is_boolean() {
+					return false;
+				}
+				let mut root = &**left;
+				while let Expression::Infix(inner_left, _, 2, _, _) = root {
+					root = &**inner_left;
+				}
+				if let Expression::Value(name, root_type, _) = root {
+					name != "this" && name != "self" && !root_type.var_style.is_ptr().unwrap_or(true)
+				} else {
+					false
+				}
+			},
+			_ => false
+		}
+	}
+
+	/// Finds the range of a returned tuple element, falling back to the whole
+	/// expression if the element only has a line-relative position.
+	fn get_element_range(expr: &Expression, start: usize, end: usize) -> (usize, usize) {
+		if let Some(pos) = expr.get_position() {
+			if let (None, Some(end)) = (pos.line, pos.end) {
+				return (pos.start, end);
+			}
+		}
+		(start, end)
+	}
+
 	pub fn is_declaration(parser: &Parser) -> bool {
 		return Self::is_return_declaration(&parser.content, parser.index);
 	}
@@ -0,0 +1,158 @@
+/**********************************************************
+ * --- Try Parser ---
+ *
+ * Parses a try statement and its catch blocks.
+ **********************************************************/
+
+use crate::{
+	declare_parse_whitespace,
+	declare_parse_ascii,
+	declare_parse_required_next_char,
+	declare_parse_type
+};
+
+use crate::config_management::ConfigData;
+
+use crate::expression::variable_type::{ VariableType, VarStyle };
+
+use crate::declaration_parser::declaration::{ Declaration, DeclarationResult };
+use crate::declaration_parser::parser::Parser;
+
+use crate::context_management::context::Context;
+use crate::context_management::context_manager::ContextManager;
+
+use crate::scope_parser::ScopeExpression;
+
+use regex::Regex;
+
+lazy_static! {
+	pub static ref TRY_REGEX: Regex = Regex::new(r"^\b(?:try)\b").unwrap();
+	pub static ref CATCH_REGEX: Regex = Regex::new(r"^\b(?:catch)\b").unwrap();
+}
+
+type TryParserResult = DeclarationResult<TryParser>;
+
+pub struct TryParser {
+	pub scope: Box<ScopeExpression>,
+	pub catches: Vec<(VariableType, String, Box<ScopeExpression>, usize, usize)>,
+	pub line: usize,
+	pub end_line: usize
+}
+
+impl Declaration<TryParser> for TryParser {
+	fn out_of_space_error_msg() -> &'static str {
+		"unexpected end of try statement"
+	}
+}
+
+impl TryParser {
+	pub fn new(parser: &mut Parser, file_name: String, config_data: &ConfigData, context: &mut Context, context_manager: &mut ContextManager) -> TryParserResult {
+		let initial_line = parser.line;
+
+		let mut try_keyword = "".to_string();
+		declare_parse_ascii!(try_keyword, parser);
+		if try_keyword != "try" {
+			return TryParserResult::Err("Unexpected Keyword", "\"try\" keyword expected", parser.index - try_keyword.len(), parser.index);
+		}
+
+		declare_parse_whitespace!(parser);
+
+		if parser.get_curr() != '{' {
+			return TryParserResult::Err("Unexpected Symbol", "expected '{' operator", parser.index, parser.index + 1);
+		}
+		let scope = ScopeExpression::new(parser, None, parser.index + 1, parser.line, &file_name, config_data, context, context_manager, None);
+		if parser.get_curr() == '}' {
+			parser.increment();
+		}
+
+		let mut catches = Vec::new();
+		loop {
+			let before_catch_index = parser.index;
+			let before_catch_line = parser.line;
+			parser.parse_whitespace();
+			if !CATCH_REGEX.is_match(&parser.content[parser.index..]) {
+				parser.reset(before_catch_index, before_catch_line);
+				break;
+			}
+
+			let catch_line = parser.line;
+			let mut catch_keyword = "".to_string();
+			declare_parse_ascii!(catch_keyword, parser);
+
+			declare_parse_whitespace!(parser);
+
+			let mut catch_type = VariableType::inferred();
+			let mut catch_name = "".to_string();
+			if parser.get_curr() == '(' {
+				let mut next_char = ' ';
+				declare_parse_required_next_char!('(', next_char, parser);
+				declare_parse_whitespace!(parser);
+
+				let mut catch_style = VarStyle::Borrow;
+				declare_parse_ascii!(catch_name, parser);
+				let style = VarStyle::new(&catch_name);
+				if !style.is_unknown() {
+					catch_style = style;
+					declare_parse_whitespace!(parser);
+					declare_parse_ascii!(catch_name, parser);
+				}
+
+				declare_parse_whitespace!(parser);
+				declare_parse_required_next_char!(':', next_char, parser);
+				declare_parse_whitespace!(parser);
+
+				let var_type;
+				declare_parse_type!(var_type, parser);
+				catch_type = VariableType {
+					var_type,
+					var_style: catch_style,
+					var_properties: None,
+					var_optional: false
+				};
+				catch_type.resolve(context, context_manager);
+
+				declare_parse_whitespace!(parser);
+				declare_parse_required_next_char!(')', next_char, parser);
+				declare_parse_whitespace!(parser);
+			}
+
+			if parser.get_curr() != '{' {
+				return TryParserResult::Err("Unexpected Symbol", "expected '{' operator", parser.index, parser.index + 1);
+			}
+
+			context.typing.push_context();
+			if !catch_name.is_empty() {
+				context.typing.add_variable(catch_name.clone(), catch_type.clone(), None);
+			}
+			let catch_scope = ScopeExpression::new(parser, None, parser.index + 1, parser.line, &file_name, config_data, context, context_manager, None);
+			context.typing.pop_context();
+			if parser.get_curr() == '}' {
+				parser.increment();
+			}
+
+			catches.push((catch_type, catch_name, Box::new(catch_scope), catch_line, parser.line));
+		}
+
+		if catches.is_empty() {
+			return TryParserResult::Err("Catch Expected", "try statement requires at least one catch", parser.index - 1, parser.index);
+		}
+
+		context.add_header("exception", true);
+
+		TryParserResult::Ok(TryParser {
+			scope: Box::new(scope),
+			catches,
+			line: initial_line,
+			end_line: parser.line
+		})
+	}
+
+	pub fn is_declaration(parser: &Parser) -> bool {
+		Self::is_try_declaration(&parser.content, parser.index)
+	}
+
+	pub fn is_try_declaration(content: &str, index: usize) -> bool {
+		let declare = &content[index..];
+		TRY_REGEX.is_match(declare)
+	}
+}
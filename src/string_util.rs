@@ -0,0 +1,84 @@
+/**********************************************************
+ * --- String Util ---
+ *
+ * Functions for converting text from Tasty Fresh source
+ * into C++ string literals.
+ **********************************************************/
+
+/// Escapes the text so it can be placed within a C++ string literal.
+///
+/// # Arguments
+///
+/// * `content` - The text to escape.
+pub fn escape_cpp_string(content: &str) -> String {
+	let mut result = String::with_capacity(content.len());
+	for c in content.chars() {
+		match c {
+			'\\' => result += "\\\\",
+			'"' => result += "\\\"",
+			'\n' => result += "\\n",
+			'\r' => result += "\\r",
+			'\t' => result += "\\t",
+			'\0' => result += "\\0",
+			// Octal escapes end after three digits, so the following character cannot be consumed.
+			c if c.is_control() => result += &format!("\\{:03o}", c as u32),
+			c => result.push(c)
+		}
+	}
+	result
+}
+
+/// Retrieves the text of a string literal from an attribute parameter.
+/// Parameters without quotes are returned trimmed, but otherwise unchanged.
+///
+/// # Arguments
+///
+/// * `param` - The parameter content.
+pub fn parse_string_parameter(param: &str) -> String {
+	let param = param.trim();
+	let literal = match param.strip_prefix('"').and_then(|p| p.strip_suffix('"')) {
+		Some(literal) => literal,
+		None => return param.to_string()
+	};
+	let mut result = String::with_capacity(literal.len());
+	let mut chars = literal.chars();
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			result.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('n') => result.push('\n'),
+			Some('r') => result.push('\r'),
+			Some('t') => result.push('\t'),
+			Some('0') => result.push('\0'),
+			Some(other) => result.push(other),
+			None => result.push('\\')
+		}
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn escapes_special_characters() {
+		assert_eq!(escape_cpp_string("say \"hi\"\\n"), "say \\\"hi\\\"\\\\n");
+		assert_eq!(escape_cpp_string("line\nnext\ttab"), "line\\nnext\\ttab");
+		assert_eq!(escape_cpp_string("bell\u{7}1"), "bell\\0071");
+	}
+
+	#[test]
+	fn parses_string_parameters() {
+		assert_eq!(parse_string_parameter(" \"a \\\"b\\\"\\nc\" "), "a \"b\"\nc");
+		assert_eq!(parse_string_parameter(" plain "), "plain");
+	}
+
+	#[test]
+	fn parsed_parameters_escape_back() {
+		let param = "\"C:\\\\temp\\t\\\"x\\\"\"";
+		assert_eq!(format!("\"{}\"", escape_cpp_string(&parse_string_parameter(param))), param);
+	}
+}
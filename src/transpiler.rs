@@ -6,29 +6,41 @@
 
 use crate::expression::Expression;
 use crate::expression::expression_parser::ExpressionEndReason;
-use crate::expression::variable_type::{ VariableType, Type };
+use crate::expression::variable_type::{ VariableType, Type, VarProps };
 use crate::expression::function_type::FunStyle;
+use crate::expression::value_type::Function;
 
 use crate::context_management::position::Position;
 use crate::context_management::global_context::GlobalContext;
+use crate::context_management::context::Context;
 use crate::context_management::context_manager::ContextManager;
 
 use crate::declaration_parser::parser::Parser;
+use crate::declaration_parser::attributes::Attributes;
 use crate::declaration_parser::module_declaration::DeclarationType;
+use crate::declaration_parser::class_declaration::ClassDeclaration;
 use crate::declaration_parser::variable_declaration::VariableExportType;
+use crate::declaration_parser::include_declaration::IncludeDeclaration;
+use crate::declaration_parser::import_declaration::ImportDeclaration;
+use crate::declaration_parser::namespace_declaration::NamespaceDeclaration;
+use crate::declaration_parser::cpp_transpiler::CPPTranspiler;
 
-use crate::config_management::ConfigData;
+use crate::config_management::{ ConfigData, ConstraintStyle, LibraryNames, Visibility };
 
 use crate::scope_parser::ScopeExpression;
 
-use crate::context_management::print_code_error;
+use crate::string_util::{ escape_cpp_string, parse_string_parameter };
+
+use crate::context_management::{ print_code_error, print_code_warning, error_count, error_limit_reached };
 
 use std::rc::Rc;
+use std::collections::BTreeMap;
 
 use regex::Regex;
 
 lazy_static! {
 	pub static ref LINE_SPLIT: Regex = Regex::new("(?:\n\r|\r\n|\r|\n)").unwrap();
+	pub static ref ASSIGNMENT_LINE: Regex = Regex::new(r#"^(\s*)([A-Za-z_][^=;()"'{}]*?[\w\]])\s*=\s*([^=\s][^;]*;)$"#).unwrap();
 }
 
 use crate::{
@@ -41,7 +53,8 @@ pub struct VarFuncDeclarations {
 	pub variable_declarations: Vec<String>,
 	pub function_declarations: Vec<String>,
 	pub variable_declarations_isolated: Vec<String>,
-	pub function_declarations_isolated: Vec<String>
+	pub function_declarations_isolated: Vec<String>,
+	pub source_order: Vec<(usize, String, bool)>
 }
 
 impl VarFuncDeclarations {
@@ -50,10 +63,40 @@ impl VarFuncDeclarations {
 			variable_declarations: Vec::new(),
 			function_declarations: Vec::new(),
 			variable_declarations_isolated: Vec::new(),
-			function_declarations_isolated: Vec::new()
+			function_declarations_isolated: Vec::new(),
+			source_order: Vec::new()
+		}
+	}
+
+	/// Adds a variable declaration, where `source_index` is the position of its declaration within the module or class.
+	pub fn add_variable(&mut self, declaration: String, isolated: bool, source_index: usize) {
+		self.source_order.push((source_index, declaration.clone(), isolated));
+		if isolated {
+			self.variable_declarations_isolated.push(declaration);
+		} else {
+			self.variable_declarations.push(declaration);
 		}
 	}
 
+	/// Adds a function declaration, where `source_index` is the position of its declaration within the module or class.
+	pub fn add_function(&mut self, declaration: String, isolated: bool, source_index: usize) {
+		self.source_order.push((source_index, declaration.clone(), isolated));
+		if isolated {
+			self.function_declarations_isolated.push(declaration);
+		} else {
+			self.function_declarations.push(declaration);
+		}
+	}
+
+	/// Moves the declarations from `other` into this one.
+	pub fn append(&mut self, mut other: VarFuncDeclarations) {
+		self.variable_declarations.append(&mut other.variable_declarations);
+		self.function_declarations.append(&mut other.function_declarations);
+		self.variable_declarations_isolated.append(&mut other.variable_declarations_isolated);
+		self.function_declarations_isolated.append(&mut other.function_declarations_isolated);
+		self.source_order.append(&mut other.source_order);
+	}
+
 	fn push_line(d: String, lines: &mut Vec<String>, tab_count: usize, tabs: &str) {
 		if tab_count > 0 {
 			for line in LINE_SPLIT.split(&d) {
@@ -94,6 +137,30 @@ impl VarFuncDeclarations {
 		}
 	}
 
+	/// Exports the declarations in the order they were declared instead of grouping variables and functions.
+	pub fn export_in_source_order(self, lines: &mut Vec<String>, tab_count: usize) {
+		let tabs = String::from_utf8(vec![b'\t'; tab_count]).unwrap_or("".to_string());
+		let mut declares = self.source_order;
+		declares.sort_by_key(|d| d.0);
+		let mut in_group = false;
+		for (_, d, isolated) in declares {
+			if isolated {
+				if in_group {
+					lines.push("".to_string());
+					in_group = false;
+				}
+				Self::push_line(d, lines, tab_count, &tabs);
+				lines.push("".to_string());
+			} else {
+				Self::push_line(d, lines, tab_count, &tabs);
+				in_group = true;
+			}
+		}
+		if in_group {
+			lines.push("".to_string());
+		}
+	}
+
 	fn export_isolated(declares: Vec<String>, lines: &mut Vec<String>, tab_count: usize, tabs: &str) {
 		if !declares.is_empty() {
 			for d in declares {
@@ -117,7 +184,7 @@ pub struct Transpiler<'a> {
 	pub output_lines: Vec<String>,
 
 	pub declarations: VarFuncDeclarations,
-	pub class_declarations: Vec<(String,VarFuncDeclarations,VarFuncDeclarations,VarFuncDeclarations)>,
+	pub class_declarations: Vec<(String,VarFuncDeclarations,VarFuncDeclarations,VarFuncDeclarations,Option<String>)>,
 	
 	pub handling_module_attributes: bool,
 	pub header_include_line: Option<usize>,
@@ -125,12 +192,21 @@ pub struct Transpiler<'a> {
 
 	pub header_system_includes: Vec<String>,
 	pub header_local_includes: Vec<String>,
+	pub header_include_conditions: BTreeMap<String, String>,
+	pub lazy_includes: Vec<(IncludeDeclaration, Vec<String>, Option<String>)>,
+	pub constexpr_functions: Vec<String>,
+	pub namespaces: Vec<NamespaceDeclaration>,
+	pub capability_class: bool,
+
+	pub module_init_lines: Vec<String>,
 
 	pub file: &'a str,
 	pub access_file_path: &'a str,
 	pub config_data: &'a ConfigData,
 	pub module_contexts: &'a mut ContextManager,
-	pub parser: &'a mut Parser
+	pub parser: &'a mut Parser,
+
+	error_count_start: usize
 }
 
 impl<'a> Transpiler<'a> {
@@ -147,29 +223,549 @@ impl<'a> Transpiler<'a> {
 
 			header_system_includes: Vec::new(),
 			header_local_includes: Vec::new(),
+			header_include_conditions: BTreeMap::new(),
+			lazy_includes: Vec::new(),
+			constexpr_functions: Vec::new(),
+			namespaces: Vec::new(),
+			capability_class: false,
+
+			module_init_lines: Vec::new(),
 
 			file: file,
 			access_file_path: access_file_path,
 			config_data: config_data,
 			module_contexts: module_contexts,
-			parser: parser
+			parser,
+
+			error_count_start: error_count()
+		}
+	}
+
+	/// Checks whether any errors were reported since this transpiler was created.
+	/// Output should not be written for a file with errors.
+	pub fn has_errors(&self) -> bool {
+		error_count() > self.error_count_start
+	}
+
+	/// Retrieves the visibility specifier for `@Export` or `@Hidden`, falling back to `--default-visibility`.
+	/// Returns an empty string if no specifier is needed, otherwise the specifier followed by a space.
+	fn get_visibility_specifier(&self, attributes: &Attributes) -> String {
+		let visibility = match (attributes.get_attribute("Export"), attributes.get_attribute("Hidden")) {
+			(Some(_), Some(hidden_attr)) => {
+				let pos = Position::new(self.file.to_string(), None, hidden_attr.start_index, Some(hidden_attr.end_index));
+				print_code_error("Conflicting Visibility", "@Export and @Hidden cannot be used together", &pos, &self.parser.content);
+				return "".to_string();
+			},
+			(Some(_), None) => Visibility::Default,
+			(None, Some(_)) => Visibility::Hidden,
+			(None, None) => {
+				if self.config_data.default_visibility == Visibility::Default {
+					return "".to_string();
+				}
+				self.config_data.default_visibility
+			}
+		};
+		match self.config_data.target.visibility(visibility) {
+			Some(specifier) => format!("{} ", specifier),
+			None => "".to_string()
+		}
+	}
+
+	/// Retrieves the specifier for `@Weak`, or an empty string if it is not used.
+	/// `conflict` describes why the declaration cannot be weak, if it cannot.
+	fn get_weak_specifier(&self, attributes: &Attributes, conflict: Option<&str>) -> &'static str {
+		let weak_attr = match attributes.get_attribute("Weak") {
+			Some(attr) => attr,
+			None => return ""
+		};
+		let pos = Position::new(self.file.to_string(), None, weak_attr.start_index, Some(weak_attr.end_index));
+		if let Some(msg) = conflict {
+			print_code_error("Invalid Weak", msg, &pos, &self.parser.content);
+			return "";
+		}
+		if self.config_data.target.is_msvc() {
+			print_code_warning("Unsupported Weak", "msvc does not support weak symbols, so @Weak is ignored", &pos, &self.parser.content);
+			return "";
+		}
+		"__attribute__((weak)) "
+	}
+
+	/// Retrieves the specifier for `@Section`, or an empty string if it is not used.
+	/// msvc places functions and variables in sections differently, so `is_function` selects which to use.
+	fn get_section_specifier(&self, attributes: &Attributes, is_function: bool, conflict: Option<&str>) -> String {
+		let section_attr = match attributes.get_attribute("Section") {
+			Some(attr) => attr,
+			None => return "".to_string()
+		};
+		let pos = Position::new(self.file.to_string(), None, section_attr.start_index, Some(section_attr.end_index));
+		if let Some(msg) = conflict {
+			print_code_error("Invalid Section", msg, &pos, &self.parser.content);
+			return "".to_string();
+		}
+		let params = attributes.get_attribute_parameters("Section", &self.parser.content);
+		let is_quoted = params.len() == 1 && {
+			let param = params[0].trim();
+			param.len() > 2 && param.starts_with('"') && param.ends_with('"')
+		};
+		if !is_quoted {
+			print_code_error("Invalid Section", "@Section requires the section name as a string, such as @Section(\".data\")", &pos, &self.parser.content);
+			return "".to_string();
+		}
+		let section = escape_cpp_string(&parse_string_parameter(&params[0]));
+		if !self.config_data.target.is_msvc() {
+			return format!("__attribute__((section(\"{}\"))) ", section);
+		}
+		if is_function {
+			return format!("__declspec(code_seg(\"{}\")) ", section);
+		}
+		format!("#pragma section(\"{}\", read, write)\n__declspec(allocate(\"{}\")) ", section, section)
+	}
+
+	/// Retrieves the specifier for `@Hot` or `@Cold`, or an empty string if neither is used.
+	fn get_hot_cold_specifier(&self, attributes: &Attributes, is_inline: bool) -> &'static str {
+		let (attr, name, specifier) = match (attributes.get_attribute("Hot"), attributes.get_attribute("Cold")) {
+			(Some(_), Some(cold_attr)) => {
+				let pos = Position::new(self.file.to_string(), None, cold_attr.start_index, Some(cold_attr.end_index));
+				print_code_error("Conflicting Hints", "@Hot and @Cold cannot be used together", &pos, &self.parser.content);
+				return "";
+			},
+			(Some(hot_attr), None) => (hot_attr, "Hot", "__attribute__((hot)) "),
+			(None, Some(cold_attr)) => (cold_attr, "Cold", "__attribute__((cold)) "),
+			(None, None) => return ""
+		};
+		let pos = Position::new(self.file.to_string(), None, attr.start_index, Some(attr.end_index));
+		if self.config_data.target.is_msvc() {
+			print_code_warning("Unsupported Hint", &format!("msvc does not support optimization hints, so @{} is ignored", name), &pos, &self.parser.content);
+			return "";
+		}
+		if is_inline && name == "Cold" {
+			print_code_warning("Conflicting Hints", "@Cold functions are optimized for size, so they are unlikely to be inlined", &pos, &self.parser.content);
+		}
+		specifier
+	}
+
+	/// Retrieves the lines of the comments above a declaration for `--keep-comments`, along with the line of each.
+	fn get_kept_comments(&self, attributes: &Attributes) -> Vec<(String, usize)> {
+		let mut result = Vec::new();
+		if !self.config_data.keep_comments {
+			return result;
+		}
+		let content = &self.parser.content;
+		for (start, end, comment_line) in attributes.get_comments() {
+			for (i, comment) in content[*start..(*end).min(content.len())].lines().enumerate() {
+				result.push((comment.trim_end().to_string(), comment_line + i));
+			}
+		}
+		result
+	}
+
+	/// Outputs the comments above a declaration for `--keep-comments`.
+	/// Unless the lines are aligned, `line` is moved past the comments.
+	fn insert_kept_comments(&mut self, attributes: &Attributes, line: &mut usize, align_lines: bool) {
+		for (comment, comment_line) in self.get_kept_comments(attributes) {
+			if align_lines {
+				insert_output_line(&mut self.output_lines, &comment, comment_line, 0);
+			} else {
+				insert_output_line(&mut self.output_lines, &comment, *line, 0);
+				*line += 1;
+			}
+		}
+	}
+
+	/// Warns that a thread-safety attribute is ignored if the target is not Clang.
+	/// Returns whether the attribute should be output.
+	fn supports_thread_safety(&self, attributes: &Attributes, name: &str) -> bool {
+		if self.config_data.target.is_clang() {
+			return true;
+		}
+		let attr = attributes.get_attribute(name).unwrap();
+		let pos = Position::new(self.file.to_string(), None, attr.start_index, Some(attr.end_index));
+		print_code_warning("Unsupported Thread Safety", &format!("thread-safety analysis requires the clang target, so @{} is ignored", name), &pos, &self.parser.content);
+		false
+	}
+
+	/// Checks that a class with `@ThreadSafe` is a lock type.
+	/// Returns whether its lock methods should be output with the capability attributes.
+	fn is_capability_class(&self, attributes: &Attributes, class_declare: &ClassDeclaration) -> bool {
+		if !class_declare.is_lock_type() {
+			let attr = attributes.get_attribute("ThreadSafe").unwrap();
+			let pos = Position::new(self.file.to_string(), None, attr.start_index, Some(attr.end_index));
+			print_code_error("Invalid ThreadSafe", "@ThreadSafe can only be used on lock types, which have \"lock\" and \"unlock\" methods", &pos, &self.parser.content);
+			return false;
+		}
+		self.supports_thread_safety(attributes, "ThreadSafe")
+	}
+
+	/// Retrieves the capability specifier for a method of a class with `@ThreadSafe`, or an empty string if it has none.
+	fn get_capability_specifier(&self, name: &str) -> &'static str {
+		if !self.capability_class {
+			return "";
+		}
+		match name {
+			"lock" => " __attribute__((acquire_capability()))",
+			"unlock" => " __attribute__((release_capability()))",
+			"try_lock" => " __attribute__((try_acquire_capability(true)))",
+			_ => ""
+		}
+	}
+
+	/// Retrieves the `guarded_by` specifier for `@GuardedBy`, or an empty string if it is not used.
+	fn get_guarded_by_specifier(&self, attributes: &Attributes, is_class_member: bool) -> String {
+		let guarded_attr = match attributes.get_attribute("GuardedBy") {
+			Some(attr) => attr,
+			None => return "".to_string()
+		};
+		let pos = Position::new(self.file.to_string(), None, guarded_attr.start_index, Some(guarded_attr.end_index));
+		if !is_class_member {
+			print_code_error("Invalid GuardedBy", "@GuardedBy can only be used on class members", &pos, &self.parser.content);
+			return "".to_string();
+		}
+		let params = attributes.get_attribute_parameters("GuardedBy", &self.parser.content);
+		let mutex = params.first().map(|p| parse_string_parameter(p)).unwrap_or_default();
+		if params.len() != 1 || mutex.is_empty() {
+			print_code_error("Invalid GuardedBy", "@GuardedBy requires the name of the mutex guarding the member", &pos, &self.parser.content);
+			return "".to_string();
+		}
+		if !self.supports_thread_safety(attributes, "GuardedBy") {
+			return "".to_string();
+		}
+		format!("__attribute__((guarded_by({}))) ", mutex)
+	}
+
+	/// Checks the forward declarations of an abstract against the type it becomes.
+	/// Types declared outside of Tasty Fresh cannot be checked, so their forwards are assumed to exist.
+	///
+	/// # Return
+	///
+	/// The forwarded functions that can be called within the abstract.
+	fn verify_forward_functions(&mut self, class_declare: &ClassDeclaration, context: &Context) -> Vec<Function> {
+		let mut result = Vec::new();
+		let becomes_type = class_declare.extensions.as_ref().and_then(|e| e.first()).map(|t| {
+			let mut var_type = VariableType::copy(t.clone());
+			var_type.resolve(context, self.module_contexts);
+			var_type.var_type
+		});
+		for declaration in &class_declare.declarations {
+			if let DeclarationType::Function(func_data, attributes) = declaration {
+				if !func_data.function_type.is_normal() {
+					continue;
+				}
+				let line_content = self.parser.content.lines().nth(func_data.line).unwrap_or("");
+				let name_start = line_content.find(&func_data.name).unwrap_or(0);
+				let pos = Position::new(self.file.to_string(), Some(func_data.line + 1), name_start, Some(name_start + func_data.name.len()));
+				match &becomes_type {
+					Some(Type::Class(cls_type)) => {
+						if !cls_type.functions.iter().any(|f| f.name == func_data.name && f.parameters.len() == func_data.parameters.len()) {
+							print_code_error("Forward Not Found", &format!("\"{}\" has no function \"{}\" with these parameters to forward to", cls_type.name, func_data.name), &pos, &self.parser.content);
+							continue;
+						}
+					},
+					Some(Type::Undeclared(_)) | Some(Type::UndeclaredWParams(_, _)) => (),
+					Some(other_type) => {
+						print_code_error("Invalid Forward", &format!("\"{}\" has no functions to forward to", other_type.to_cpp(false, &self.config_data.library)), &pos, &self.parser.content);
+						continue;
+					},
+					None => {
+						print_code_error("Invalid Forward", "forward can only be used on abstracts that use \"becomes\"", &pos, &self.parser.content);
+						continue;
+					}
+				}
+				result.push(func_data.to_typing_function(&self.parser.content, attributes));
+			}
+		}
+		result
+	}
+
+	fn print_invalid_alignment(&self, attributes: &Attributes) {
+		let align_attr = attributes.get_attribute("Align").unwrap();
+		let pos = match align_attr.parameters.as_ref().and_then(|p| p.first()).and_then(|p| p.as_ref().left()) {
+			Some(range) => Position::new(self.file.to_string(), None, range.0, Some(range.1)),
+			None => Position::new(self.file.to_string(), None, align_attr.start_index, Some(align_attr.end_index))
+		};
+		print_code_error("Invalid Alignment", "@Align requires a single power-of-two integer", &pos, &self.parser.content);
+	}
+
+	/// Generates a class for testing with a stub of each function in an abstract with `@Mock`.
+	/// The stubs record their names in `calls` and return a default value.
+	fn add_mock_class(&mut self, class_declare: &ClassDeclaration) {
+		let mut public_declares = VarFuncDeclarations::new();
+		public_declares.add_variable(format!("{}<{}> calls;", self.config_data.library.std_name("vector"), self.config_data.library.std_name("string")), false, 0);
+		for declaration in class_declare.abstract_declarations.as_ref().unwrap() {
+			if let DeclarationType::Function(func_data, _) = declaration {
+				if !func_data.function_type.is_normal() {
+					continue;
+				}
+				let func = func_data.to_function(&self.parser.content);
+				public_declares.add_function(format!("{} {{ calls.push_back(\"{}\");{} }}",
+					func.to_cpp(false, true, None, &func_data.function_type, &self.config_data.library),
+					func_data.name,
+					if func_data.return_type.is_void() { "" } else { " return {};" }
+				), false, 0);
+			}
+		}
+		for include in &["vector", "string"] {
+			if !self.header_system_includes.iter().any(|i| i == include) {
+				self.header_system_includes.push(include.to_string());
+			}
+		}
+		let separator = if self.module_contexts.get_context_immut(self.access_file_path).uses_allman_braces() { "\n" } else { " " };
+		self.class_declarations.push((format!("class {}Mock{}{{", class_declare.name, separator), VarFuncDeclarations::new(), public_declares, VarFuncDeclarations::new(), None));
+	}
+
+	/// Adds the includes from `@IfUsed` whose symbols appear in the generated output.
+	/// This must be called after all the declarations have been transpiled.
+	pub fn add_lazy_includes(&mut self) {
+		if self.lazy_includes.is_empty() {
+			return;
+		}
+		let mut output = self.output_lines.join("\n");
+		for declaration in self.declarations.source_order.iter() {
+			output += "\n";
+			output += &declaration.1;
+		}
+		for cls in self.class_declarations.iter() {
+			output += "\n";
+			output += &cls.0;
+			for declaration in cls.1.source_order.iter().chain(cls.2.source_order.iter()).chain(cls.3.source_order.iter()) {
+				output += "\n";
+				output += &declaration.1;
+			}
+			if let Some(content) = &cls.4 {
+				output += "\n";
+				output += content;
+			}
+		}
+
+		let align_lines = self.module_contexts.get_context(self.access_file_path).align_lines;
+		let mut source_line = self.header_include_line.map(|l| l + 1).unwrap_or(0);
+		for (include, symbols, condition) in std::mem::take(&mut self.lazy_includes) {
+			let is_used = symbols.iter().any(|symbol| {
+				let symbol_regex = Regex::new(&format!(r"(?:^|[^\w]){}(?:[^\w]|$)", regex::escape(symbol))).unwrap();
+				symbol_regex.is_match(&output)
+			});
+			if !is_used {
+				continue;
+			}
+			if include.location.is_header() {
+				if let Some(cond) = condition {
+					self.header_include_conditions.insert(include.path.clone(), cond);
+				}
+				if include.inc_type.is_local() {
+					self.header_local_includes.push(include.path.clone());
+				} else {
+					self.header_system_includes.push(include.path.clone());
+				}
+			} else {
+				let include_line = IncludeDeclaration::wrap_with_condition(include.to_cpp(), condition.as_ref());
+				if align_lines {
+					insert_output_line(&mut self.output_lines, &include_line, include.line, 0);
+				} else {
+					self.output_lines.insert(source_line, include_line);
+					source_line += 1;
+				}
+			}
+		}
+	}
+
+	/// Reports namespace declarations that are not placed between the module's includes and its other declarations.
+	/// Anything transpiled before the namespace would be left outside of it, and includes after it would be wrapped by it.
+	fn verify_namespace_order(&self, declarations: &[DeclarationType]) {
+		let is_include = |declaration: &DeclarationType| matches!(declaration, DeclarationType::Include(..) | DeclarationType::Import(..));
+		for (index, declaration) in declarations.iter().enumerate() {
+			if let DeclarationType::Namespace(namespace, _) = declaration {
+				let is_preceded = declarations[..index].iter().any(|d| {
+					!is_include(d) && !matches!(d, DeclarationType::ModuleAttribute(_) | DeclarationType::Assume(..) | DeclarationType::Namespace(..))
+				});
+				if is_preceded || declarations[index + 1..].iter().any(is_include) {
+					let pos = Position::new(self.file.to_string(), None, namespace.start_index, Some(namespace.end_index));
+					print_code_error("Invalid Namespace", "namespaces must be declared after the includes and imports, but before all other declarations", &pos, &self.parser.content);
+				}
+			}
+		}
+	}
+
+	/// Closes the namespaces declared by the module at the end of its source.
+	pub fn close_namespaces(&mut self) {
+		if self.namespaces.is_empty() {
+			return;
+		}
+		self.output_lines.push("".to_string());
+		for _ in &self.namespaces {
+			self.output_lines.push("}".to_string());
+		}
+	}
+
+	/// Adds the function that initializes the module's global variables when `--module-init` is used.
+	/// The function is registered so the generated registry can call it after the modules it imports.
+	pub fn add_module_init(&mut self, global_context: &mut GlobalContext) {
+		if self.module_init_lines.is_empty() {
+			return;
+		}
+		let sanitized: String = self.access_file_path.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+		let func_name = format!("__init_module_{}", sanitized);
+		self.output_lines.push("".to_string());
+		if self.module_contexts.get_context_immut(self.access_file_path).uses_allman_braces() {
+			self.output_lines.push(format!("void {}()", func_name));
+			self.output_lines.push("{".to_string());
+		} else {
+			self.output_lines.push(format!("void {}() {{", func_name));
+		}
+		for init_line in &self.module_init_lines {
+			self.output_lines.push(format!("\t{}", init_line));
+		}
+		self.output_lines.push("}".to_string());
+		self.declarations.add_function(format!("void {}();", func_name), false, usize::MAX);
+		let header_path = format!("{}.{}", &self.file[..self.file.len() - 6], self.config_data.header_ext);
+		global_context.add_module_init(func_name, header_path, self.access_file_path.to_string());
+	}
+
+	/// Aligns the `=` of runs of consecutive single-line assignments with the same indentation.
+	/// Lines that continue a previous statement or belong to the preprocessor are left untouched.
+	pub fn align_assignments(&mut self) {
+		let mut run: Vec<(usize, String, String, String)> = Vec::new();
+		for i in 0..=self.output_lines.len() {
+			let mut assignment = None;
+			if i < self.output_lines.len() {
+				let starts_statement = i == 0 || {
+					let prev = self.output_lines[i - 1].trim_end();
+					prev.trim().is_empty() || prev.ends_with(';') || prev.ends_with('{') || prev.ends_with('}') || prev.ends_with(':')
+				};
+				if starts_statement {
+					if let Some(caps) = ASSIGNMENT_LINE.captures(&self.output_lines[i]) {
+						assignment = Some((i, caps[1].to_string(), caps[2].to_string(), caps[3].to_string()));
+					}
+				}
+			}
+			let continues_run = match (&assignment, run.last()) {
+				(Some(a), Some(last)) => a.0 == last.0 + 1 && a.1 == last.1,
+				_ => false
+			};
+			if !continues_run {
+				if run.len() > 1 {
+					let width = run.iter().map(|a| a.2.chars().count()).max().unwrap_or(0);
+					for a in &run {
+						self.output_lines[a.0] = format!("{}{:<width$} = {}", a.1, a.2, a.3, width = width);
+					}
+				}
+				run.clear();
+			}
+			if let Some(a) = assignment {
+				run.push(a);
+			}
+		}
+	}
+
+	/// Includes the header of an imported module and makes its declarations visible.
+	/// Imports are added before the variables, so they are included before anything that uses them.
+	fn add_import(&mut self, import: &ImportDeclaration) {
+		if self.module_contexts.module_exists(&import.path) {
+			let real_path = format!("{}{}.{}", self.config_data.include_prefix, import.path, self.config_data.header_ext);
+			let context = self.module_contexts.get_context(self.access_file_path);
+			context.import_module(import.path.clone());
+			if import.is_header || import.is_pub {
+				self.header_local_includes.push(real_path.clone());
+			} else {
+				let line = if context.align_lines { import.line } else { self.output_lines.len() };
+				insert_output_line(&mut self.output_lines, format!("#include \"{}\"", real_path).as_str(), line, 0);
+			}
+		} else {
+			let pos = Position::new(self.file.to_string(), None, import.start_index, Some(import.end_index));
+			print_code_error("Import Not Found", "could not find Tasty Fresh source file", &pos, &self.parser.content)
 		}
 	}
 
 	pub fn parse_declarations(&mut self,
 		declarations: &mut Vec<DeclarationType>,
-		global_context: &GlobalContext,
+		global_context: &mut GlobalContext,
 		mut class_declarations: Option<(&str, &mut VarFuncDeclarations, &mut VarFuncDeclarations, &mut VarFuncDeclarations, Option<String>)>,
 		abstract_details: Option<(&str, Type)>
 	) {
 		let is_class_declare = !class_declarations.is_none();
+		if !is_class_declare {
+			self.verify_namespace_order(declarations);
+		}
 
 		//let mut declarations_clone = declarations.clone();
-		for declaration in declarations.iter_mut() {
+		for (source_index, declaration) in declarations.iter_mut().enumerate() {
+			if error_limit_reached() {
+				return;
+			}
+			// Module Attributes
+			if let DeclarationType::ModuleAttribute(module_attribute) = declaration {
+				let context = self.module_contexts.get_context(self.access_file_path);
+				context.register_module_attribute(&module_attribute.name);
+				if context.align_lines {
+					self.header_include_line = Some(module_attribute.line);
+				}
+				continue;
+			} else if self.handling_module_attributes {
+				self.handling_module_attributes = false;
+			}
+
 			match declaration {
+				DeclarationType::Import(import, _attributes) => self.add_import(import),
+				DeclarationType::Namespace(namespace, _attributes) => {
+					let context = self.module_contexts.get_context(self.access_file_path);
+					let line = if context.align_lines {
+						namespace.line
+					} else if self.namespaces.is_empty() {
+						self.output_lines.len() + 1
+					} else {
+						self.output_lines.len()
+					};
+					insert_output_line(&mut self.output_lines, &namespace.to_cpp(), line, 0);
+					self.namespaces.push(namespace.clone());
+				},
+				DeclarationType::Include(include, attributes) => {
+					let condition = IncludeDeclaration::get_condition(attributes, &self.parser.content);
+					if let Some(symbols) = IncludeDeclaration::get_used_symbols(attributes, &self.parser.content) {
+						if symbols.is_empty() {
+							let attr = attributes.get_attribute("IfUsed").unwrap();
+							let pos = Position::new(self.file.to_string(), None, attr.start_index, Some(attr.end_index));
+							print_code_error("Invalid IfUsed", "@IfUsed requires the symbols that cause the include to be used", &pos, &self.parser.content);
+						} else {
+							self.lazy_includes.push((include.clone(), symbols, condition));
+						}
+						continue;
+					}
+					if include.location.is_header() {
+						if let Some(cond) = condition {
+							self.header_include_conditions.insert(include.path.clone(), cond);
+						}
+						if include.inc_type.is_local() {
+							self.header_local_includes.push(include.path.clone());
+						} else {
+							self.header_system_includes.push(include.path.clone());
+						}
+					} else {
+						let context = self.module_contexts.get_context(self.access_file_path);
+						let line = if context.align_lines { include.line } else { self.output_lines.len() };
+						insert_output_line(&mut self.output_lines, &IncludeDeclaration::wrap_with_condition(include.to_cpp(), condition.as_ref()), line, 0);
+					}
+					
+				},
 				DeclarationType::Variable(var_data, attributes) => {
 					attributes.flatten_attributes(global_context, self.parser.content.as_str());
 
+					if var_data.bindings.is_some() {
+						let value_range = var_data.value.unwrap_or((0, self.parser.content.len()));
+						let pos = Position::new(self.file.to_string(), None, value_range.0, Some(value_range.1));
+						print_code_error("Invalid Binding", "structured bindings can only be declared within a scope", &pos, &self.parser.content);
+						continue;
+					}
+
+					if let Some(uninit_range) = var_data.uninit.filter(|_| self.config_data.strict) {
+						let pos = Position::new(self.file.to_string(), None, uninit_range.0, Some(uninit_range.1));
+						print_code_warning("Uninitialized Variable", &format!("\"{}\" is intentionally left uninitialized", var_data.name), &pos, &self.parser.content);
+					}
+
+					if attributes.has_attribute("Align") {
+						match ClassDeclaration::get_alignment(attributes, &self.parser.content) {
+							Some(alignment) => var_data.alignment = Some(alignment),
+							None => self.print_invalid_alignment(attributes)
+						}
+					}
+
 					let mut context = self.module_contexts.take_context(self.access_file_path);
 					let mut reason = ExpressionEndReason::Unknown;
 					let mut expr: Option<Rc<Expression>> = None;
@@ -181,7 +777,7 @@ impl<'a> Transpiler<'a> {
 						}
 					}
 					let var_type = &var_data.var_type;
-					let line = if context.align_lines { var_data.line } else {
+					let mut line = if context.align_lines { var_data.line } else {
 						if self.end_line > var_data.line || var_data.line - self.end_line < 2 {
 							self.output_lines.len()
 						} else {
@@ -193,9 +789,52 @@ impl<'a> Transpiler<'a> {
 					} else {
 						context.module.add_variable(var_data.name.clone(), var_data.var_type.clone(), Some(self.module_contexts));
 					}
-					if !is_class_declare || var_data.is_only_static() {
+					if !is_class_declare && attributes.has_attribute("Define") {
+						if let Some(literal) = expr.as_ref().filter(|e| e.is_literal()) {
+							let define_declaration = format!("#define {} {}", var_data.name, literal.to_string(&self.config_data.operators, &mut context));
+							configure_declaration_with_attributes(
+								&mut self.declarations,
+								source_index,
+								&define_declaration,
+								attributes,
+								&self.parser.content,
+								false
+							);
+						} else {
+							let value_range = var_data.value.unwrap_or((0, self.parser.content.len()));
+							let pos = Position::new(self.file.to_string(), None, value_range.0, Some(value_range.1));
+							print_code_error("Invalid Define", "@Define requires a literal initializer", &pos, &self.parser.content);
+						}
+						self.end_line = var_data.line;
+						self.module_contexts.add_context(self.access_file_path.to_string(), context);
+						continue;
+					}
+					let guarded_by = self.get_guarded_by_specifier(attributes, is_class_declare);
+					let section = self.get_section_specifier(attributes, false, if is_class_declare && !var_data.is_only_static() {
+						Some("@Section cannot be used on non-static class members")
+					} else {
+						None
+					});
+					let weak = self.get_weak_specifier(attributes, if is_class_declare && !var_data.is_only_static() {
+						Some("@Weak cannot be used on non-static class members")
+					} else if var_data.var_type.var_properties.as_ref().map(|props| props.contains(&VarProps::Constexpr)).unwrap_or(false) {
+						Some("@Weak has no effect on constexpr variables")
+					} else {
+						None
+					});
+					let moved_to_init = self.config_data.module_init && !is_class_declare && expr.is_some() &&
+						var_data.can_move_to_module_init(expr.as_ref().unwrap());
+					self.insert_kept_comments(attributes, &mut line, context.align_lines);
+					if moved_to_init {
 						insert_output_line(&mut self.output_lines,
-							&var_data.to_cpp(&expr,
+							&format!("{}{}{}", section, weak, var_data.to_cpp(&None, &self.config_data.operators, &mut context, VariableExportType::ModuleSource)),
+							line,
+							0,
+						);
+						self.module_init_lines.push(var_data.to_module_init_cpp(expr.as_ref().unwrap(), &self.config_data.operators, &mut context));
+					} else if !is_class_declare || var_data.is_only_static() {
+						insert_output_line(&mut self.output_lines,
+							&format!("{}{}{}", section, weak, var_data.to_cpp(&expr,
 								&self.config_data.operators,
 								&mut context,
 								if is_class_declare && var_data.is_only_static() {
@@ -203,19 +842,40 @@ impl<'a> Transpiler<'a> {
 								} else {
 									VariableExportType::ModuleSource
 								}
-							),
+							)),
 							line,
 							0,
 						);
 					}
 					self.end_line = var_data.line;
+					let mut bitfield_width = None;
+					if attributes.has_attribute("Bitfield") {
+						let bitfield_attr = attributes.get_attribute("Bitfield").unwrap();
+						let pos = match bitfield_attr.parameters.as_ref().and_then(|p| p.first()).and_then(|p| p.as_ref().left()) {
+							Some(range) => Position::new(self.file.to_string(), None, range.0, Some(range.1)),
+							None => Position::new(self.file.to_string(), None, bitfield_attr.start_index, Some(bitfield_attr.end_index))
+						};
+						if !is_class_declare || var_data.is_only_static() {
+							print_code_error("Invalid Bitfield", "@Bitfield can only be used on non-static class members", &pos, &self.parser.content);
+						} else if var_data.alignment.is_some() {
+							print_code_error("Invalid Bitfield", "bitfields cannot be aligned with @Align", &pos, &self.parser.content);
+						} else if let Some(value_range) = var_data.value {
+							let value_pos = Position::new(self.file.to_string(), None, value_range.0, Some(value_range.1));
+							print_code_error("Invalid Bitfield", "bitfields cannot have an initializer", &value_pos, &self.parser.content);
+						} else {
+							match var_data.get_bitfield_width(attributes, &self.parser.content) {
+								Some(width) => bitfield_width = Some(width),
+								None => print_code_error("Invalid Bitfield", "@Bitfield requires a width that fits within its integer type", &pos, &self.parser.content)
+							}
+						}
+					}
 					let add_to_header = !attributes.has_attribute("NoHeader");
 					if add_to_header {
 						if !is_class_declare {
-							let var_declaraction = format!("{} {} {}", if var_data.is_only_static() { "static" } else { "extern" }, var_type.to_cpp(), var_data.name);
+							let var_declaraction = format!("{}{} {} {}", var_data.alignment_to_cpp(), if var_data.is_only_static() { "static" } else { "extern" }, var_type.to_cpp(&self.config_data.library), var_data.name);
 							configure_declaration_with_attributes(
-								&mut self.declarations.variable_declarations,
-								&mut self.declarations.variable_declarations_isolated,
+								&mut self.declarations,
+								source_index,
 								&var_declaraction,
 								&attributes,
 								&self.parser.content,
@@ -223,7 +883,9 @@ impl<'a> Transpiler<'a> {
 							);
 						} else {
 							let var_declaraction = if is_class_declare && var_data.is_only_static() {
-								format!("static {} {} ", var_type.to_cpp(), var_data.name)
+								format!("{}static {} {} ", var_data.alignment_to_cpp(), var_type.to_cpp(&self.config_data.library), var_data.name)
+							} else if let Some(width) = bitfield_width {
+								var_data.to_bitfield_cpp(width, &self.config_data.library)
 							} else {
 								var_data.to_cpp(&expr, &self.config_data.operators, &mut context, if is_class_declare {
 									VariableExportType::ClassHeader
@@ -233,9 +895,9 @@ impl<'a> Transpiler<'a> {
 							};
 							let temp = &mut class_declarations.as_mut().unwrap().2;
 							configure_declaration_with_attributes(
-								&mut temp.variable_declarations,
-								&mut temp.variable_declarations_isolated,
-								&var_declaraction[0..var_declaraction.len() - 1],
+								temp,
+								source_index,
+								&format!("{}{}", guarded_by, &var_declaraction[0..var_declaraction.len() - 1]),
 								&attributes,
 								&self.parser.content,
 								true
@@ -249,27 +911,17 @@ impl<'a> Transpiler<'a> {
 			}
 		}
 
-		for declaration in declarations {
-			// Module Attributes
-			if let DeclarationType::ModuleAttribute(module_attribute) = declaration {
-				let context = self.module_contexts.get_context(self.access_file_path);
-				context.register_module_attribute(&module_attribute.name);
-				if context.align_lines {
-					self.header_include_line = Some(module_attribute.line);
-				}
-				continue;
-			} else if self.handling_module_attributes {
-				self.handling_module_attributes = false;
+		for (source_index, declaration) in declarations.iter_mut().enumerate() {
+			if error_limit_reached() {
+				return;
 			}
-
-			// All Others
 			match declaration {
 				DeclarationType::Refurbish(refurbish_declare, attributes) => {
 					attributes.flatten_attributes(global_context, self.parser.content.as_str());
 					let context = self.module_contexts.take_context(self.access_file_path);
 					self.module_contexts.add_context(self.access_file_path.to_string(), context);
 
-					let name = refurbish_declare.make_name();
+					let name = refurbish_declare.make_name(&self.config_data.library);
 					let r_type = refurbish_declare.refurbish_type.clone();
 					self.parse_declarations(
 						&mut refurbish_declare.declarations,
@@ -281,8 +933,19 @@ impl<'a> Transpiler<'a> {
 				DeclarationType::Class(class_declare, attributes) => {
 					attributes.flatten_attributes(global_context, self.parser.content.as_str());
 					if class_declare.class_type.is_abstract() {
+						if attributes.has_attribute("Mock") && self.config_data.emit_mocks {
+							self.add_mock_class(class_declare);
+						}
 						let mut context = self.module_contexts.take_context(self.access_file_path);
 						let var_type = class_declare.to_class(&mut context, self.module_contexts, &self.parser.content, &attributes);
+
+						// Forwarded functions are called on the underlying value, so they must exist on the type the abstract becomes.
+						let forward_functions = self.verify_forward_functions(class_declare, &context);
+						context.typing.push_context();
+						for func in &forward_functions {
+							context.typing.add_function(func.name.clone(), func.clone(), None);
+						}
+						context.forward_functions = forward_functions.into_iter().map(|func| func.name).collect();
 						self.module_contexts.add_context(self.access_file_path.to_string(), context);
 
 						self.parse_declarations(
@@ -291,7 +954,16 @@ impl<'a> Transpiler<'a> {
 							None,
 							Some((&class_declare.name, Type::Class(var_type)))
 						);
+
+						let mut context = self.module_contexts.take_context(self.access_file_path);
+						context.forward_functions.clear();
+						context.typing.pop_context();
+						self.module_contexts.add_context(self.access_file_path.to_string(), context);
 					} else {
+						if let Some(mock_attr) = attributes.get_attribute("Mock") {
+							let pos = Position::new(self.file.to_string(), None, mock_attr.start_index, Some(mock_attr.end_index));
+							print_code_error("Invalid Mock", "@Mock can only be used on abstracts", &pos, &self.parser.content);
+						}
 						let mut construct_declares = VarFuncDeclarations::new();
 						let mut public_declares = VarFuncDeclarations::new();
 						let mut private_declares = VarFuncDeclarations::new();
@@ -312,17 +984,28 @@ impl<'a> Transpiler<'a> {
 										if cls_type.is_some() {
 											let cls_type_unwrap = cls_type.unwrap();
 											for prop in cls_type_unwrap.properties {
+												context.class_members.variables.push(prop.name.clone());
 												context.typing.add_variable(prop.name.clone(), prop.prop_type.clone(), Some(self.module_contexts));
 											}
 											for func in cls_type_unwrap.functions {
+												context.class_members.add_function(&func);
 												context.typing.add_function(func.name.clone(), func.clone(), Some(self.module_contexts));
 											}
 										}
 									}
 								}
+								context.base_functions = get_base_functions(extends, &context, self.module_contexts);
+							}
+							for declaration in &class_declare.declarations {
+								match declaration {
+									DeclarationType::Variable(var_data, _) => context.class_members.variables.push(var_data.name.clone()),
+									DeclarationType::Function(func_data, _) => context.class_members.add_function(&func_data.to_function(&self.parser.content)),
+									_ => ()
+								}
 							}
 							self.module_contexts.add_context(self.access_file_path.to_string(), context);
 						}
+						self.capability_class = attributes.has_attribute("ThreadSafe") && self.is_capability_class(attributes, class_declare);
 						self.parse_declarations(
 							&mut class_declare.declarations,
 							global_context,
@@ -332,17 +1015,20 @@ impl<'a> Transpiler<'a> {
 									if extensions.is_empty() || extensions.len() > 1 {
 										None
 									} else {
-										Some(extensions.first().unwrap().to_cpp(false))
+										Some(extensions.first().unwrap().to_cpp(false, &self.config_data.library))
 									}
 								} else {
 									None
 								})),
 							None
 						);
+						self.capability_class = false;
 						{
 							let context = self.module_contexts.get_context(self.access_file_path);
 							context.typing.pop_context();
 							context.is_class = false;
+							context.base_functions.clear();
+							context.class_members.clear();
 						}
 
 						if class_declare.declaration_id != 0 {
@@ -352,20 +1038,49 @@ impl<'a> Transpiler<'a> {
 							self.module_contexts.update_class(class_declare.declaration_id, class_data);
 						}
 
+						if attributes.has_attribute("Align") && ClassDeclaration::get_alignment(attributes, &self.parser.content).is_none() {
+							self.print_invalid_alignment(attributes);
+						}
+
 						let mut isolated = false;
 						let mut class_content = get_configure_declaration_with_attributes(
 							&mut isolated,
-							&class_declare.to_cpp(attributes, self.parser.content.as_str()),
+							&class_declare.to_cpp(attributes, self.parser.content.as_str(), self.config_data.target, &self.config_data.library, &self.get_visibility_specifier(attributes),
+								if self.module_contexts.get_context_immut(self.access_file_path).uses_allman_braces() { "\n" } else { " " }),
 							&attributes,
 							&self.parser.content,
 							false
 						);
 
+						let mut class_end = None;
+						if attributes.has_attribute("Packed") && self.config_data.target.is_msvc() {
+							class_content = format!("#pragma pack(push, 1)\n{}", class_content);
+							class_end = Some("#pragma pack(pop)".to_string());
+						}
+
+						if attributes.has_attribute("Serialize") {
+							let mut context = self.module_contexts.take_context(self.access_file_path);
+							let class_data = class_declare.to_class(&mut context, self.module_contexts, &self.parser.content, attributes);
+							self.module_contexts.add_context(self.access_file_path.to_string(), context);
+							let (declarations, definitions) = class_declare.get_serialize_functions(&class_data.properties, &self.config_data.json_type);
+							if !self.header_system_includes.contains(&self.config_data.json_include) {
+								self.header_system_includes.push(self.config_data.json_include.clone());
+							}
+							class_end = Some(match class_end {
+								Some(end) => format!("{}\n\n{}", end, declarations),
+								None => format!("\n{}", declarations)
+							});
+							let line = self.output_lines.len() + 1;
+							insert_output_line(&mut self.output_lines, &definitions, line, 0);
+						}
+
+						class_content = self.get_kept_comments(attributes).into_iter().map(|(comment, _)| comment + "\n").collect::<String>() + &class_content;
+
 						if isolated {
 							class_content += "\n";
 						}
 
-						self.class_declarations.push((class_content, construct_declares, public_declares, private_declares));
+						self.class_declarations.push((class_content, construct_declares, public_declares, private_declares, class_end));
 					}
 				},
 				DeclarationType::Injection(injection, _attributes) => {
@@ -383,51 +1098,24 @@ impl<'a> Transpiler<'a> {
 					}
 				},
 				DeclarationType::Assume(_assume, _attributes) => {
-				},
-				DeclarationType::Import(import, _attributes) => {
-					if self.module_contexts.module_exists(&import.path) {
-						let real_path = if self.config_data.hpp_headers { 
-							format!("{}.hpp", import.path)
-						} else {
-							format!("{}.h", import.path)
-						};
-						let context = self.module_contexts.get_context(self.access_file_path);
-						context.import_module(import.path.clone());
-						if import.is_header {
-							self.header_local_includes.push(real_path.clone());
-						} else {
-							let line = if context.align_lines { import.line } else { self.output_lines.len() };
-							insert_output_line(&mut self.output_lines, format!("#include \"{}\"", real_path).as_str(), line, 0);
-						}
-					} else {
-						let pos = Position::new(self.file.to_string(), Some(import.line + 1), 7, Some(7 + import.path.len()));
-						print_code_error("Import Not Found", "could not find Tasty Fresh source file", &pos, &self.parser.content)
-					}
-				},
-				DeclarationType::Include(include, _attributes) => {
-					if include.location.is_header() {
-						if include.inc_type.is_local() {
-							self.header_local_includes.push(include.path.clone());
-						} else {
-							self.header_system_includes.push(include.path.clone());
-						}
-					} else {
-						let context = self.module_contexts.get_context(self.access_file_path);
-						let line = if context.align_lines { include.line } else { self.output_lines.len() };
-						insert_output_line(&mut self.output_lines, format!("#include {}", if include.inc_type.is_local() {
-							format!("\"{}\"", include.path)
-						} else {
-							format!("<{}>", include.path)
-						}).as_str(), line, 0);
-					}
-					
 				},
 				DeclarationType::Function(func_data, attributes) => {
+					// Calls to meta functions are replaced with their result, so the functions themselves are not output.
+					if func_data.is_meta() && !is_class_declare {
+						continue;
+					}
 					let is_static_extend = abstract_details.is_some();
 					if is_static_extend {
 						func_data.name = format!("{}_{}", abstract_details.as_ref().unwrap().0, func_data.name);
 						func_data.parameters.insert(0, (
-							if func_data.props.contains(&FunStyle::Const) {
+							if let Some(self_style) = &func_data.self_style {
+								VariableType {
+									var_type: abstract_details.as_ref().unwrap().1.clone(),
+									var_style: self_style.clone(),
+									var_properties: None,
+									var_optional: false
+								}
+							} else if func_data.props.contains(&FunStyle::Const) {
 								VariableType::borrow(abstract_details.as_ref().unwrap().1.clone())
 							} else {
 								VariableType::rref(abstract_details.as_ref().unwrap().1.clone())
@@ -441,19 +1129,156 @@ impl<'a> Transpiler<'a> {
 
 					attributes.flatten_attributes(global_context, self.parser.content.as_str());
 
+					if !is_class_declare && !is_static_extend && attributes.has_attribute("Test") {
+						let test_name = attributes.get_attribute_parameters("Test", &self.parser.content).first()
+							.map(|name| parse_string_parameter(name))
+							.unwrap_or(func_data.name.clone());
+						let header_path = format!("{}.{}", &self.file[..self.file.len() - 6], self.config_data.header_ext);
+						global_context.add_test(test_name, func_data.name.clone(), header_path);
+					}
+
+					if !is_class_declare && !is_static_extend && attributes.has_attribute("Bench") {
+						let bench_name = attributes.get_attribute_parameters("Bench", &self.parser.content).first()
+							.map(|name| parse_string_parameter(name))
+							.unwrap_or(func_data.name.clone());
+						let header_path = format!("{}.{}", &self.file[..self.file.len() - 6], self.config_data.header_ext);
+						global_context.add_bench(bench_name, func_data.name.clone(), header_path);
+					}
+
+					let mut entry_point_main = None;
+					if attributes.has_attribute("EntryPoint") {
+						let entry_attr = attributes.get_attribute("EntryPoint").unwrap();
+						let pos = Position::new(self.file.to_string(), None, entry_attr.start_index, Some(entry_attr.end_index));
+						let forwards_arguments = func_data.parameters.len() == 2 && func_data.parameters[0].0.is_number();
+						let returns_int = match &func_data.return_type.var_type {
+							Type::Number(number_type) => number_type.integer_range().is_some(),
+							_ => false
+						};
+						if is_class_declare || is_static_extend || func_data.is_generic() {
+							print_code_error("Invalid Entry Point", "@EntryPoint can only be used on module-level functions", &pos, &self.parser.content);
+						} else if func_data.name == "main" {
+							print_code_error("Invalid Entry Point", "@EntryPoint cannot be used on a function named \"main\"", &pos, &self.parser.content);
+						} else if !func_data.parameters.is_empty() && !forwards_arguments {
+							print_code_error("Invalid Entry Point", "@EntryPoint functions must take no parameters or (argc, argv)", &pos, &self.parser.content);
+						} else if !returns_int && !func_data.return_type.is_void() {
+							print_code_error("Invalid Entry Point", "@EntryPoint functions must return an integer or void", &pos, &self.parser.content);
+						} else if let Err(existing_file) = global_context.set_entry_point(func_data.name.clone(), self.file.to_string()) {
+							print_code_error("Duplicate Entry Point", &format!("an @EntryPoint was already declared in \"{}\"", existing_file), &pos, &self.parser.content);
+						} else {
+							let call = format!("{}({})", func_data.name, if forwards_arguments { "argc, argv" } else { "" });
+							let separator = if self.module_contexts.get_context_immut(self.access_file_path).uses_allman_braces() { "\n" } else { " " };
+							entry_point_main = Some(if returns_int {
+								format!("int main(int argc, char** argv){}{{\n\treturn {};\n}}", separator, call)
+							} else {
+								format!("int main(int argc, char** argv){}{{\n\t{};\n\treturn 0;\n}}", separator, call)
+							});
+						}
+					}
+
 					let mut context = self.module_contexts.take_context(self.access_file_path);
 
+					// Type parameters shadow outer types with the same name within the function.
+					context.typing.push_context();
+					for type_param in &func_data.type_params {
+						context.typing.add_type_parameter(type_param.clone(), None);
+					}
+
+					// "This" refers to the enclosing class, so fluent methods can return their own type.
+					if is_class_declare {
+						let class_type = Type::Undeclared(vec![class_declarations.as_ref().unwrap().0.to_string()]);
+						func_data.return_type.replace_this(&class_type);
+						for param in &mut func_data.parameters {
+							param.0.replace_this(&class_type);
+						}
+					}
+
 					func_data.return_type.resolve(&context, self.module_contexts);
 
 					for param in &mut func_data.parameters {
 						param.0.resolve(&context, self.module_contexts);
 					}
 
+					if attributes.has_attribute("Override") {
+						let override_attr = attributes.get_attribute("Override").unwrap();
+						let pos = Position::new(self.file.to_string(), None, override_attr.start_index, Some(override_attr.end_index));
+						if !is_class_declare {
+							print_code_error("Invalid Override", "@Override can only be used on class methods", &pos, &self.parser.content);
+						} else if let Some(base) = find_overridden_function(&func_data.to_function(&self.parser.content), &context.base_functions, &self.config_data.library) {
+							if !func_data.return_type.is_covariant_with(&base.return_type, &context, self.module_contexts) {
+								print_code_error("Mismatched Override Return", &format!("return type must match or be covariant with \"{}\"", base.return_type.to_cpp(&self.config_data.library)), &pos, &self.parser.content);
+							} else if !func_data.props.contains(&FunStyle::Override) {
+								func_data.props.push(FunStyle::Override);
+							}
+						} else {
+							print_code_error("Override Not Found", &format!("no base class has a virtual \"{}\" with these parameters", func_data.name), &pos, &self.parser.content);
+						}
+					}
+
+					let mut is_final = false;
+					if attributes.has_attribute("Final") {
+						let final_attr = attributes.get_attribute("Final").unwrap();
+						let pos = Position::new(self.file.to_string(), None, final_attr.start_index, Some(final_attr.end_index));
+						if !is_class_declare || !func_data.props.iter().any(|s| s.is_virtual() || s.is_override()) {
+							print_code_error("Invalid Final", "@Final can only be used on virtual or override methods", &pos, &self.parser.content);
+						} else {
+							is_final = true;
+						}
+					}
+					let final_specifier = if is_final { " final" } else { "" };
+
+					let mut call_conv = None;
+					if attributes.has_attribute("CallConv") {
+						let call_conv_attr = attributes.get_attribute("CallConv").unwrap();
+						let params = attributes.get_attribute_parameters("CallConv", &self.parser.content);
+						let name = params.first().map(|p| p.trim().trim_matches('"').to_string()).unwrap_or("".to_string());
+						call_conv = if params.len() == 1 { self.config_data.target.calling_convention(&name) } else { None };
+						if call_conv.is_none() {
+							let pos = match call_conv_attr.parameters.as_ref().and_then(|p| p.first()).and_then(|p| p.as_ref().left()) {
+								Some(range) => Position::new(self.file.to_string(), None, range.0, Some(range.1)),
+								None => Position::new(self.file.to_string(), None, call_conv_attr.start_index, Some(call_conv_attr.end_index))
+							};
+							print_code_error("Unknown Calling Convention", "expected \"cdecl\", \"stdcall\", \"fastcall\", \"thiscall\", or \"vectorcall\"", &pos, &self.parser.content);
+						}
+					}
+
 					let mut func_content: Option<String> = None;
 					let mut line = if context.align_lines { func_data.line } else { self.output_lines.len() + 1 };
 					let add_to_header = !attributes.has_attribute("NoHeader");
 					self.end_line = line;
 					let mut constructor_additions: Option<Vec<String>> = None;
+					let mut generic_definition: Option<String> = None;
+					let mut is_constexpr = false;
+					let no_discard = format!("{}{}{}{}{}{}",
+						if attributes.has_attribute("NoDiscard") { "[[nodiscard]] " } else { "" },
+						if attributes.has_attribute("NoReturn") { "[[noreturn]] " } else { "" },
+						if is_class_declare { "".to_string() } else { self.get_visibility_specifier(attributes) },
+						self.get_hot_cold_specifier(attributes, func_data.props.contains(&FunStyle::Inline)),
+						self.get_section_specifier(attributes, true, if func_data.is_meta() {
+							Some("@Section has no effect on meta functions")
+						} else {
+							None
+						}),
+						self.get_weak_specifier(attributes, if func_data.props.contains(&FunStyle::Inline) {
+							Some("@Weak has no effect on inline functions")
+						} else if func_data.is_meta() {
+							Some("@Weak has no effect on meta functions")
+						} else {
+							None
+						})
+					);
+					if func_data.function_type.is_operator() && func_data.name == "<=>" {
+						context.add_header("compare", true);
+					}
+					if func_data.is_generic() {
+						match self.config_data.constraint_style {
+							ConstraintStyle::EnableIf => context.add_header("type_traits", true),
+							ConstraintStyle::Requires => {
+								if func_data.constraints.iter().any(|c| c.1.to_cpp(false, &self.config_data.library).starts_with(&self.config_data.library.std_name(""))) {
+									context.add_header("concepts", true);
+								}
+							}
+						}
+					}
 					if !func_data.header_only() {
 						if func_data.start_index.is_some() && func_data.end_index.is_some() {
 							context.typing.push_context();
@@ -463,7 +1288,39 @@ impl<'a> Transpiler<'a> {
 							if is_static_extend {
 								context.convert_this_to_self = true;
 							}
+							context.function_return_type = Some(func_data.return_type.clone());
+							let allow_reinterpret = context.allow_reinterpret;
+							if attributes.has_attribute("Unsafe") {
+								context.allow_reinterpret = true;
+							}
+							context.function_labels.clear();
 							let scope = ScopeExpression::new(self.parser, None, func_data.start_index.unwrap(), func_data.line, self.file, self.config_data, &mut context, self.module_contexts, Some(func_data.return_type.clone()));
+							if self.config_data.warn_unused {
+								scope.warn_unused_variables(self.file, &self.parser.content);
+							}
+							if self.config_data.infer_const_methods && is_class_declare && !is_static_extend && func_data.can_infer_const() &&
+								!overrides_base_function(&func_data.to_function(&self.parser.content), &context.base_functions, &self.config_data.library) {
+								let locals = func_data.parameters.iter().map(|p| p.1.clone()).collect::<Vec<String>>();
+								if !scope.may_modify_members(&context.class_members, &locals) {
+									func_data.is_const = true;
+									context.class_members.set_const_function(&func_data.name);
+								}
+							}
+							if self.config_data.auto_constexpr && !is_class_declare && !is_static_extend && func_data.can_auto_constexpr() &&
+								attributes.only_has_attributes(&["Doc", "NoDiscard"]) {
+								let locals = func_data.parameters.iter().map(|p| p.1.clone()).collect::<Vec<String>>();
+								if scope.is_constexpr_compatible(&locals, &self.constexpr_functions) {
+									is_constexpr = true;
+									self.constexpr_functions.push(func_data.name.clone());
+								}
+							}
+							if self.config_data.strict && attributes.has_attribute("NoReturn") && !scope.never_returns() {
+								let noreturn_attr = attributes.get_attribute("NoReturn").unwrap();
+								let pos = Position::new(self.file.to_string(), None, noreturn_attr.start_index, Some(noreturn_attr.end_index));
+								print_code_warning("Function May Return", &format!("\"{}\" is marked @NoReturn, but it does not always throw, exit, or loop forever", func_data.name), &pos, &self.parser.content);
+							}
+							context.allow_reinterpret = allow_reinterpret;
+							context.function_return_type = None;
 							if func_data.function_type.is_constructor() {
 								context.activate_constructor(class_declarations.as_ref().unwrap().4.clone());
 							}
@@ -476,10 +1333,36 @@ impl<'a> Transpiler<'a> {
 							}
 							context.typing.pop_context();
 						}
-						let func_declaration = func_data.to_function(&self.parser.content).to_cpp(false, false,
+					}
+					context.typing.pop_context();
+
+					let mut cpp_function = func_data.to_function(&self.parser.content);
+					cpp_function.call_conv = call_conv;
+					if (func_data.is_generic() || is_constexpr) && !func_data.header_only() {
+						// Templates and constexpr functions must be defined where they are declared, so the whole function goes to the header.
+						let mut definition_lines = Vec::new();
+						if func_data.is_generic() {
+							definition_lines.push(func_data.template_declaration(self.config_data.constraint_style, &self.config_data.library));
+						}
+						definition_lines.push(format!("{}{}{}{}{}{{", no_discard, if is_constexpr { "constexpr " } else { "" }, cpp_function.to_cpp(true, true,
 							if is_class_declare { Some(class_declarations.as_ref().unwrap().0) } else { None },
-							&func_data.function_type
-						);
+							&func_data.function_type,
+							&self.config_data.library
+						), final_specifier, if context.uses_allman_braces() { "\n" } else { " " }));
+						for func_line in LINE_SPLIT.split(func_content.as_deref().unwrap_or("")) {
+							if !func_line.trim().is_empty() {
+								definition_lines.push(func_line.to_string());
+							}
+						}
+						definition_lines.push("}".to_string());
+						generic_definition = Some(definition_lines.join("\n"));
+					} else if !func_data.header_only() {
+						self.insert_kept_comments(attributes, &mut line, context.align_lines);
+						let func_declaration = format!("{}{}", if add_to_header { "" } else { no_discard.as_str() }, cpp_function.to_cpp(false, false,
+							if is_class_declare { Some(class_declarations.as_ref().unwrap().0) } else { None },
+							&func_data.function_type,
+							&self.config_data.library
+						));
 						insert_output_line(&mut self.output_lines, &func_declaration, line, 0);
 						if func_content.is_some() {
 							if func_data.function_type.is_constructor() && constructor_additions.is_some() {
@@ -490,6 +1373,9 @@ impl<'a> Transpiler<'a> {
 								}
 							}
 							let original_line = line;
+							if context.uses_allman_braces() {
+								line += 1;
+							}
 							insert_output_line(&mut self.output_lines, "{", line, 0);
 							for func_line in LINE_SPLIT.split(&func_content.unwrap()) {
 								insert_output_line(&mut self.output_lines, func_line, line, 0);
@@ -501,51 +1387,77 @@ impl<'a> Transpiler<'a> {
 						}
 						self.end_line = func_data.line + (line - self.end_line);
 					}
-					if add_to_header {
-						let header_func_declare = func_data.to_function(&self.parser.content).to_cpp(true,
+					if add_to_header || generic_definition.is_some() {
+						let is_definition = generic_definition.is_some();
+						let comments = if is_definition || func_data.header_only() {
+							self.get_kept_comments(attributes).into_iter().map(|(comment, _)| comment + "\n").collect::<String>()
+						} else {
+							"".to_string()
+						};
+						let header_func_declare = comments + &generic_definition.unwrap_or_else(|| format!("{}{}{}{}", no_discard, cpp_function.to_cpp(true,
 							true,
 							if is_class_declare { Some(class_declarations.as_ref().unwrap().0) } else { None },
-							&func_data.function_type
-						);
-						if !is_class_declare {
-							configure_declaration_with_attributes(
-								&mut self.declarations.function_declarations,
-								&mut self.declarations.function_declarations_isolated,
-								&header_func_declare,
-								&attributes,
-								&self.parser.content,
-								true
-							);
+							&func_data.function_type,
+							&self.config_data.library
+						), self.get_capability_specifier(&func_data.name), final_specifier));
+						let target: &mut VarFuncDeclarations = if !is_class_declare {
+							&mut self.declarations
+						} else if func_data.function_type.is_constructor_or_destructor() {
+							&mut *class_declarations.as_mut().unwrap().1
 						} else {
-							if func_data.function_type.is_constructor_or_destructor() {
-								let temp = &mut class_declarations.as_mut().unwrap().1;
-								configure_declaration_with_attributes(
-									&mut temp.function_declarations,
-									&mut temp.function_declarations_isolated,
-									&header_func_declare,
-									&attributes,
-									&self.parser.content,
-									true
-								);
-							} else {
-								let temp = &mut class_declarations.as_mut().unwrap().2;
-								configure_declaration_with_attributes(
-									&mut temp.function_declarations,
-									&mut temp.function_declarations_isolated,
-									&header_func_declare,
-									&attributes,
-									&self.parser.content,
-									true
-								);
-							}
-						}
+							&mut *class_declarations.as_mut().unwrap().2
+						};
+						let mut isolated = false;
+						let declaration = get_configure_declaration_with_attributes(&mut isolated, &header_func_declare, attributes, &self.parser.content, !is_definition);
+						target.add_function(declaration, isolated || is_definition, source_index);
 
 						self.module_contexts.add_context(self.access_file_path.to_string(), context);
 					}
+					if let Some(main_definition) = entry_point_main {
+						let line = self.output_lines.len() + 1;
+						insert_output_line(&mut self.output_lines, &main_definition, line, 0);
+					}
 				},
 				_ => {
 				}
 			}
 		}
 	}
-}
\ No newline at end of file
+}
+
+/// Collects the functions of every base class, including the bases of those classes.
+fn get_base_functions(extensions: &[Type], context: &Context, module_contexts: &mut ContextManager) -> Vec<Function> {
+	let mut result = Vec::new();
+	let mut visited = Vec::new();
+	let mut pending = extensions.to_vec();
+	while let Some(extension) = pending.pop() {
+		let mut var_type = VariableType::copy(extension);
+		var_type.resolve(context, module_contexts);
+		if let Some(cls_type) = var_type.var_type.get_class_type() {
+			if visited.contains(&cls_type.name) {
+				continue;
+			}
+			visited.push(cls_type.name.clone());
+			result.extend(cls_type.functions);
+			if let Some(base_extensions) = cls_type.extensions {
+				pending.extend(base_extensions);
+			}
+		}
+	}
+	result
+}
+
+/// Checks whether a virtual base function with the same name and parameters exists.
+fn overrides_base_function(func: &Function, base_functions: &[Function], library: &LibraryNames) -> bool {
+	find_overridden_function(func, base_functions, library).is_some()
+}
+
+/// Finds the virtual base function with the same name and parameters.
+fn find_overridden_function<'a>(func: &Function, base_functions: &'a [Function], library: &LibraryNames) -> Option<&'a Function> {
+	base_functions.iter().find(|base| {
+		base.name == func.name &&
+		base.styles.iter().any(|s| s.is_virtual() || s.is_override()) &&
+		base.parameters.len() == func.parameters.len() &&
+		base.parameters.iter().zip(&func.parameters).all(|(a, b)| a.prop_type.to_cpp(library) == b.prop_type.to_cpp(library))
+	})
+}
@@ -0,0 +1,183 @@
+/**********************************************************
+ * --- Examples ---
+ *
+ * Transpiles each fixture in "examples/regression" and
+ * compares the result with the output checked in beside
+ * its source.
+ *
+ * A fixture is a directory of .tasty files and the files
+ * they are expected to generate. Optionally, "args.txt"
+ * lists extra compiler options and "output.txt" holds the
+ * expected messages. Without "output.txt", no messages
+ * are expected. Files within "config" are copied with the
+ * sources so a fixture can provide its own configuration.
+ *
+ * If "stdin.txt" exists, it is piped to every run, and
+ * "stderr.txt" holds the expected error output.
+ *
+ * Each line of "args.txt" is a separate run over the same
+ * directory, so a fixture can check what a second run
+ * does. Timings in the messages are masked, and hidden
+ * files (such as the incremental cache) are not compared.
+ *
+ * Files within "changes/<run>" replace the sources before
+ * that run (counting from 1), so a fixture can check how
+ * a run reacts to edited files.
+ *
+ * Run with TASTY_FRESH_BLESS=1 to rewrite the expected
+ * output of every fixture.
+ **********************************************************/
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::io::Write;
+use std::process::{ Command, Stdio };
+
+use regex::Regex;
+
+const ARGS_FILE: &str = "args.txt";
+const OUTPUT_FILE: &str = "output.txt";
+const STDIN_FILE: &str = "stdin.txt";
+const STDERR_FILE: &str = "stderr.txt";
+const CONFIG_DIR: &str = "config";
+const CHANGES_DIR: &str = "changes";
+
+/// Retrieves every file within the directory, relative to it and sorted.
+fn list_files(dir: &Path, prefix: &Path, result: &mut Vec<PathBuf>) {
+	let mut entries = fs::read_dir(dir).unwrap().map(|e| e.unwrap().path()).collect::<Vec<PathBuf>>();
+	entries.sort();
+	for entry in entries {
+		let relative = prefix.join(entry.file_name().unwrap());
+		if relative.file_name().unwrap().to_string_lossy().starts_with('.') {
+			continue;
+		}
+		if entry.is_dir() {
+			list_files(&entry, &relative, result);
+		} else {
+			result.push(relative);
+		}
+	}
+}
+
+fn is_source(path: &Path) -> bool {
+	path.extension().map(|ext| ext == "tasty").unwrap_or(false) || path.starts_with(CONFIG_DIR) || path.starts_with(CHANGES_DIR)
+}
+
+/// Copies the fixture files within `from` into the work directory, keeping their paths relative to `from`.
+fn copy_sources(fixture: &Path, files: &[PathBuf], from: &Path, work_dir: &Path) {
+	for file in files.iter().filter(|f| f.starts_with(from)) {
+		let target = work_dir.join(file.strip_prefix(from).unwrap());
+		fs::create_dir_all(target.parent().unwrap()).unwrap();
+		fs::copy(fixture.join(file), target).unwrap();
+	}
+}
+
+/// Transpiles a copy of the fixture and returns the mismatches with its expected output.
+fn check_fixture(fixture: &Path, work_root: &Path, bless: bool) -> Vec<String> {
+	let name = fixture.file_name().unwrap().to_str().unwrap();
+	let work_dir = work_root.join(name);
+	let mut fixture_files = Vec::new();
+	list_files(fixture, Path::new(""), &mut fixture_files);
+	fs::create_dir_all(&work_dir).unwrap();
+	let sources = fixture_files.iter().filter(|f| is_source(f) && !f.starts_with(CHANGES_DIR)).cloned().collect::<Vec<PathBuf>>();
+	copy_sources(fixture, &sources, Path::new(""), &work_dir);
+
+	let args = fs::read_to_string(fixture.join(ARGS_FILE)).unwrap_or_default();
+	let mut runs = args.lines().filter(|line| !line.trim().is_empty()).collect::<Vec<&str>>();
+	if runs.is_empty() {
+		runs.push("");
+	}
+	let config_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("config");
+	let duration_regex = Regex::new(r"\d+\.\d+ms").unwrap();
+	let input = fs::read_to_string(fixture.join(STDIN_FILE)).unwrap_or_default();
+	let mut output = String::new();
+	let mut errors = String::new();
+	for (index, run) in runs.into_iter().enumerate() {
+		copy_sources(fixture, &fixture_files, &Path::new(CHANGES_DIR).join((index + 1).to_string()), &work_dir);
+		let mut child = Command::new(env!("CARGO_BIN_EXE_tasty_fresh"))
+			.current_dir(work_root)
+			.arg(format!("--src:{}", name))
+			.arg(format!("--config-dir:{}", config_dir.display()))
+			.args(run.split_whitespace())
+			.env("NO_COLOR", "1")
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.spawn()
+			.unwrap();
+		child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+		let result = child.wait_with_output().unwrap();
+		output += &String::from_utf8_lossy(&result.stdout);
+		errors += &String::from_utf8_lossy(&result.stderr);
+	}
+	let output = duration_regex.replace_all(&output, "<time>").replace(&work_root.display().to_string(), "<root>");
+	let errors = errors.replace(&work_root.display().to_string(), "<root>");
+
+	let mut generated_files = Vec::new();
+	list_files(&work_dir, Path::new(""), &mut generated_files);
+	generated_files.retain(|f| !is_source(f));
+	let expected_files = fixture_files.into_iter()
+		.filter(|f| !is_source(f) && ![ARGS_FILE, OUTPUT_FILE, STDIN_FILE, STDERR_FILE].iter().any(|name| f == Path::new(name)))
+		.collect::<Vec<PathBuf>>();
+
+	if bless {
+		for file in &expected_files {
+			fs::remove_file(fixture.join(file)).unwrap();
+		}
+		for file in &generated_files {
+			let content = fs::read_to_string(work_dir.join(file)).unwrap().replace(&work_root.display().to_string(), "<root>");
+			fs::create_dir_all(fixture.join(file).parent().unwrap()).unwrap();
+			fs::write(fixture.join(file), content).unwrap();
+		}
+		for (file, content) in [(OUTPUT_FILE, &output), (STDERR_FILE, &errors)] {
+			if content.is_empty() {
+				let _ = fs::remove_file(fixture.join(file));
+			} else {
+				fs::write(fixture.join(file), content).unwrap();
+			}
+		}
+		return Vec::new();
+	}
+
+	let mut mismatches = Vec::new();
+	let expected_output = fs::read_to_string(fixture.join(OUTPUT_FILE)).unwrap_or_default();
+	if output != expected_output {
+		mismatches.push(format!("{}: messages differ\n--- expected\n{}\n--- actual\n{}", name, expected_output, output));
+	}
+	let expected_errors = fs::read_to_string(fixture.join(STDERR_FILE)).unwrap_or_default();
+	if errors != expected_errors {
+		mismatches.push(format!("{}: error output differs\n--- expected\n{}\n--- actual\n{}", name, expected_errors, errors));
+	}
+	for file in expected_files.iter().filter(|f| !generated_files.contains(f)) {
+		mismatches.push(format!("{}: {} was not generated", name, file.display()));
+	}
+	for file in &generated_files {
+		if !expected_files.contains(file) {
+			mismatches.push(format!("{}: {} was generated, but not expected", name, file.display()));
+			continue;
+		}
+		let expected = fs::read_to_string(fixture.join(file)).unwrap();
+		let actual = fs::read_to_string(work_dir.join(file)).unwrap().replace(&work_root.display().to_string(), "<root>");
+		if expected != actual {
+			mismatches.push(format!("{}: {} differs\n--- expected\n{}\n--- actual\n{}", name, file.display(), expected, actual));
+		}
+	}
+	mismatches
+}
+
+#[test]
+fn regression_fixtures() {
+	let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples").join("regression");
+	let work_root = std::env::temp_dir().join(format!("tasty_fresh_regression_{}", std::process::id()));
+	let _ = fs::remove_dir_all(&work_root);
+	let bless = std::env::var_os("TASTY_FRESH_BLESS").is_some();
+
+	let mut fixtures = fs::read_dir(&fixtures_dir).unwrap().map(|e| e.unwrap().path()).filter(|p| p.is_dir()).collect::<Vec<PathBuf>>();
+	fixtures.sort();
+	let mut mismatches = Vec::new();
+	for fixture in &fixtures {
+		mismatches.extend(check_fixture(fixture, &work_root, bless));
+	}
+	let _ = fs::remove_dir_all(&work_root);
+	assert!(mismatches.is_empty(), "{} fixture mismatches:\n\n{}", mismatches.len(), mismatches.join("\n\n"));
+}